@@ -0,0 +1,429 @@
+//! Bulletproofs-style range proofs over Pedersen commitments
+//!
+//! Proves that a committed value `v` lies in `[0, 2^n)` without revealing
+//! `v`, following Bunz et al.'s aggregated range proof construction: `v` is
+//! bit-decomposed into `a_L`/`a_R`, blinded with random vector polynomials
+//! `l(X)`/`r(X)`, committed to at a single evaluation point `x`, and the
+//! resulting inner product `<l, r> = t_hat` is proven via the logarithmic
+//! recursive inner-product argument (IPA) instead of sending `l`/`r` in the
+//! clear. All challenges are derived via Fiat-Shamir from a transcript
+//! seeded with the caller-supplied nonce, so no interaction is required.
+//!
+//! `n` (the bit width) must be a power of two, as required by the IPA's
+//! halving recursion.
+
+use anyhow::{anyhow, Result};
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use crate::classical::curve25519::scalar_to_point;
+use crate::hashing::hash_blake3;
+
+/// Generator basis for a range proof: a value generator `g`, a blinding
+/// generator `h`, a dedicated inner-product generator `u`, and two
+/// length-`n` vectors `gs`/`hs` used for the bit-vector commitments, all
+/// derived deterministically from a domain tag.
+#[derive(Clone, Debug)]
+pub struct BpGenerators {
+    g: RistrettoPoint,
+    h: RistrettoPoint,
+    u: RistrettoPoint,
+    gs: Vec<RistrettoPoint>,
+    hs: Vec<RistrettoPoint>,
+}
+
+impl BpGenerators {
+    /// Derive generators supporting range proofs over `n_bits` bits.
+    /// `n_bits` must be a power of two.
+    pub fn derive(domain: &str, n_bits: usize) -> Result<Self> {
+        if n_bits == 0 || !n_bits.is_power_of_two() {
+            return Err(anyhow!("n_bits must be a non-zero power of two, got {}", n_bits));
+        }
+        let g = derive_point(domain, 0);
+        let h = derive_point(domain, 1);
+        let u = derive_point(domain, 2);
+        let gs = (0..n_bits).map(|i| derive_point(domain, 10 + i as u64)).collect();
+        let hs = (0..n_bits).map(|i| derive_point(domain, 10_000 + i as u64)).collect();
+        Ok(Self { g, h, u, gs, hs })
+    }
+
+    /// Pedersen-commit to `value` under blinding `gamma`: `V = value*g + gamma*h`
+    pub fn commit(&self, value: u64, gamma: &Scalar) -> RistrettoPoint {
+        scalar_from_u64(value) * self.g + gamma * self.h
+    }
+}
+
+fn derive_point(domain: &str, tag: u64) -> RistrettoPoint {
+    let mut data = domain.as_bytes().to_vec();
+    data.extend_from_slice(&tag.to_le_bytes());
+    scalar_to_point(&hash_blake3(&data))
+}
+
+fn scalar_from_u64(value: u64) -> Scalar {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&value.to_le_bytes());
+    Scalar::from_bytes_mod_order(bytes)
+}
+
+fn random_scalar() -> Scalar {
+    let mut wide = [0u8; 64];
+    OsRng.fill_bytes(&mut wide);
+    Scalar::from_bytes_mod_order_wide(&wide)
+}
+
+/// Sample a fresh Pedersen blinding factor for [`BpGenerators::commit`] /
+/// [`prove_range`], without callers needing a direct `curve25519-dalek`
+/// dependency of their own.
+pub fn random_blinding() -> Scalar {
+    random_scalar()
+}
+
+fn scalar_from_hash(bytes: &[u8; 32]) -> Scalar {
+    let mut wide = [0u8; 64];
+    wide[..32].copy_from_slice(bytes);
+    Scalar::from_bytes_mod_order_wide(&wide)
+}
+
+fn decompress(bytes: &[u8; 32]) -> Result<RistrettoPoint> {
+    CompressedRistretto(*bytes)
+        .decompress()
+        .ok_or_else(|| anyhow!("Invalid Ristretto point encoding"))
+}
+
+fn inner_product(a: &[Scalar], b: &[Scalar]) -> Scalar {
+    a.iter().zip(b.iter()).fold(scalar_from_u64(0), |acc, (x, y)| acc + x * y)
+}
+
+fn sum_scalars(xs: &[Scalar]) -> Scalar {
+    xs.iter().fold(scalar_from_u64(0), |acc, x| acc + x)
+}
+
+fn multiexp(scalars: &[Scalar], points: &[RistrettoPoint]) -> RistrettoPoint {
+    scalars.iter().zip(points.iter()).map(|(s, p)| s * p).sum()
+}
+
+fn vec_add(a: &[Scalar], b: &[Scalar]) -> Vec<Scalar> {
+    a.iter().zip(b.iter()).map(|(x, y)| x + y).collect()
+}
+
+/// Fiat-Shamir transcript: an append-only byte log whose running hash
+/// derives each successive challenge scalar.
+struct Transcript(Vec<u8>);
+
+impl Transcript {
+    fn new(nonce: &[u8]) -> Self {
+        Self(nonce.to_vec())
+    }
+
+    fn append(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn challenge_scalar(&mut self) -> Scalar {
+        let digest = hash_blake3(&self.0);
+        self.0.extend_from_slice(&digest);
+        scalar_from_hash(&digest)
+    }
+}
+
+/// A logarithmic-size proof that the prover knows vectors `a`, `b` with
+/// `<a, b> = t_hat` opening a Pedersen-style vector commitment, produced by
+/// recursively halving the generator vectors (Bulletproofs IPA).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct InnerProductProof {
+    l_vec: Vec<[u8; 32]>,
+    r_vec: Vec<[u8; 32]>,
+    a: [u8; 32],
+    b: [u8; 32],
+}
+
+fn ipa_prove(
+    transcript: &mut Transcript,
+    mut a: Vec<Scalar>,
+    mut b: Vec<Scalar>,
+    mut gs: Vec<RistrettoPoint>,
+    mut hs: Vec<RistrettoPoint>,
+    u: &RistrettoPoint,
+) -> InnerProductProof {
+    let mut l_vec = Vec::new();
+    let mut r_vec = Vec::new();
+
+    while a.len() > 1 {
+        let n = a.len() / 2;
+        let (a_lo, a_hi) = a.split_at(n);
+        let (b_lo, b_hi) = b.split_at(n);
+        let (g_lo, g_hi) = gs.split_at(n);
+        let (h_lo, h_hi) = hs.split_at(n);
+
+        let c_l = inner_product(a_lo, b_hi);
+        let c_r = inner_product(a_hi, b_lo);
+        let l = multiexp(a_lo, g_hi) + multiexp(b_hi, h_lo) + c_l * u;
+        let r = multiexp(a_hi, g_lo) + multiexp(b_lo, h_hi) + c_r * u;
+
+        transcript.append(&l.compress().to_bytes());
+        transcript.append(&r.compress().to_bytes());
+        let x = transcript.challenge_scalar();
+        let x_inv = x.invert();
+
+        a = vec_add(&a_lo.iter().map(|v| x * v).collect::<Vec<_>>(), &a_hi.iter().map(|v| x_inv * v).collect::<Vec<_>>());
+        b = vec_add(&b_lo.iter().map(|v| x_inv * v).collect::<Vec<_>>(), &b_hi.iter().map(|v| x * v).collect::<Vec<_>>());
+        gs = (0..n).map(|i| x_inv * g_lo[i] + x * g_hi[i]).collect();
+        hs = (0..n).map(|i| x * h_lo[i] + x_inv * h_hi[i]).collect();
+
+        l_vec.push(l.compress().to_bytes());
+        r_vec.push(r.compress().to_bytes());
+    }
+
+    InnerProductProof { l_vec, r_vec, a: a[0].to_bytes(), b: b[0].to_bytes() }
+}
+
+fn ipa_verify(
+    transcript: &mut Transcript,
+    proof: &InnerProductProof,
+    mut gs: Vec<RistrettoPoint>,
+    mut hs: Vec<RistrettoPoint>,
+    u: &RistrettoPoint,
+    mut p: RistrettoPoint,
+) -> Result<bool> {
+    if proof.l_vec.len() != proof.r_vec.len() {
+        return Ok(false);
+    }
+
+    for (l_bytes, r_bytes) in proof.l_vec.iter().zip(proof.r_vec.iter()) {
+        let n = gs.len() / 2;
+        if n == 0 {
+            return Ok(false);
+        }
+        let l = decompress(l_bytes)?;
+        let r = decompress(r_bytes)?;
+
+        transcript.append(l_bytes);
+        transcript.append(r_bytes);
+        let x = transcript.challenge_scalar();
+        let x_inv = x.invert();
+
+        let (g_lo, g_hi) = gs.split_at(n);
+        let (h_lo, h_hi) = hs.split_at(n);
+        gs = (0..n).map(|i| x_inv * g_lo[i] + x * g_hi[i]).collect();
+        hs = (0..n).map(|i| x * h_lo[i] + x_inv * h_hi[i]).collect();
+
+        p = x * x * l + p + x_inv * x_inv * r;
+    }
+
+    if gs.len() != 1 {
+        return Ok(false);
+    }
+
+    let a = scalar_from_hash(&proof.a);
+    let b = scalar_from_hash(&proof.b);
+    let expected = a * gs[0] + b * hs[0] + (a * b) * u;
+    Ok(expected == p)
+}
+
+/// A range proof that a Pedersen commitment opens to a value in `[0, 2^n)`,
+/// produced by [`prove_range`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RangeProof {
+    /// `V = value*g + gamma*h`, the commitment being proven in-range
+    pub commitment: [u8; 32],
+    a: [u8; 32],
+    s: [u8; 32],
+    t1: [u8; 32],
+    t2: [u8; 32],
+    t_hat: [u8; 32],
+    tau_x: [u8; 32],
+    mu: [u8; 32],
+    ipp: InnerProductProof,
+    n_bits: usize,
+}
+
+/// Prove that `value` (committed under `gamma`) lies in `[0, 2^n_bits)`.
+/// `nonce` binds the proof to a specific challenge/session via Fiat-Shamir.
+pub fn prove_range(generators: &BpGenerators, value: u64, n_bits: usize, gamma: &Scalar, nonce: &[u8]) -> Result<RangeProof> {
+    if generators.gs.len() != n_bits || generators.hs.len() != n_bits {
+        return Err(anyhow!("Generator set does not match n_bits={}", n_bits));
+    }
+    if n_bits < 64 && value >= (1u64 << n_bits) {
+        return Err(anyhow!("Value {} does not fit in {} bits", value, n_bits));
+    }
+
+    let commitment = generators.commit(value, gamma);
+
+    let a_l: Vec<Scalar> = (0..n_bits).map(|i| scalar_from_u64((value >> i) & 1)).collect();
+    let one = scalar_from_u64(1);
+    let a_r: Vec<Scalar> = a_l.iter().map(|b| *b - one).collect();
+
+    let alpha = random_scalar();
+    let a_commit = alpha * generators.h + multiexp(&a_l, &generators.gs) + multiexp(&a_r, &generators.hs);
+
+    let s_l: Vec<Scalar> = (0..n_bits).map(|_| random_scalar()).collect();
+    let s_r: Vec<Scalar> = (0..n_bits).map(|_| random_scalar()).collect();
+    let rho = random_scalar();
+    let s_commit = rho * generators.h + multiexp(&s_l, &generators.gs) + multiexp(&s_r, &generators.hs);
+
+    let mut transcript = Transcript::new(nonce);
+    transcript.append(&commitment.compress().to_bytes());
+    transcript.append(&a_commit.compress().to_bytes());
+    transcript.append(&s_commit.compress().to_bytes());
+    let y = transcript.challenge_scalar();
+    let z = transcript.challenge_scalar();
+
+    let y_n = powers(y, n_bits);
+    let twos = powers(scalar_from_u64(2), n_bits);
+    let z2 = z * z;
+
+    let l0: Vec<Scalar> = a_l.iter().map(|v| *v - z).collect();
+    let r0: Vec<Scalar> = (0..n_bits).map(|i| y_n[i] * (a_r[i] + z) + z2 * twos[i]).collect();
+    let l1 = s_l.clone();
+    let r1: Vec<Scalar> = (0..n_bits).map(|i| y_n[i] * s_r[i]).collect();
+
+    let t0 = inner_product(&l0, &r0);
+    let t1 = inner_product(&l0, &r1) + inner_product(&l1, &r0);
+    let t2 = inner_product(&l1, &r1);
+
+    let tau1 = random_scalar();
+    let tau2 = random_scalar();
+    let t1_commit = t1 * generators.g + tau1 * generators.h;
+    let t2_commit = t2 * generators.g + tau2 * generators.h;
+
+    transcript.append(&t1_commit.compress().to_bytes());
+    transcript.append(&t2_commit.compress().to_bytes());
+    let x = transcript.challenge_scalar();
+
+    let l: Vec<Scalar> = (0..n_bits).map(|i| l0[i] + x * l1[i]).collect();
+    let r: Vec<Scalar> = (0..n_bits).map(|i| r0[i] + x * r1[i]).collect();
+    let t_hat = inner_product(&l, &r);
+    debug_assert_eq!(t_hat, t0 + x * t1 + x * x * t2);
+
+    let tau_x = tau2 * x * x + tau1 * x + z2 * gamma;
+    let mu = alpha + rho * x;
+
+    // H'_i = y_n[i]^-1 * H_i so the inner-product relation holds over
+    // (l, r) directly; see module docs.
+    let y_n_inv: Vec<Scalar> = y_n.iter().map(|y| y.invert()).collect();
+    let hs_prime: Vec<RistrettoPoint> = generators.hs.iter().zip(y_n_inv.iter()).map(|(h, yi)| yi * h).collect();
+
+    transcript.append(&t_hat.to_bytes());
+    transcript.append(&tau_x.to_bytes());
+    transcript.append(&mu.to_bytes());
+    let ipp = ipa_prove(&mut transcript, l, r, generators.gs.clone(), hs_prime, &generators.u);
+
+    Ok(RangeProof {
+        commitment: commitment.compress().to_bytes(),
+        a: a_commit.compress().to_bytes(),
+        s: s_commit.compress().to_bytes(),
+        t1: t1_commit.compress().to_bytes(),
+        t2: t2_commit.compress().to_bytes(),
+        t_hat: t_hat.to_bytes(),
+        tau_x: tau_x.to_bytes(),
+        mu: mu.to_bytes(),
+        ipp,
+        n_bits,
+    })
+}
+
+fn powers(base: Scalar, n: usize) -> Vec<Scalar> {
+    let mut out = Vec::with_capacity(n);
+    let mut acc = scalar_from_u64(1);
+    for _ in 0..n {
+        out.push(acc);
+        acc *= base;
+    }
+    out
+}
+
+/// Verify a [`RangeProof`] against the same generator basis and nonce used
+/// to produce it. Returns `true` only if `proof.commitment` opens to some
+/// value in `[0, 2^n_bits)`; the value itself is never learned.
+pub fn verify_range(generators: &BpGenerators, proof: &RangeProof, nonce: &[u8]) -> Result<bool> {
+    if proof.n_bits != generators.gs.len() || proof.n_bits != generators.hs.len() {
+        return Ok(false);
+    }
+
+    let commitment = decompress(&proof.commitment)?;
+    let a_commit = decompress(&proof.a)?;
+    let s_commit = decompress(&proof.s)?;
+    let t1_commit = decompress(&proof.t1)?;
+    let t2_commit = decompress(&proof.t2)?;
+
+    let mut transcript = Transcript::new(nonce);
+    transcript.append(&proof.commitment);
+    transcript.append(&proof.a);
+    transcript.append(&proof.s);
+    let y = transcript.challenge_scalar();
+    let z = transcript.challenge_scalar();
+
+    transcript.append(&proof.t1);
+    transcript.append(&proof.t2);
+    let x = transcript.challenge_scalar();
+
+    let t_hat = scalar_from_hash(&proof.t_hat);
+    let tau_x = scalar_from_hash(&proof.tau_x);
+    let mu = scalar_from_hash(&proof.mu);
+
+    let n_bits = proof.n_bits;
+    let y_n = powers(y, n_bits);
+    let twos = powers(scalar_from_u64(2), n_bits);
+    let z2 = z * z;
+    let z3 = z2 * z;
+    let sum_y_n: Scalar = sum_scalars(&y_n);
+    let sum_twos: Scalar = sum_scalars(&twos);
+    let delta = (z - z2) * sum_y_n - z3 * sum_twos;
+
+    // t_hat*g + tau_x*h =?= z^2*V + delta*g + x*T1 + x^2*T2
+    let lhs = t_hat * generators.g + tau_x * generators.h;
+    let rhs = z2 * commitment + delta * generators.g + x * t1_commit + (x * x) * t2_commit;
+    if lhs != rhs {
+        return Ok(false);
+    }
+
+    let y_n_inv: Vec<Scalar> = y_n.iter().map(|v| v.invert()).collect();
+    let hs_prime: Vec<RistrettoPoint> = generators.hs.iter().zip(y_n_inv.iter()).map(|(h, yi)| yi * h).collect();
+
+    let sum_gs: RistrettoPoint = generators.gs.iter().sum();
+    let sum_hs: RistrettoPoint = generators.hs.iter().sum();
+    let z2_twos_over_y: Vec<Scalar> = (0..n_bits).map(|i| z2 * twos[i] * y_n_inv[i]).collect();
+
+    // P = A + x*S - mu*h - z*sum(gs) + z*sum(hs) + z^2*<twos/y, hs>
+    let p = a_commit + x * s_commit - mu * generators.h - z * sum_gs + z * sum_hs
+        + multiexp(&z2_twos_over_y, &generators.hs);
+
+    transcript.append(&proof.t_hat);
+    transcript.append(&proof.tau_x);
+    transcript.append(&proof.mu);
+
+    let p_ipa = p + t_hat * generators.u;
+    ipa_verify(&mut transcript, &proof.ipp, generators.gs.clone(), hs_prime, &generators.u, p_ipa)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_proof_round_trip() -> Result<()> {
+        let generators = BpGenerators::derive("zhtp-bp-test", 8)?;
+        let gamma = random_scalar();
+        let proof = prove_range(&generators, 42, 8, &gamma, b"session-nonce")?;
+        assert!(verify_range(&generators, &proof, b"session-nonce")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_proof_rejects_wrong_nonce() -> Result<()> {
+        let generators = BpGenerators::derive("zhtp-bp-test-nonce", 8)?;
+        let gamma = random_scalar();
+        let proof = prove_range(&generators, 7, 8, &gamma, b"nonce-a")?;
+        assert!(!verify_range(&generators, &proof, b"nonce-b")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_out_of_range_rejected_at_proving_time() {
+        let generators = BpGenerators::derive("zhtp-bp-test-range", 8).unwrap();
+        let gamma = random_scalar();
+        assert!(prove_range(&generators, 256, 8, &gamma, b"n").is_err());
+    }
+}
@@ -4,7 +4,11 @@
 
 pub mod ring_signature;
 pub mod multisig;
+pub mod bbs_plus;
+pub mod bulletproofs;
 
 // Re-export main types and functions
 pub use ring_signature::*;
 pub use multisig::*;
+pub use bbs_plus::{BbsCredential, BbsGenerators, BbsIssuerKeyPair, BbsProof, verify_proof as verify_bbs_proof};
+pub use bulletproofs::{BpGenerators, RangeProof, prove_range, verify_range, random_blinding as random_bp_blinding};
@@ -0,0 +1,109 @@
+//! RS256/ES256 JWS signature verification for OIDC ID tokens
+//!
+//! Verifies the `header.payload` signing input of a JWT against the
+//! provider's published JWKS key material, so an OIDC caller never has
+//! to trust a token's claims without first proving the configured
+//! provider's own key produced the signature.
+
+use anyhow::Result;
+use p256::ecdsa::{signature::Verifier, Signature as P256Signature, VerifyingKey as P256VerifyingKey};
+use rsa::pkcs1v15::Pkcs1v15Sign;
+use rsa::pkcs8::DecodePublicKey;
+use rsa::RsaPublicKey;
+use sha2::{Digest, Sha256};
+
+/// Verify an RS256 (RSASSA-PKCS1-v1_5 using SHA-256) JWS signature over
+/// `signing_input` (the `base64url(header) + "." + base64url(payload)`
+/// bytes). `key_material` is the signer's RSA public key, DER-encoded as
+/// a SubjectPublicKeyInfo.
+pub fn verify_rs256(signing_input: &[u8], signature: &[u8], key_material: &[u8]) -> Result<bool> {
+    let public_key = RsaPublicKey::from_public_key_der(key_material)
+        .map_err(|e| anyhow::anyhow!("Invalid RS256 key material: {}", e))?;
+    let digest = Sha256::digest(signing_input);
+    let scheme = Pkcs1v15Sign::new::<Sha256>();
+    Ok(public_key.verify(scheme, &digest, signature).is_ok())
+}
+
+/// Verify an ES256 (ECDSA using the P-256 curve and SHA-256) JWS
+/// signature over `signing_input`. `key_material` is the signer's public
+/// key as an uncompressed SEC1 point (`0x04 || X || Y`, 65 bytes).
+pub fn verify_es256(signing_input: &[u8], signature: &[u8], key_material: &[u8]) -> Result<bool> {
+    let verifying_key = P256VerifyingKey::from_sec1_bytes(key_material)
+        .map_err(|e| anyhow::anyhow!("Invalid ES256 key material: {}", e))?;
+    let ecdsa_signature = P256Signature::from_slice(signature)
+        .map_err(|e| anyhow::anyhow!("Invalid ES256 signature encoding: {}", e))?;
+    Ok(verifying_key.verify(signing_input, &ecdsa_signature).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::{signature::Signer, SigningKey as P256SigningKey};
+    use rand::rngs::OsRng;
+    use rsa::pkcs8::EncodePublicKey;
+    use rsa::signature::{SignatureEncoding, Signer as RsaSigner};
+    use rsa::{pkcs1v15::SigningKey as RsaSigningKey, RsaPrivateKey};
+
+    #[test]
+    fn test_verify_es256_roundtrip() {
+        let signing_key = P256SigningKey::random(&mut OsRng);
+        let key_material = signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+
+        let signing_input = b"header.payload";
+        let signature: P256Signature = signing_key.sign(signing_input);
+
+        assert!(verify_es256(signing_input, &signature.to_bytes(), &key_material).unwrap());
+    }
+
+    #[test]
+    fn test_verify_es256_rejects_tampered_payload() {
+        let signing_key = P256SigningKey::random(&mut OsRng);
+        let key_material = signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+
+        let signature: P256Signature = signing_key.sign(b"header.payload");
+
+        assert!(!verify_es256(b"header.tampered", &signature.to_bytes(), &key_material).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rs256_roundtrip() {
+        let private_key = RsaPrivateKey::new(&mut OsRng, 2048).unwrap();
+        let key_material = private_key
+            .to_public_key()
+            .to_public_key_der()
+            .unwrap()
+            .as_bytes()
+            .to_vec();
+
+        let signing_key = RsaSigningKey::<Sha256>::new(private_key);
+        let signing_input = b"header.payload";
+        let signature = signing_key.sign(signing_input);
+
+        assert!(verify_rs256(signing_input, &signature.to_bytes(), &key_material).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rs256_rejects_wrong_key() {
+        let signing_key = RsaSigningKey::<Sha256>::new(RsaPrivateKey::new(&mut OsRng, 2048).unwrap());
+        let other_public_der = RsaPrivateKey::new(&mut OsRng, 2048)
+            .unwrap()
+            .to_public_key()
+            .to_public_key_der()
+            .unwrap()
+            .as_bytes()
+            .to_vec();
+
+        let signing_input = b"header.payload";
+        let signature = signing_key.sign(signing_input);
+
+        assert!(!verify_rs256(signing_input, &signature.to_bytes(), &other_public_der).unwrap());
+    }
+}
@@ -1,10 +1,19 @@
 //! Classical cryptography compatibility module
-//! 
-//! Ed25519 and Curve25519 operations for legacy compatibility and ring signatures
+//!
+//! Ed25519 and Curve25519 operations for legacy compatibility and ring
+//! signatures, plus X25519 Diffie-Hellman key exchange, secp256k1 ECDSA
+//! recovery for Ethereum-style wallet signatures, and RS256/ES256 JWS
+//! verification for OIDC ID tokens
 
 pub mod ed25519;
 pub mod curve25519;
+pub mod x25519;
+pub mod secp256k1;
+pub mod jwt_verify;
 
 // Re-export main functions
 pub use ed25519::*;
 pub use curve25519::*;
+pub use x25519::*;
+pub use secp256k1::*;
+pub use jwt_verify::*;
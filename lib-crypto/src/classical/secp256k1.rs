@@ -0,0 +1,173 @@
+//! secp256k1 ECDSA recovery for Ethereum-style wallet signatures
+//!
+//! Lets an Ethereum wallet address act as a guardian: verifies a SIWE
+//! (EIP-4361) message signed the way MetaMask's `personal_sign` does
+//! (EIP-191 prefixed, then Keccak-256 hashed) by recovering the signer's
+//! public key from the 65-byte `{r, s, v}` signature and deriving the
+//! address from it, rather than checking a ZHTP post-quantum signature.
+
+use anyhow::Result;
+use k256::ecdsa::{RecoveryId, Signature as K256Signature, VerifyingKey};
+use sha3::{Digest, Keccak256};
+
+/// Keccak-256 hash (distinct from SHA3-256: Ethereum uses the original
+/// Keccak padding, not the later NIST SHA-3 standard used elsewhere in
+/// this crate's `hashing::sha3` module)
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Hash a message the way `personal_sign`/MetaMask does: prefixed with
+/// `"\x19Ethereum Signed Message:\n" + len` before hashing, so a signed
+/// SIWE message can never collide with a raw transaction signature
+pub fn eip191_hash(message: &[u8]) -> [u8; 32] {
+    let mut prefixed = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    prefixed.extend_from_slice(message);
+    keccak256(&prefixed)
+}
+
+/// Recover the 20-byte Ethereum address that produced `signature` (65
+/// bytes: `r || s || v`, with `v` as 0/1 or the legacy 27/28) over
+/// `message_hash`
+pub fn recover_eth_address(message_hash: &[u8; 32], signature: &[u8]) -> Result<[u8; 20]> {
+    if signature.len() != 65 {
+        return Err(anyhow::anyhow!(
+            "Invalid signature length: expected 65 bytes, got {}",
+            signature.len()
+        ));
+    }
+
+    let (rs, v) = signature.split_at(64);
+    let recovery_byte = match v[0] {
+        0 | 1 => v[0],
+        27 | 28 => v[0] - 27,
+        other => return Err(anyhow::anyhow!("Invalid recovery id byte: {}", other)),
+    };
+
+    let ecdsa_signature =
+        K256Signature::from_slice(rs).map_err(|e| anyhow::anyhow!("Invalid ECDSA signature: {}", e))?;
+    let recovery_id = RecoveryId::from_byte(recovery_byte)
+        .ok_or_else(|| anyhow::anyhow!("Invalid recovery id"))?;
+
+    let verifying_key = VerifyingKey::recover_from_prehash(message_hash, &ecdsa_signature, recovery_id)
+        .map_err(|e| anyhow::anyhow!("Failed to recover public key: {}", e))?;
+
+    Ok(eth_address_from_verifying_key(&verifying_key))
+}
+
+/// Derive the 20-byte Ethereum address from a public key: the last 20
+/// bytes of the Keccak-256 hash of the uncompressed 64-byte `X || Y` point
+fn eth_address_from_verifying_key(verifying_key: &VerifyingKey) -> [u8; 20] {
+    let uncompressed = verifying_key.to_encoded_point(false);
+    let pubkey_bytes = &uncompressed.as_bytes()[1..]; // strip the 0x04 tag byte
+    let hash = keccak256(pubkey_bytes);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Parse a `0x`-prefixed (or bare) hex Ethereum address, case-insensitively
+pub fn parse_eth_address(address: &str) -> Result<[u8; 20]> {
+    let stripped = address.strip_prefix("0x").unwrap_or(address);
+    let bytes = hex::decode(stripped).map_err(|e| anyhow::anyhow!("Invalid Ethereum address hex: {}", e))?;
+    if bytes.len() != 20 {
+        return Err(anyhow::anyhow!("Invalid Ethereum address length"));
+    }
+    let mut out = [0u8; 20];
+    out.copy_from_slice(&bytes);
+    Ok(out)
+}
+
+/// Render a 20-byte address as an EIP-55 checksummed `0x...` string
+pub fn to_checksum_address(address: &[u8; 20]) -> String {
+    let hex_address = hex::encode(address);
+    let hash = keccak256(hex_address.as_bytes());
+
+    let mut checksummed = String::with_capacity(42);
+    checksummed.push_str("0x");
+    for (i, c) in hex_address.chars().enumerate() {
+        if !c.is_ascii_alphabetic() {
+            checksummed.push(c);
+            continue;
+        }
+        // The nibble of the hash at this position decides upper vs lower case
+        let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+        if nibble >= 8 {
+            checksummed.push(c.to_ascii_uppercase());
+        } else {
+            checksummed.push(c);
+        }
+    }
+    checksummed
+}
+
+/// Verify that `signature` over `message_hash` was produced by the wallet
+/// at `expected_address` (compared as EIP-55 checksummed addresses, so
+/// case differences in the input don't matter)
+pub fn verify_eth_signature(
+    message_hash: &[u8; 32],
+    signature: &[u8],
+    expected_address: &str,
+) -> Result<bool> {
+    let recovered = recover_eth_address(message_hash, signature)?;
+    let expected = parse_eth_address(expected_address)?;
+    Ok(to_checksum_address(&recovered) == to_checksum_address(&expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::SigningKey;
+
+    #[test]
+    fn test_checksum_address_vectors() {
+        // EIP-55 reference test vectors
+        assert_eq!(
+            to_checksum_address(&parse_eth_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap()),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(
+            to_checksum_address(&parse_eth_address("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359").unwrap()),
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+        );
+    }
+
+    #[test]
+    fn test_recover_eth_address_roundtrip() -> Result<()> {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32].into())?;
+        let expected_address = eth_address_from_verifying_key(signing_key.verifying_key());
+
+        let message_hash = eip191_hash(b"ZHTP guardian SIWE test message");
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&message_hash)?;
+
+        let mut signature_bytes = [0u8; 65];
+        signature_bytes[..64].copy_from_slice(&signature.to_bytes());
+        signature_bytes[64] = recovery_id.to_byte();
+
+        let recovered = recover_eth_address(&message_hash, &signature_bytes)?;
+        assert_eq!(recovered, expected_address);
+
+        let recovered_address = to_checksum_address(&recovered);
+        assert!(verify_eth_signature(&message_hash, &signature_bytes, &recovered_address)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_wrong_address() -> Result<()> {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32].into())?;
+        let message_hash = eip191_hash(b"ZHTP guardian SIWE test message");
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&message_hash)?;
+
+        let mut signature_bytes = [0u8; 65];
+        signature_bytes[..64].copy_from_slice(&signature.to_bytes());
+        signature_bytes[64] = recovery_id.to_byte();
+
+        let wrong_address = "0x0000000000000000000000000000000000000000"; // 20 zero bytes
+        assert!(!verify_eth_signature(&message_hash, &signature_bytes, wrong_address)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+//! X25519 Diffie-Hellman key exchange
+//!
+//! Separate from the Ristretto-based curve operations in `curve25519.rs`
+//! (ring signatures use the Ristretto group; key exchange uses the
+//! Montgomery form specified by RFC 7748).
+
+use anyhow::Result;
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use rand::{RngCore, rngs::OsRng};
+
+/// Generate an X25519 keypair (public, secret), both 32 bytes
+pub fn x25519_keypair() -> ([u8; 32], [u8; 32]) {
+    let mut secret = [0u8; 32];
+    OsRng.fill_bytes(&mut secret);
+    let public = MontgomeryPoint::mul_base_clamped(secret).to_bytes();
+    (public, secret)
+}
+
+/// Derive the public key matching an X25519 secret key
+pub fn x25519_public_from_secret(secret: &[u8; 32]) -> [u8; 32] {
+    MontgomeryPoint::mul_base_clamped(*secret).to_bytes()
+}
+
+/// Perform an X25519 Diffie-Hellman exchange, producing a shared secret
+pub fn x25519_diffie_hellman(secret: &[u8; 32], peer_public: &[u8; 32]) -> Result<[u8; 32]> {
+    let peer_point = MontgomeryPoint(*peer_public);
+    let shared = peer_point.mul_clamped(*secret);
+
+    if shared.to_bytes() == [0u8; 32] {
+        return Err(anyhow::anyhow!("X25519 exchange produced a contributory all-zero shared secret"));
+    }
+
+    Ok(shared.to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x25519_exchange_agrees_on_both_sides() {
+        let (server_public, server_secret) = x25519_keypair();
+        let (client_public, client_secret) = x25519_keypair();
+
+        let server_shared = x25519_diffie_hellman(&server_secret, &client_public).unwrap();
+        let client_shared = x25519_diffie_hellman(&client_secret, &server_public).unwrap();
+
+        assert_eq!(server_shared, client_shared);
+    }
+
+    #[test]
+    fn test_x25519_public_from_secret_matches_keypair() {
+        let (public, secret) = x25519_keypair();
+        assert_eq!(x25519_public_from_secret(&secret), public);
+    }
+}
@@ -18,6 +18,7 @@ pub mod verification;
 pub mod hashing;
 pub mod random;
 pub mod seed;
+pub mod session;
 // Note: password module moved to lib-identity/src/auth/password.rs
 
 // Re-export commonly used types and functions
@@ -40,6 +41,9 @@ pub use random::{SecureRng, generate_nonce};
 // Re-export seed functionality
 pub use seed::generate_identity_seed;
 
+// Re-export the authenticated session layer
+pub use session::{HandshakeMessage, RekeyPolicy, SecureSession, SessionIdentity, TransportFrame};
+
 // Re-export keypair functionality
 pub use keypair::generation::KeyPair;
 
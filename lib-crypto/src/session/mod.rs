@@ -0,0 +1,15 @@
+//! Authenticated session layer built on top of `SecureRng`
+//!
+//! Provides a Noise-inspired mutually authenticated, encrypted channel
+//! between two nodes, each holding a static X25519 keypair and a set of
+//! trusted peer public keys. See [`secure_session`] for the handshake and
+//! transport implementation and [`replay_window`] for the anti-replay
+//! filter used on the receive side.
+
+pub mod replay_window;
+pub mod secure_session;
+
+pub use replay_window::ReplayWindow;
+pub use secure_session::{
+    HandshakeMessage, RekeyPolicy, SecureSession, SessionIdentity, TransportFrame,
+};
@@ -0,0 +1,118 @@
+//! 64-bit sliding-window anti-replay filter
+//!
+//! Tracks the highest transport message counter seen plus a bitmap of the
+//! 64 counters below it, so messages may arrive out of order over an
+//! unreliable transport while replays and counters too old to track are
+//! still rejected. Modeled on the anti-replay windows used by IPsec/WireGuard.
+
+/// Sliding window over the most recently seen 64 message counters
+#[derive(Debug, Clone, Default)]
+pub struct ReplayWindow {
+    highest: Option<u64>,
+    seen: u64,
+}
+
+impl ReplayWindow {
+    /// Create an empty window that has not seen any counter yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `counter` would currently be accepted: not already seen and
+    /// not older than the tracked window. Does not mutate the window, so
+    /// callers can check freshness before committing to an expensive
+    /// operation (like AEAD decryption) and only mark it seen on success.
+    pub fn is_fresh(&self, counter: u64) -> bool {
+        match self.highest {
+            None => true,
+            Some(highest) => {
+                if counter > highest {
+                    true
+                } else {
+                    let age = highest - counter;
+                    age < 64 && self.seen & (1u64 << age) == 0
+                }
+            }
+        }
+    }
+
+    /// Record `counter` as seen, sliding the window forward if it is a new
+    /// high-water mark. Safe to call with a stale counter (no-op beyond
+    /// the window).
+    pub fn mark_seen(&mut self, counter: u64) {
+        match self.highest {
+            None => {
+                self.highest = Some(counter);
+                self.seen = 1;
+            }
+            Some(highest) => {
+                if counter > highest {
+                    let shift = counter - highest;
+                    self.seen = if shift >= 64 { 0 } else { self.seen << shift };
+                    self.seen |= 1;
+                    self.highest = Some(counter);
+                } else {
+                    let age = highest - counter;
+                    if age < 64 {
+                        self.seen |= 1u64 << age;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check freshness and mark in one step; returns whether `counter` was
+    /// accepted
+    pub fn check_and_mark(&mut self, counter: u64) -> bool {
+        if self.is_fresh(counter) {
+            self.mark_seen(counter);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_strictly_increasing_counters() {
+        let mut window = ReplayWindow::new();
+        for i in 0..10 {
+            assert!(window.check_and_mark(i));
+        }
+    }
+
+    #[test]
+    fn rejects_exact_replay() {
+        let mut window = ReplayWindow::new();
+        assert!(window.check_and_mark(5));
+        assert!(!window.check_and_mark(5));
+    }
+
+    #[test]
+    fn accepts_reordered_messages_within_window() {
+        let mut window = ReplayWindow::new();
+        assert!(window.check_and_mark(10));
+        assert!(window.check_and_mark(8));
+        assert!(window.check_and_mark(9));
+        assert!(!window.check_and_mark(8));
+    }
+
+    #[test]
+    fn rejects_counters_older_than_window() {
+        let mut window = ReplayWindow::new();
+        assert!(window.check_and_mark(1000));
+        assert!(!window.check_and_mark(1000 - 64));
+    }
+
+    #[test]
+    fn large_forward_jump_resets_window() {
+        let mut window = ReplayWindow::new();
+        assert!(window.check_and_mark(5));
+        assert!(window.check_and_mark(5 + 1000));
+        assert!(!window.check_and_mark(5));
+    }
+}
@@ -0,0 +1,623 @@
+//! Noise-inspired authenticated session handshake and transport
+//!
+//! Each node holds a static X25519 keypair plus a set of trusted peer
+//! public keys, configured one of two ways:
+//!
+//! - [`SessionIdentity::SharedSecret`] deterministically derives the
+//!   static keypair from a passphrase via HKDF, so every node sharing the
+//!   passphrase derives the same keypair and implicitly trusts only that
+//!   shared public key.
+//! - [`SessionIdentity::ExplicitTrust`] generates a random static keypair
+//!   via [`SecureRng`] and trusts an externally configured set of peer
+//!   public keys.
+//!
+//! The handshake follows a Noise IK-style pattern: the initiator already
+//! knows the responder's static public key (it must be a member of the
+//! initiator's trusted set), generates an ephemeral keypair, and sends the
+//! ephemeral public key alongside its own static public key encrypted
+//! under a key derived from the ephemeral/responder-static DH. Both sides
+//! mix all four DH combinations (ee, es, se, ss) into a chaining key via
+//! HKDF to derive a pair of directional transport keys, and the responder
+//! aborts if the initiator's decrypted static key is not in its trusted
+//! set. Transport frames are sealed with ChaCha20-Poly1305 under a
+//! per-message counter nonce and checked against a [`ReplayWindow`] on
+//! receipt.
+
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::classical::x25519::{x25519_diffie_hellman, x25519_public_from_secret};
+use crate::kdf::hkdf::derive_keys;
+use crate::random::SecureRng;
+use crate::symmetric::chacha20::{decrypt_data, encrypt_data};
+
+use super::replay_window::ReplayWindow;
+
+const HANDSHAKE_INFO: &[u8] = b"zhtp-secure-session-handshake-v1";
+const CHAIN_INFO: &[u8] = b"zhtp-secure-session-chain-v1";
+const TRANSPORT_INFO: &[u8] = b"zhtp-secure-session-transport-v1";
+const STATIC_KEYPAIR_INFO: &[u8] = b"zhtp-secure-session-static-keypair-v1";
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// How a node's static keypair and trust relationships are configured
+pub enum SessionIdentity {
+    /// All nodes sharing `passphrase` deterministically derive the same
+    /// static keypair via HKDF, and implicitly trust only that shared
+    /// public key.
+    SharedSecret { passphrase: String },
+    /// A randomly generated static keypair with an externally configured
+    /// set of trusted peer public keys.
+    ExplicitTrust { trusted_keys: HashSet<[u8; 32]> },
+}
+
+/// A handshake message: an ephemeral public key plus the sender's static
+/// public key, encrypted under a key derived from the ephemeral DH so the
+/// static key is never sent in the clear.
+#[derive(Debug, Clone)]
+pub struct HandshakeMessage {
+    pub ephemeral_public: [u8; 32],
+    pub encrypted_static: Vec<u8>,
+    pub timestamp: u64,
+}
+
+/// Thresholds that trigger automatic rekeying of an established session
+#[derive(Debug, Clone, Copy)]
+pub struct RekeyPolicy {
+    pub max_messages: u64,
+    pub max_age_secs: u64,
+}
+
+impl Default for RekeyPolicy {
+    fn default() -> Self {
+        Self {
+            max_messages: 1_000_000,
+            max_age_secs: 3600,
+        }
+    }
+}
+
+/// A ChaCha20-Poly1305-sealed transport message, tagged with its sender's
+/// per-direction counter so the receiver can reconstruct the nonce and
+/// check the anti-replay window.
+#[derive(Debug, Clone)]
+pub struct TransportFrame {
+    pub counter: u64,
+    pub ciphertext: Vec<u8>,
+}
+
+struct TransportDirection {
+    key: [u8; 32],
+    counter: u64,
+}
+
+struct TransportKeys {
+    send: TransportDirection,
+    recv: TransportDirection,
+    recv_window: ReplayWindow,
+    established_at: u64,
+}
+
+impl TransportKeys {
+    fn new(send_key: [u8; 32], recv_key: [u8; 32]) -> Self {
+        Self {
+            send: TransportDirection { key: send_key, counter: 0 },
+            recv: TransportDirection { key: recv_key, counter: 0 },
+            recv_window: ReplayWindow::new(),
+            established_at: now_secs(),
+        }
+    }
+}
+
+enum SessionState {
+    /// No handshake has been performed yet
+    Idle,
+    /// A `HandshakeMessage` was sent and we are waiting for the peer's response
+    Initiating {
+        peer_static_public: [u8; 32],
+        ephemeral_secret: [u8; 32],
+    },
+    /// Transport keys are derived and ready for encrypt/decrypt
+    Established {
+        current: TransportKeys,
+        previous: Option<TransportKeys>,
+    },
+}
+
+/// A single authenticated, encrypted session between this node and one peer
+pub struct SecureSession {
+    static_public: [u8; 32],
+    static_secret: [u8; 32],
+    trusted_keys: HashSet<[u8; 32]>,
+    rekey_policy: RekeyPolicy,
+    state: SessionState,
+}
+
+impl SecureSession {
+    /// Build a session from a [`SessionIdentity`]
+    pub fn new(identity: SessionIdentity) -> Result<Self> {
+        let (static_public, static_secret, trusted_keys) = match identity {
+            SessionIdentity::SharedSecret { passphrase } => {
+                let derived = derive_keys(passphrase.as_bytes(), STATIC_KEYPAIR_INFO, 32)?;
+                let mut static_secret = [0u8; 32];
+                static_secret.copy_from_slice(&derived);
+                let static_public = x25519_public_from_secret(&static_secret);
+                let mut trusted_keys = HashSet::new();
+                trusted_keys.insert(static_public);
+                (static_public, static_secret, trusted_keys)
+            }
+            SessionIdentity::ExplicitTrust { trusted_keys } => {
+                let mut rng = SecureRng::new();
+                let static_secret = rng.generate_key_material();
+                let static_public = x25519_public_from_secret(&static_secret);
+                (static_public, static_secret, trusted_keys)
+            }
+        };
+
+        Ok(Self {
+            static_public,
+            static_secret,
+            trusted_keys,
+            rekey_policy: RekeyPolicy::default(),
+            state: SessionState::Idle,
+        })
+    }
+
+    /// Set thresholds for automatic rekeying
+    pub fn with_rekey_policy(mut self, policy: RekeyPolicy) -> Self {
+        self.rekey_policy = policy;
+        self
+    }
+
+    /// This node's static X25519 public key
+    pub fn static_public_key(&self) -> [u8; 32] {
+        self.static_public
+    }
+
+    /// Add a peer's static public key to the trusted set
+    pub fn trust_peer(&mut self, peer_static_public: [u8; 32]) {
+        self.trusted_keys.insert(peer_static_public);
+    }
+
+    /// Whether transport keys have been derived and the session is ready
+    /// to encrypt/decrypt
+    pub fn is_established(&self) -> bool {
+        matches!(self.state, SessionState::Established { .. })
+    }
+
+    /// Encrypt `static_public` under a key derived from the ephemeral DH
+    /// with the recipient's static public key
+    fn seal_handshake_message(
+        static_public: &[u8; 32],
+        ephemeral_secret: &[u8; 32],
+        ephemeral_public: &[u8; 32],
+        peer_static_public: &[u8; 32],
+    ) -> Result<HandshakeMessage> {
+        let temp_dh = x25519_diffie_hellman(ephemeral_secret, peer_static_public)?;
+        let temp_key = derive_keys(&temp_dh, HANDSHAKE_INFO, 32)?;
+        let encrypted_static = encrypt_data(static_public, &temp_key)?;
+        Ok(HandshakeMessage {
+            ephemeral_public: *ephemeral_public,
+            encrypted_static,
+            timestamp: now_secs(),
+        })
+    }
+
+    /// Decrypt a peer's static public key from a `HandshakeMessage`, using
+    /// our own secret (static or ephemeral, matching whichever side of the
+    /// DH the sender used) paired with the sender's ephemeral public key
+    fn open_handshake_static(
+        local_secret: &[u8; 32],
+        peer_ephemeral_public: &[u8; 32],
+        encrypted_static: &[u8],
+    ) -> Result<[u8; 32]> {
+        let temp_dh = x25519_diffie_hellman(local_secret, peer_ephemeral_public)?;
+        let temp_key = derive_keys(&temp_dh, HANDSHAKE_INFO, 32)?;
+        let decrypted = decrypt_data(encrypted_static, &temp_key)?;
+        decrypted
+            .try_into()
+            .map_err(|_| anyhow!("decrypted static key was not 32 bytes"))
+    }
+
+    /// Mix all four DH combinations into directional transport keys
+    fn derive_transport_keys(
+        ee: &[u8; 32],
+        es: &[u8; 32],
+        se: &[u8; 32],
+        ss: &[u8; 32],
+        is_initiator: bool,
+    ) -> Result<TransportKeys> {
+        let mut chaining_material = Vec::with_capacity(128);
+        chaining_material.extend_from_slice(ee);
+        chaining_material.extend_from_slice(es);
+        chaining_material.extend_from_slice(se);
+        chaining_material.extend_from_slice(ss);
+
+        let chaining_key = derive_keys(&chaining_material, CHAIN_INFO, 32)?;
+        let key_material = derive_keys(&chaining_key, TRANSPORT_INFO, 64)?;
+        let (initiator_to_responder, responder_to_initiator) = key_material.split_at(32);
+
+        let (send_key, recv_key) = if is_initiator {
+            (initiator_to_responder, responder_to_initiator)
+        } else {
+            (responder_to_initiator, initiator_to_responder)
+        };
+
+        Ok(TransportKeys::new(
+            send_key.try_into().expect("hkdf output is 32 bytes"),
+            recv_key.try_into().expect("hkdf output is 32 bytes"),
+        ))
+    }
+
+    /// Begin a handshake with a known, trusted peer
+    pub fn initiate_handshake(&mut self, peer_static_public: [u8; 32]) -> Result<HandshakeMessage> {
+        if !self.trusted_keys.contains(&peer_static_public) {
+            return Err(anyhow!("refusing to initiate handshake with an untrusted static key"));
+        }
+
+        let mut rng = SecureRng::new();
+        let ephemeral_secret = rng.generate_key_material();
+        let ephemeral_public = x25519_public_from_secret(&ephemeral_secret);
+
+        let message = Self::seal_handshake_message(
+            &self.static_public,
+            &ephemeral_secret,
+            &ephemeral_public,
+            &peer_static_public,
+        )?;
+
+        self.state = SessionState::Initiating { peer_static_public, ephemeral_secret };
+        Ok(message)
+    }
+
+    /// Respond to an incoming handshake, producing our own `HandshakeMessage`
+    /// and deriving transport keys immediately (the responder completes the
+    /// handshake in one round trip). Also used to respond to a rekey
+    /// handshake, in which case the previous transport keys are retained
+    /// briefly so in-flight packets under them still decrypt.
+    pub fn respond_to_handshake(&mut self, incoming: &HandshakeMessage) -> Result<HandshakeMessage> {
+        let peer_static_public = Self::open_handshake_static(
+            &self.static_secret,
+            &incoming.ephemeral_public,
+            &incoming.encrypted_static,
+        )?;
+
+        if !self.trusted_keys.contains(&peer_static_public) {
+            return Err(anyhow!("peer static key is not in the trusted set"));
+        }
+
+        let mut rng = SecureRng::new();
+        let ephemeral_secret = rng.generate_key_material();
+        let ephemeral_public = x25519_public_from_secret(&ephemeral_secret);
+
+        let response = Self::seal_handshake_message(
+            &self.static_public,
+            &ephemeral_secret,
+            &ephemeral_public,
+            &peer_static_public,
+        )?;
+
+        let ee = x25519_diffie_hellman(&ephemeral_secret, &incoming.ephemeral_public)?;
+        let es = x25519_diffie_hellman(&self.static_secret, &incoming.ephemeral_public)?;
+        let se = x25519_diffie_hellman(&ephemeral_secret, &peer_static_public)?;
+        let ss = x25519_diffie_hellman(&self.static_secret, &peer_static_public)?;
+
+        let transport_keys = Self::derive_transport_keys(&ee, &es, &se, &ss, false)?;
+        let previous = match std::mem::replace(&mut self.state, SessionState::Idle) {
+            SessionState::Established { current, .. } => Some(current),
+            _ => None,
+        };
+        self.state = SessionState::Established { current: transport_keys, previous };
+        Ok(response)
+    }
+
+    /// Complete a handshake we initiated, using the peer's response
+    pub fn complete_handshake(&mut self, response: &HandshakeMessage) -> Result<()> {
+        let (peer_static_public, ephemeral_secret) = match &self.state {
+            SessionState::Initiating { peer_static_public, ephemeral_secret } => {
+                (*peer_static_public, *ephemeral_secret)
+            }
+            _ => return Err(anyhow!("no handshake in progress")),
+        };
+
+        let responder_static = Self::open_handshake_static(
+            &self.static_secret,
+            &response.ephemeral_public,
+            &response.encrypted_static,
+        )?;
+        if responder_static != peer_static_public {
+            return Err(anyhow!(
+                "responder's static key does not match the peer we addressed the handshake to"
+            ));
+        }
+
+        let ee = x25519_diffie_hellman(&ephemeral_secret, &response.ephemeral_public)?;
+        let es = x25519_diffie_hellman(&ephemeral_secret, &peer_static_public)?;
+        let se = x25519_diffie_hellman(&self.static_secret, &response.ephemeral_public)?;
+        let ss = x25519_diffie_hellman(&self.static_secret, &peer_static_public)?;
+
+        let transport_keys = Self::derive_transport_keys(&ee, &es, &se, &ss, true)?;
+        self.state = SessionState::Established { current: transport_keys, previous: None };
+        Ok(())
+    }
+
+    /// Re-run the handshake with the currently established peer. Call
+    /// [`Self::complete_rekey`] with the peer's response to retain the
+    /// previous transport keys briefly so packets already in flight under
+    /// them still decrypt.
+    pub fn rekey(&mut self, peer_static_public: [u8; 32]) -> Result<HandshakeMessage> {
+        if !self.is_established() {
+            return Err(anyhow!("cannot rekey a session that is not established"));
+        }
+        self.initiate_handshake(peer_static_public)
+    }
+
+    /// Complete a rekey handshake we initiated, retaining the previous
+    /// transport keys so in-flight packets still decrypt
+    pub fn complete_rekey(&mut self, response: &HandshakeMessage) -> Result<()> {
+        let (peer_static_public, ephemeral_secret) = match &self.state {
+            SessionState::Initiating { peer_static_public, ephemeral_secret } => {
+                (*peer_static_public, *ephemeral_secret)
+            }
+            _ => return Err(anyhow!("no rekey handshake in progress")),
+        };
+
+        let responder_static = Self::open_handshake_static(
+            &self.static_secret,
+            &response.ephemeral_public,
+            &response.encrypted_static,
+        )?;
+        if responder_static != peer_static_public {
+            return Err(anyhow!(
+                "responder's static key does not match the peer we addressed the rekey to"
+            ));
+        }
+
+        let ee = x25519_diffie_hellman(&ephemeral_secret, &response.ephemeral_public)?;
+        let es = x25519_diffie_hellman(&ephemeral_secret, &peer_static_public)?;
+        let se = x25519_diffie_hellman(&self.static_secret, &response.ephemeral_public)?;
+        let ss = x25519_diffie_hellman(&self.static_secret, &peer_static_public)?;
+
+        let new_keys = Self::derive_transport_keys(&ee, &es, &se, &ss, true)?;
+
+        let previous = match std::mem::replace(&mut self.state, SessionState::Idle) {
+            SessionState::Established { current, .. } => Some(current),
+            _ => None,
+        };
+        self.state = SessionState::Established { current: new_keys, previous };
+        Ok(())
+    }
+
+    /// Whether the established session has crossed this node's rekey policy
+    pub fn needs_rekey(&self) -> bool {
+        match &self.state {
+            SessionState::Established { current, .. } => {
+                current.send.counter >= self.rekey_policy.max_messages
+                    || now_secs().saturating_sub(current.established_at) >= self.rekey_policy.max_age_secs
+            }
+            _ => false,
+        }
+    }
+
+    fn counter_nonce(counter: u64) -> [u8; 12] {
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&counter.to_be_bytes());
+        nonce
+    }
+
+    /// Encrypt a transport message, returning a counter-tagged frame
+    pub fn encrypt_message(&mut self, plaintext: &[u8]) -> Result<TransportFrame> {
+        let current = match &mut self.state {
+            SessionState::Established { current, .. } => current,
+            _ => return Err(anyhow!("session is not established")),
+        };
+
+        let counter = current.send.counter;
+        current.send.counter += 1;
+
+        let nonce_bytes = Self::counter_nonce(counter);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&current.send.key));
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+            .map_err(|e| anyhow!("transport frame encryption failed: {}", e))?;
+
+        Ok(TransportFrame { counter, ciphertext })
+    }
+
+    /// Decrypt a transport frame, rejecting replays and out-of-window
+    /// messages. Tries the current transport keys first, then falls back
+    /// to the previous key set retained briefly after a rekey.
+    pub fn decrypt_message(&mut self, frame: &TransportFrame) -> Result<Vec<u8>> {
+        match &mut self.state {
+            SessionState::Established { current, previous } => {
+                if let Some(plaintext) = Self::try_decrypt(current, frame) {
+                    return Ok(plaintext);
+                }
+                if let Some(previous) = previous {
+                    if let Some(plaintext) = Self::try_decrypt(previous, frame) {
+                        return Ok(plaintext);
+                    }
+                }
+                Err(anyhow!("failed to decrypt transport frame (replay, too old, or wrong key)"))
+            }
+            _ => Err(anyhow!("session is not established")),
+        }
+    }
+
+    /// Attempt decryption under one key set; only marks the replay window
+    /// once authentication has actually succeeded, so a forged frame can't
+    /// be used to burn a legitimate counter out of the window.
+    fn try_decrypt(keys: &mut TransportKeys, frame: &TransportFrame) -> Option<Vec<u8>> {
+        if !keys.recv_window.is_fresh(frame.counter) {
+            return None;
+        }
+        let nonce_bytes = Self::counter_nonce(frame.counter);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(&keys.recv.key));
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce_bytes), frame.ciphertext.as_slice())
+            .ok()?;
+        keys.recv_window.mark_seen(frame.counter);
+        Some(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explicit_pair() -> (SecureSession, SecureSession) {
+        let mut initiator = SecureSession::new(SessionIdentity::ExplicitTrust {
+            trusted_keys: HashSet::new(),
+        })
+        .unwrap();
+        let mut responder = SecureSession::new(SessionIdentity::ExplicitTrust {
+            trusted_keys: HashSet::new(),
+        })
+        .unwrap();
+
+        let initiator_pub = initiator.static_public_key();
+        let responder_pub = responder.static_public_key();
+        initiator.trust_peer(responder_pub);
+        responder.trust_peer(initiator_pub);
+        (initiator, responder)
+    }
+
+    #[test]
+    fn shared_secret_mode_is_deterministic_and_self_trusting() {
+        let a = SecureSession::new(SessionIdentity::SharedSecret {
+            passphrase: "correct horse battery staple".to_string(),
+        })
+        .unwrap();
+        let b = SecureSession::new(SessionIdentity::SharedSecret {
+            passphrase: "correct horse battery staple".to_string(),
+        })
+        .unwrap();
+        assert_eq!(a.static_public_key(), b.static_public_key());
+        assert!(a.trusted_keys.contains(&a.static_public_key()));
+
+        let c = SecureSession::new(SessionIdentity::SharedSecret {
+            passphrase: "different secret".to_string(),
+        })
+        .unwrap();
+        assert_ne!(a.static_public_key(), c.static_public_key());
+    }
+
+    #[test]
+    fn full_handshake_round_trip_establishes_directional_keys() {
+        let (mut initiator, mut responder) = explicit_pair();
+        let responder_pub = responder.static_public_key();
+
+        let init_msg = initiator.initiate_handshake(responder_pub).unwrap();
+        let resp_msg = responder.respond_to_handshake(&init_msg).unwrap();
+        initiator.complete_handshake(&resp_msg).unwrap();
+
+        assert!(initiator.is_established());
+        assert!(responder.is_established());
+
+        let plaintext = b"hello over the secure session";
+        let frame = initiator.encrypt_message(plaintext).unwrap();
+        let decrypted = responder.decrypt_message(&frame).unwrap();
+        assert_eq!(decrypted, plaintext);
+
+        let reply = responder.encrypt_message(b"ack").unwrap();
+        let decrypted_reply = initiator.decrypt_message(&reply).unwrap();
+        assert_eq!(decrypted_reply, b"ack");
+    }
+
+    #[test]
+    fn handshake_with_untrusted_peer_is_rejected() {
+        let mut initiator = SecureSession::new(SessionIdentity::ExplicitTrust {
+            trusted_keys: HashSet::new(),
+        })
+        .unwrap();
+        let responder = SecureSession::new(SessionIdentity::ExplicitTrust {
+            trusted_keys: HashSet::new(),
+        })
+        .unwrap();
+        let result = initiator.initiate_handshake(responder.static_public_key());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn responder_rejects_handshake_from_untrusted_initiator() {
+        let (mut initiator, mut responder) = explicit_pair();
+        responder.trusted_keys.clear();
+
+        let responder_pub = responder.static_public_key();
+        let init_msg = initiator.initiate_handshake(responder_pub).unwrap();
+        let result = responder.respond_to_handshake(&init_msg);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn replayed_transport_frame_is_rejected() {
+        let (mut initiator, mut responder) = explicit_pair();
+        let responder_pub = responder.static_public_key();
+
+        let init_msg = initiator.initiate_handshake(responder_pub).unwrap();
+        let resp_msg = responder.respond_to_handshake(&init_msg).unwrap();
+        initiator.complete_handshake(&resp_msg).unwrap();
+
+        let frame = initiator.encrypt_message(b"one-time message").unwrap();
+        assert!(responder.decrypt_message(&frame).is_ok());
+        assert!(responder.decrypt_message(&frame).is_err());
+    }
+
+    #[test]
+    fn rekey_retains_previous_keys_for_in_flight_messages() {
+        let (mut initiator, mut responder) = explicit_pair();
+        let responder_pub = responder.static_public_key();
+
+        let init_msg = initiator.initiate_handshake(responder_pub).unwrap();
+        let resp_msg = responder.respond_to_handshake(&init_msg).unwrap();
+        initiator.complete_handshake(&resp_msg).unwrap();
+
+        // A message encrypted under the first key set, but not yet delivered
+        let in_flight = initiator.encrypt_message(b"in flight before rekey").unwrap();
+
+        let rekey_init = initiator.rekey(responder_pub).unwrap();
+        let rekey_resp = responder.respond_to_handshake(&rekey_init).unwrap();
+        initiator.complete_rekey(&rekey_resp).unwrap();
+
+        // New messages use the new keys
+        let fresh = initiator.encrypt_message(b"after rekey").unwrap();
+        assert_eq!(responder.decrypt_message(&fresh).unwrap(), b"after rekey");
+
+        // The in-flight packet encrypted under the pre-rekey keys should
+        // still decrypt because the responder retains the previous key set
+        assert_eq!(
+            responder.decrypt_message(&in_flight).unwrap(),
+            b"in flight before rekey"
+        );
+    }
+
+    #[test]
+    fn needs_rekey_triggers_on_message_count_threshold() {
+        let (mut initiator, mut responder) = explicit_pair();
+        let responder_pub = responder.static_public_key();
+
+        let init_msg = initiator
+            .initiate_handshake(responder_pub)
+            .unwrap();
+        let resp_msg = responder.respond_to_handshake(&init_msg).unwrap();
+        initiator.complete_handshake(&resp_msg).unwrap();
+        initiator = initiator.with_rekey_policy(RekeyPolicy { max_messages: 2, max_age_secs: 3600 });
+
+        assert!(!initiator.needs_rekey());
+        initiator.encrypt_message(b"one").unwrap();
+        assert!(!initiator.needs_rekey());
+        initiator.encrypt_message(b"two").unwrap();
+        assert!(initiator.needs_rekey());
+    }
+}
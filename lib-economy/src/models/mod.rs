@@ -5,6 +5,7 @@
 
 pub mod economic_model;
 pub mod token_reward;
+pub mod reward_engine;
 pub mod fee_calculation;
 pub mod parameter_adjustment;
 pub mod anti_speculation;
@@ -12,6 +13,7 @@ pub mod reward_adjustments;
 
 pub use economic_model::*;
 pub use token_reward::*;
+pub use reward_engine::{RewardEngine, DefaultInfraEngine, IspBypassEngine};
 pub use fee_calculation::*;
 pub use parameter_adjustment::*;
 pub use anti_speculation::*;
@@ -57,6 +59,7 @@ mod tests {
     #[test]
     fn test_token_reward_calculation() {
         let model = EconomicModel::new();
+        let engine = DefaultInfraEngine::from_model(&model);
         let work = WorkMetrics {
             routing_work: 5_000_000, // 5MB
             storage_work: 2_000_000_000, // 2GB
@@ -64,8 +67,8 @@ mod tests {
             quality_score: 0.98, // High quality
             uptime_hours: 24, // Perfect uptime
         };
-        
-        let reward = TokenReward::calculate(&work, &model).unwrap();
+
+        let reward = TokenReward::calculate(&work, &engine).unwrap();
         
         // Base rewards
         assert_eq!(reward.routing_reward, 5); // 5MB * 1 token/MB
@@ -89,7 +92,8 @@ mod tests {
             cost_savings_provided: 150,
         };
         
-        let reward = TokenReward::calculate_isp_bypass(&work).unwrap();
+        let engine = IspBypassEngine::default_rates();
+        let reward = TokenReward::calculate_isp_bypass(&work, &engine).unwrap();
         
         // Expected calculations
         let expected_bandwidth = 5 * crate::ISP_BYPASS_CONNECTIVITY_RATE; // 5 * 100 = 500
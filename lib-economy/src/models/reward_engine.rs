@@ -0,0 +1,150 @@
+//! Pluggable economic-model engine for reward calculation
+//!
+//! `TokenReward` used to hard-code the SOV/ZHTP infrastructure pricing
+//! model directly against `EconomicModel`. That pricing is now behind the
+//! `RewardEngine` trait, generic over the work-metrics type it scores, so
+//! a deployment can plug in its own pricing (or an entirely different
+//! metric shape, like `IspBypassWork`) without forking `TokenReward`.
+//! This mirrors how block-reward logic gets abstracted behind an engine
+//! trait so new consensus/economic variants plug in cleanly.
+
+use crate::models::EconomicModel;
+use crate::types::{IspBypassWork, WorkMetrics};
+
+/// An economic model capable of pricing a particular kind of work metric.
+///
+/// Implementations score the individual components of a reward; `TokenReward`
+/// combines them and applies the minimum-reward floor.
+pub trait RewardEngine<W> {
+    /// Reward for routing/packet-forwarding work
+    fn routing_reward(&self, work: &W) -> u64;
+    /// Reward for storage work
+    fn storage_reward(&self, work: &W) -> u64;
+    /// Reward for computational work
+    fn compute_reward(&self, work: &W) -> u64;
+    /// Bonus for exceptional quality, given the base reward already earned
+    fn quality_bonus(&self, work: &W, base_reward: u64) -> u64;
+    /// Bonus for high uptime, given the base reward already earned
+    fn uptime_bonus(&self, work: &W, base_reward: u64) -> u64;
+}
+
+/// Default infrastructure pricing engine, scoring `WorkMetrics` the same
+/// way the original hard-coded `TokenReward::calculate` did: ISP/CDN-style
+/// per-MB routing and per-GB storage rates, with minimal quality/uptime
+/// bonuses since infrastructure is expected to be reliable by default.
+#[derive(Debug, Clone)]
+pub struct DefaultInfraEngine {
+    /// Tokens per MB of data routed
+    pub base_routing_rate: u64,
+    /// Tokens per GB stored per month
+    pub base_storage_rate: u64,
+    /// Tokens per computation/validation
+    pub base_compute_rate: u64,
+    /// Quality multiplier for exceptional service
+    pub quality_multiplier: f64,
+    /// Uptime multiplier for reliability
+    pub uptime_multiplier: f64,
+}
+
+impl DefaultInfraEngine {
+    /// Build an engine from an `EconomicModel`'s pricing parameters
+    pub fn from_model(model: &EconomicModel) -> Self {
+        Self {
+            base_routing_rate: model.base_routing_rate,
+            base_storage_rate: model.base_storage_rate,
+            base_compute_rate: model.base_compute_rate,
+            quality_multiplier: model.quality_multiplier,
+            uptime_multiplier: model.uptime_multiplier,
+        }
+    }
+}
+
+impl RewardEngine<WorkMetrics> for DefaultInfraEngine {
+    fn routing_reward(&self, work: &WorkMetrics) -> u64 {
+        (work.routing_work / 1_000_000).saturating_mul(self.base_routing_rate) // bytes to MB
+    }
+
+    fn storage_reward(&self, work: &WorkMetrics) -> u64 {
+        (work.storage_work / 1_000_000_000).saturating_mul(self.base_storage_rate) // bytes to GB
+    }
+
+    fn compute_reward(&self, work: &WorkMetrics) -> u64 {
+        work.compute_work.saturating_mul(self.base_compute_rate)
+    }
+
+    fn quality_bonus(&self, work: &WorkMetrics, base_reward: u64) -> u64 {
+        if work.qualifies_for_quality_bonus() {
+            ((base_reward as f64) * self.quality_multiplier) as u64
+        } else {
+            0
+        }
+    }
+
+    fn uptime_bonus(&self, work: &WorkMetrics, base_reward: u64) -> u64 {
+        if work.qualifies_for_uptime_bonus() {
+            ((base_reward as f64) * self.uptime_multiplier) as u64
+        } else {
+            0
+        }
+    }
+}
+
+/// Mesh/ISP-bypass pricing engine, scoring `IspBypassWork`: connectivity
+/// and mesh-routing rates instead of ISP/CDN-style bandwidth and storage
+/// pricing, with a flat bonus for excellent connection quality. There is
+/// no compute component for this kind of work, and bandwidth sharing
+/// (which has no `DefaultInfraEngine` equivalent) is reported through the
+/// `storage_reward` slot since both represent capacity contributed to the
+/// network.
+#[derive(Debug, Clone)]
+pub struct IspBypassEngine {
+    /// Tokens per GB of bandwidth shared
+    pub connectivity_rate: u64,
+    /// Tokens per MB routed through the mesh
+    pub mesh_rate: u64,
+    /// Tokens per hour of uptime provided
+    pub uptime_rate: u64,
+    /// Quality bonus fraction applied above `quality_threshold`
+    pub quality_bonus_fraction: f64,
+    /// Connection quality score above which the quality bonus applies
+    pub quality_threshold: f64,
+}
+
+impl IspBypassEngine {
+    /// Build an engine using the network-wide ISP-bypass rate constants
+    pub fn default_rates() -> Self {
+        Self {
+            connectivity_rate: crate::ISP_BYPASS_CONNECTIVITY_RATE,
+            mesh_rate: crate::ISP_BYPASS_MESH_RATE,
+            uptime_rate: crate::ISP_BYPASS_UPTIME_BONUS,
+            quality_bonus_fraction: 0.5,
+            quality_threshold: 0.9,
+        }
+    }
+}
+
+impl RewardEngine<IspBypassWork> for IspBypassEngine {
+    fn routing_reward(&self, work: &IspBypassWork) -> u64 {
+        work.packets_routed_mb.saturating_mul(self.mesh_rate)
+    }
+
+    fn storage_reward(&self, work: &IspBypassWork) -> u64 {
+        work.bandwidth_shared_gb.saturating_mul(self.connectivity_rate)
+    }
+
+    fn compute_reward(&self, _work: &IspBypassWork) -> u64 {
+        0 // Not applicable to mesh/ISP-bypass work
+    }
+
+    fn quality_bonus(&self, work: &IspBypassWork, base_reward: u64) -> u64 {
+        if work.connection_quality > self.quality_threshold {
+            ((base_reward as f64) * self.quality_bonus_fraction) as u64
+        } else {
+            0
+        }
+    }
+
+    fn uptime_bonus(&self, work: &IspBypassWork, _base_reward: u64) -> u64 {
+        work.uptime_hours.saturating_mul(self.uptime_rate)
+    }
+}
@@ -6,7 +6,7 @@
 use anyhow::Result;
 use serde::{Serialize, Deserialize};
 use crate::types::{WorkMetrics, IspBypassWork};
-use crate::models::EconomicModel;
+use crate::models::reward_engine::RewardEngine;
 
 /// Token reward for infrastructure services
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,38 +28,24 @@ pub struct TokenReward {
 }
 
 impl TokenReward {
-    /// Calculate comprehensive token rewards based on useful work
-    pub fn calculate(work: &WorkMetrics, model: &EconomicModel) -> Result<Self> {
-        // INTERNET INFRASTRUCTURE REWARDS (like ISP/CDN revenue sharing)
-        // Routing: 1 SOV per MB of data routed (actual bandwidth costs)
-        let routing_reward = (work.routing_work / 1_000_000).saturating_mul(model.base_routing_rate); // bytes to MB
-        
-        // Storage: 10 SOV per GB stored per month (cloud storage pricing model)
-        let storage_reward = (work.storage_work / 1_000_000_000).saturating_mul(model.base_storage_rate); // bytes to GB
-        
-        // Compute: Minimal processing fee for consensus validation
-        let compute_reward = work.compute_work.saturating_mul(model.base_compute_rate);
-        
+    /// Calculate comprehensive token rewards based on useful work, pricing
+    /// each component through `engine`. Swapping `engine` is how a
+    /// deployment (pure mesh/ISP-bypass, storage-heavy, etc.) supplies its
+    /// own pricing without forking this method.
+    pub fn calculate(work: &WorkMetrics, engine: &dyn RewardEngine<WorkMetrics>) -> Result<Self> {
+        let routing_reward = engine.routing_reward(work);
+        let storage_reward = engine.storage_reward(work);
+        let compute_reward = engine.compute_reward(work);
+
         // MINIMAL BONUSES (infrastructure is expected to be reliable)
-        let quality_bonus = if work.qualifies_for_quality_bonus() {
-            let base_reward = routing_reward.saturating_add(storage_reward).saturating_add(compute_reward);
-            ((base_reward as f64) * model.quality_multiplier) as u64
-        } else {
-            0 // No bonus unless exceptional
-        };
-        
-        let uptime_bonus = if work.qualifies_for_uptime_bonus() {
-            let base_reward = routing_reward.saturating_add(storage_reward).saturating_add(compute_reward);
-            ((base_reward as f64) * model.uptime_multiplier) as u64
-        } else {
-            0 // No bonus unless near-perfect uptime
-        };
-        
-        let total_reward = routing_reward.saturating_add(storage_reward)
-            .saturating_add(compute_reward)
+        let base_reward = routing_reward.saturating_add(storage_reward).saturating_add(compute_reward);
+        let quality_bonus = engine.quality_bonus(work, base_reward);
+        let uptime_bonus = engine.uptime_bonus(work, base_reward);
+
+        let total_reward = base_reward
             .saturating_add(quality_bonus)
             .saturating_add(uptime_bonus);
-        
+
         // Ensure minimum reward floor for network participation
         let final_total = if total_reward == 0 { 1 } else { total_reward };
 
@@ -89,27 +75,25 @@ impl TokenReward {
         Ok(())
     }
     
-    /// Calculate  specific rewards
-    pub fn calculate_isp_bypass(work: &IspBypassWork) -> Result<Self> {
-        //  REWARDS - replacing traditional ISP revenue
-        let bandwidth_reward = work.bandwidth_shared_gb.saturating_mul(crate::ISP_BYPASS_CONNECTIVITY_RATE);
-        let routing_reward = work.packets_routed_mb.saturating_mul(crate::ISP_BYPASS_MESH_RATE);
-        let uptime_bonus = work.uptime_hours.saturating_mul(crate::ISP_BYPASS_UPTIME_BONUS);
-        
-        // Quality multiplier for high-quality connections
-        let base_total = bandwidth_reward.saturating_add(routing_reward).saturating_add(uptime_bonus);
-        let quality_bonus = if work.connection_quality > 0.9 {
-            ((base_total as f64) * 0.5) as u64 // 50% bonus for excellent quality
-        } else {
-            0
-        };
-        
+    /// Calculate mesh/ISP-bypass specific rewards, pricing each component
+    /// through `engine` (see `IspBypassEngine` for the default rates). This
+    /// is just another `RewardEngine` consumer rather than a separate
+    /// hard-coded calculation.
+    pub fn calculate_isp_bypass(work: &IspBypassWork, engine: &dyn RewardEngine<IspBypassWork>) -> Result<Self> {
+        let routing_reward = engine.routing_reward(work);
+        let storage_reward = engine.storage_reward(work); // bandwidth-sharing reward
+        let compute_reward = engine.compute_reward(work);
+        let uptime_bonus = engine.uptime_bonus(work, 0);
+
+        let base_total = routing_reward.saturating_add(storage_reward).saturating_add(uptime_bonus);
+        let quality_bonus = engine.quality_bonus(work, base_total);
+
         let total_reward = base_total.saturating_add(quality_bonus);
-        
+
         Ok(TokenReward {
             routing_reward,
-            storage_reward: 0, // Not applicable for 
-            compute_reward: 0, // Not applicable for 
+            storage_reward,
+            compute_reward,
             quality_bonus,
             uptime_bonus,
             total_reward,
@@ -133,17 +117,19 @@ mod tests {
     use super::*;
     use crate::types::WorkMetrics;
     use crate::models::EconomicModel;
+    use crate::models::reward_engine::DefaultInfraEngine;
 
     #[test]
     fn test_token_reward_calculation() {
         let model = EconomicModel::new();
+        let engine = DefaultInfraEngine::from_model(&model);
         let mut work = WorkMetrics::new();
         work.add_routing_work(1_000_000); // 1MB
         work.add_storage_work(1_000_000_000); // 1GB
         work.add_compute_work(100);
         work.update_quality_score(0.95);
 
-        let reward = TokenReward::calculate(&work, &model).unwrap();
+        let reward = TokenReward::calculate(&work, &engine).unwrap();
         assert!(reward.routing_reward > 0);
         assert!(reward.storage_reward > 0);
         assert!(reward.compute_reward > 0);
@@ -156,9 +142,10 @@ mod tests {
     #[test]
     fn test_zero_work_minimum_reward() {
         let model = EconomicModel::new();
+        let engine = DefaultInfraEngine::from_model(&model);
         let work = WorkMetrics::new();
 
-        let reward = TokenReward::calculate(&work, &model).unwrap();
+        let reward = TokenReward::calculate(&work, &engine).unwrap();
         assert_eq!(reward.routing_reward, 0);
         assert_eq!(reward.storage_reward, 0);
         assert_eq!(reward.compute_reward, 0);
@@ -198,4 +185,33 @@ mod tests {
         assert_eq!(reward1.uptime_bonus, 35);
         assert_eq!(reward1.total_reward, 700);
     }
+
+    #[test]
+    fn test_token_reward_isp_bypass() {
+        use crate::models::reward_engine::IspBypassEngine;
+
+        let work = IspBypassWork {
+            bandwidth_shared_gb: 5,
+            packets_routed_mb: 200,
+            uptime_hours: 12,
+            connection_quality: 0.95,
+            users_served: 3,
+            cost_savings_provided: 150,
+        };
+
+        let engine = IspBypassEngine::default_rates();
+        let reward = TokenReward::calculate_isp_bypass(&work, &engine).unwrap();
+
+        let expected_bandwidth = 5 * crate::ISP_BYPASS_CONNECTIVITY_RATE;
+        let expected_routing = 200 * crate::ISP_BYPASS_MESH_RATE;
+        let expected_uptime = 12 * crate::ISP_BYPASS_UPTIME_BONUS;
+        let expected_base = expected_bandwidth + expected_routing + expected_uptime;
+        let expected_with_quality = expected_base + ((expected_base as f64) * 0.5) as u64;
+
+        assert_eq!(reward.routing_reward, expected_routing);
+        assert_eq!(reward.storage_reward, expected_bandwidth);
+        assert_eq!(reward.uptime_bonus, expected_uptime);
+        assert_eq!(reward.quality_bonus, (expected_base as f64 * 0.5) as u64);
+        assert_eq!(reward.total_reward, expected_with_quality);
+    }
 }
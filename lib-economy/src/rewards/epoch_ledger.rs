@@ -0,0 +1,221 @@
+//! Epoch-based reward checkpointing
+//!
+//! Ties reward payouts to a specific epoch/block-height snapshot so that
+//! audits and late claims can replay exactly what was owed, rather than
+//! recomputing against whatever state happens to exist now. Once an epoch
+//! is settled its checkpoint is frozen -- later work can never retroactively
+//! change a past payout.
+
+use std::collections::{HashMap, HashSet};
+use anyhow::{Result, anyhow};
+use serde::{Serialize, Deserialize};
+use lib_crypto::Hash;
+
+use crate::models::TokenReward;
+use crate::types::WorkMetrics;
+
+/// Immutable snapshot of one settled epoch's rewards
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardCheckpoint {
+    pub epoch: u64,
+    pub block_height: u64,
+    pub total_work: WorkMetrics,
+    pub total_reward: u64,
+    pub per_participant: HashMap<Hash, TokenReward>,
+}
+
+/// Ledger of settled epoch checkpoints and which participants have claimed
+/// against them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpochRewardLedger {
+    /// Settled checkpoints, keyed by epoch. Once present a checkpoint is
+    /// immutable; `settle_epoch` refuses to overwrite one.
+    checkpoints: HashMap<u64, RewardCheckpoint>,
+    /// Participants who have already claimed their reward for a given
+    /// epoch, so a payout can never be claimed twice.
+    claimed: HashMap<u64, HashSet<Hash>>,
+}
+
+impl EpochRewardLedger {
+    /// Create an empty ledger with no settled epochs
+    pub fn new() -> Self {
+        Self {
+            checkpoints: HashMap::new(),
+            claimed: HashMap::new(),
+        }
+    }
+
+    /// Freeze the reward checkpoint for `epoch` at `block_height`. The
+    /// checkpoint records each participant's reward as resolved from the
+    /// snapshot taken at the epoch's end block, not from current state.
+    /// Fails if `epoch` has already been settled.
+    pub fn settle_epoch(
+        &mut self,
+        epoch: u64,
+        block_height: u64,
+        total_work: WorkMetrics,
+        per_participant: HashMap<Hash, TokenReward>,
+    ) -> Result<()> {
+        if self.checkpoints.contains_key(&epoch) {
+            return Err(anyhow!("Epoch {} is already settled", epoch));
+        }
+
+        let total_reward = per_participant
+            .values()
+            .map(|reward| reward.total_reward)
+            .sum();
+
+        self.checkpoints.insert(
+            epoch,
+            RewardCheckpoint {
+                epoch,
+                block_height,
+                total_work,
+                total_reward,
+                per_participant,
+            },
+        );
+        self.claimed.insert(epoch, HashSet::new());
+
+        Ok(())
+    }
+
+    /// Pay out `participant`'s reward for `epoch` exactly once. Fails if
+    /// the epoch isn't settled yet, the participant has no recorded
+    /// reward, or the reward was already claimed.
+    pub fn claim_for_epoch(&mut self, participant: &Hash, epoch: u64) -> Result<TokenReward> {
+        let checkpoint = self
+            .checkpoints
+            .get(&epoch)
+            .ok_or_else(|| anyhow!("Epoch {} is not settled", epoch))?;
+
+        let reward = checkpoint
+            .per_participant
+            .get(participant)
+            .ok_or_else(|| anyhow!("No reward recorded for this participant in epoch {}", epoch))?
+            .clone();
+
+        let claimed_this_epoch = self.claimed.entry(epoch).or_insert_with(HashSet::new);
+        if !claimed_this_epoch.insert(participant.clone()) {
+            return Err(anyhow!(
+                "Reward for epoch {} has already been claimed",
+                epoch
+            ));
+        }
+
+        Ok(reward)
+    }
+
+    /// Read-only lookup of a participant's recorded reward for `epoch`,
+    /// regardless of claim status -- for audits and dashboards.
+    pub fn reward_at(&self, participant: &Hash, epoch: u64) -> Result<&TokenReward> {
+        let checkpoint = self
+            .checkpoints
+            .get(&epoch)
+            .ok_or_else(|| anyhow!("Epoch {} is not settled", epoch))?;
+
+        checkpoint
+            .per_participant
+            .get(participant)
+            .ok_or_else(|| anyhow!("No reward recorded for this participant in epoch {}", epoch))
+    }
+
+    /// Whether `participant` has already claimed their reward for `epoch`
+    pub fn has_claimed(&self, participant: &Hash, epoch: u64) -> bool {
+        self.claimed
+            .get(&epoch)
+            .map_or(false, |claimants| claimants.contains(participant))
+    }
+
+    /// The frozen checkpoint for a settled epoch, if any
+    pub fn checkpoint(&self, epoch: u64) -> Option<&RewardCheckpoint> {
+        self.checkpoints.get(&epoch)
+    }
+}
+
+impl Default for EpochRewardLedger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participant(byte: u8) -> Hash {
+        Hash([byte; 32])
+    }
+
+    fn reward(total: u64) -> TokenReward {
+        TokenReward {
+            routing_reward: total,
+            storage_reward: 0,
+            compute_reward: 0,
+            quality_bonus: 0,
+            uptime_bonus: 0,
+            total_reward: total,
+            currency: "ZHTP".to_string(),
+        }
+    }
+
+    #[test]
+    fn settle_epoch_is_immutable() {
+        let mut ledger = EpochRewardLedger::new();
+        let mut per_participant = HashMap::new();
+        per_participant.insert(participant(1), reward(100));
+
+        ledger
+            .settle_epoch(1, 1000, WorkMetrics::new(), per_participant.clone())
+            .unwrap();
+
+        assert!(ledger
+            .settle_epoch(1, 2000, WorkMetrics::new(), per_participant)
+            .is_err());
+    }
+
+    #[test]
+    fn claim_for_epoch_pays_out_exactly_once() {
+        let mut ledger = EpochRewardLedger::new();
+        let alice = participant(1);
+        let mut per_participant = HashMap::new();
+        per_participant.insert(alice.clone(), reward(250));
+
+        ledger
+            .settle_epoch(7, 70_000, WorkMetrics::new(), per_participant)
+            .unwrap();
+
+        let claimed = ledger.claim_for_epoch(&alice, 7).unwrap();
+        assert_eq!(claimed.total_reward, 250);
+        assert!(ledger.has_claimed(&alice, 7));
+
+        assert!(ledger.claim_for_epoch(&alice, 7).is_err());
+    }
+
+    #[test]
+    fn reward_at_is_read_only_and_survives_a_claim() {
+        let mut ledger = EpochRewardLedger::new();
+        let bob = participant(2);
+        let mut per_participant = HashMap::new();
+        per_participant.insert(bob.clone(), reward(42));
+
+        ledger
+            .settle_epoch(3, 30_000, WorkMetrics::new(), per_participant)
+            .unwrap();
+
+        assert_eq!(ledger.reward_at(&bob, 3).unwrap().total_reward, 42);
+        ledger.claim_for_epoch(&bob, 3).unwrap();
+        // Still auditable after the claim -- the checkpoint never changes.
+        assert_eq!(ledger.reward_at(&bob, 3).unwrap().total_reward, 42);
+    }
+
+    #[test]
+    fn unsettled_epoch_rejects_claims_and_lookups() {
+        let mut ledger = EpochRewardLedger::new();
+        let carol = participant(3);
+
+        assert!(ledger.claim_for_epoch(&carol, 9).is_err());
+        assert!(ledger.reward_at(&carol, 9).is_err());
+        assert!(!ledger.has_claimed(&carol, 9));
+    }
+}
@@ -5,6 +5,8 @@
 
 pub mod calculator;
 pub mod types;
+pub mod epoch_ledger;
 
 pub use calculator::*;
 pub use types::*;
+pub use epoch_ledger::{EpochRewardLedger, RewardCheckpoint};
@@ -8,7 +8,7 @@ use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::models::{TokenReward, EconomicModel};
+use crate::models::{TokenReward, EconomicModel, DefaultInfraEngine, IspBypassEngine};
 use crate::types::{WorkMetrics, IspBypassWork, NetworkStats, TransactionType};
 use crate::wallets::WalletBalance;
 use crate::wasm::logging::info;
@@ -308,12 +308,14 @@ impl RewardManager {
         }
 
         // Calculate standard network rewards
-        let network_reward = TokenReward::calculate(&self.current_work, economic_model)?;
+        let infra_engine = DefaultInfraEngine::from_model(economic_model);
+        let network_reward = TokenReward::calculate(&self.current_work, &infra_engine)?;
 
         // Calculate  rewards if applicable
         let mut total_reward = network_reward;
         if self.isp_bypass_work.bandwidth_shared_gb > 0 || self.isp_bypass_work.packets_routed_mb > 0 {
-            let bypass_reward = TokenReward::calculate_isp_bypass(&self.isp_bypass_work)?;
+            let bypass_engine = IspBypassEngine::default_rates();
+            let bypass_reward = TokenReward::calculate_isp_bypass(&self.isp_bypass_work, &bypass_engine)?;
             total_reward.combine(&bypass_reward);
         }
 
@@ -546,7 +548,7 @@ impl IspBypassRewards {
             cost_savings_provided: self.cost_savings,
         };
 
-        let mut reward = TokenReward::calculate_isp_bypass(&work)?;
+        let mut reward = TokenReward::calculate_isp_bypass(&work, &IspBypassEngine::default_rates())?;
         
         // Apply bypass multiplier for exceptional service
         if self.bypass_multiplier != 1.0 {
@@ -39,7 +39,8 @@ mod edge_case_tests {
     #[test]
     fn test_extreme_work_metrics() {
         let model = EconomicModel::new();
-        
+        let engine = DefaultInfraEngine::from_model(&model);
+
         // Test with zero work
         let zero_work = WorkMetrics {
             routing_work: 0,
@@ -49,7 +50,7 @@ mod edge_case_tests {
             uptime_hours: 0,
         };
         
-        let reward = TokenReward::calculate(&zero_work, &model).unwrap();
+        let reward = TokenReward::calculate(&zero_work, &engine).unwrap();
         assert_eq!(reward.routing_reward, 0);
         assert_eq!(reward.storage_reward, 0);
         assert_eq!(reward.compute_reward, 0);
@@ -67,7 +68,7 @@ mod edge_case_tests {
             uptime_hours: u64::MAX,
         };
         
-        let max_reward = TokenReward::calculate(&max_work, &model).unwrap();
+        let max_reward = TokenReward::calculate(&max_work, &engine).unwrap();
         // Should handle large numbers without overflow
         assert!(max_reward.total_reward > 0);
         assert!(max_reward.routing_reward > 0);
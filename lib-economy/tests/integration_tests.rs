@@ -227,7 +227,8 @@ mod tests {
             uptime_hours: 24, // Above bonus threshold
         };
         
-        let reward = TokenReward::calculate(&work_metrics, &model).unwrap();
+        let engine = DefaultInfraEngine::from_model(&model);
+        let reward = TokenReward::calculate(&work_metrics, &engine).unwrap();
         
         // Check base rewards
         assert_eq!(reward.routing_reward, 1); // 1 MB * 1 token/MB
@@ -38,7 +38,8 @@ mod benchmarks {
     #[test]
     fn benchmark_reward_calculation() {
         let model = EconomicModel::new();
-        
+        let engine = DefaultInfraEngine::from_model(&model);
+
         let start = Instant::now();
         for i in 0..ITERATIONS {
             let work = WorkMetrics {
@@ -48,7 +49,7 @@ mod benchmarks {
                 quality_score: (i % 100) as f64 / 100.0,
                 uptime_hours: (i % 25) as u64,
             };
-            let _ = TokenReward::calculate(&work, &model).unwrap();
+            let _ = TokenReward::calculate(&work, &engine).unwrap();
         }
         let duration = start.elapsed();
         
@@ -52,7 +52,8 @@ mod stress_tests {
     #[test]
     fn test_massive_reward_distribution() {
         let model = EconomicModel::new();
-        
+        let engine = DefaultInfraEngine::from_model(&model);
+
         // Simulate reward calculation for 50,000 nodes
         let node_count = 50_000;
         let mut total_rewards = 0u64;
@@ -66,7 +67,7 @@ mod stress_tests {
                 uptime_hours: ((i * 13) % 8760) as u64, // Up to 1 year
             };
             
-            let reward = TokenReward::calculate(&work, &model).unwrap();
+            let reward = TokenReward::calculate(&work, &engine).unwrap();
             total_rewards += reward.total_reward;
         }
         
@@ -460,11 +461,12 @@ mod load_tests {
     #[test]
     fn test_reward_calculation_speed() {
         let model = EconomicModel::new();
+        let engine = DefaultInfraEngine::from_model(&model);
         let start = Instant::now();
-        
+
         // Calculate rewards for 25,000 nodes
         let node_count = 25_000;
-        
+
         for i in 0..node_count {
             let work = WorkMetrics {
                 routing_work: (i % 10000) as u64,
@@ -473,8 +475,8 @@ mod load_tests {
                 quality_score: 0.8,
                 uptime_hours: (i % 8760) as u64,
             };
-            
-            let _reward = TokenReward::calculate(&work, &model).unwrap();
+
+            let _reward = TokenReward::calculate(&work, &engine).unwrap();
         }
         
         let duration = start.elapsed();
@@ -490,9 +492,10 @@ mod load_tests {
     fn test_memory_stability_over_time() {
         // Run economic operations for extended period
         let mut model = EconomicModel::new();
+        let engine = DefaultInfraEngine::from_model(&model);
         let mut treasury = DaoTreasury::new();
         let iterations = 100_000;
-        
+
         let start = Instant::now();
         
         for i in 0..iterations {
@@ -517,7 +520,7 @@ mod load_tests {
                         quality_score: 0.75,
                         uptime_hours: (i % 720) as u64,
                     };
-                    let _reward = TokenReward::calculate(&work, &model).unwrap();
+                    let _reward = TokenReward::calculate(&work, &engine).unwrap();
                 }
                 _ => {
                     // Adjust parameters
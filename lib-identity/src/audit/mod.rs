@@ -0,0 +1,76 @@
+//! Guardian and Recovery Audit Log
+//!
+//! Append-only, per-identity log of sensitive guardian and recovery actions
+//! (who did what, from where, when), persisted through `IdentityManager` so
+//! an identity owner can review it after the fact and detect a malicious
+//! guardian.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kind of sensitive guardian/recovery action being recorded
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AuditEventKind {
+    /// A guardian was added to an identity's guardian config
+    GuardianAdded,
+
+    /// A guardian was removed from an identity's guardian config
+    GuardianRemoved,
+
+    /// A guardian's own config (e.g. its notification endpoint) was updated
+    GuardianUpdated,
+
+    /// A social recovery request was initiated for an identity
+    RecoveryInitiated,
+
+    /// A guardian approved a pending recovery request
+    RecoveryApproved,
+
+    /// A guardian rejected a pending recovery request
+    RecoveryRejected,
+
+    /// A recovery request was completed and a new session issued
+    RecoveryCompleted,
+}
+
+/// A single append-only audit log entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    /// DID of whoever performed the action (identity owner or guardian)
+    pub actor_did: String,
+
+    /// What happened
+    pub kind: AuditEventKind,
+
+    /// DID of the identity the action was performed against
+    pub target_identity_did: String,
+
+    /// Client IP the action was performed from
+    pub client_ip: String,
+
+    /// Client User-Agent the action was performed from
+    pub user_agent: String,
+
+    /// When the event was recorded
+    pub timestamp: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    /// Record a new audit event at the current time
+    pub fn new(
+        actor_did: String,
+        kind: AuditEventKind,
+        target_identity_did: String,
+        client_ip: String,
+        user_agent: String,
+    ) -> Self {
+        Self {
+            actor_did,
+            kind,
+            target_identity_did,
+            client_ip,
+            user_agent,
+            timestamp: Utc::now(),
+        }
+    }
+}
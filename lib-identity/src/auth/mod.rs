@@ -7,4 +7,4 @@ pub mod password;
 pub mod session;
 
 pub use password::{PasswordManager, PasswordError, PasswordValidation, PasswordStrength};
-pub use session::SessionToken;
\ No newline at end of file
+pub use session::{SessionToken, RefreshToken};
\ No newline at end of file
@@ -97,6 +97,94 @@ impl SessionToken {
             }
         }
 
+        true
+    }
+}
+
+/// Refresh token paired with a short-lived [`SessionToken`] access token
+///
+/// Presented only to the session-refresh endpoint to mint a fresh
+/// access/refresh pair; never used to authorize a request directly. Each
+/// refresh consumes (rotates) the presented id, so a client holds this
+/// instead of one long-lived access token.
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    pub token: String,
+    pub identity_id: IdentityId,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub bound_ip: Option<String>,
+    pub bound_user_agent: Option<String>,
+}
+
+impl RefreshToken {
+    /// Check if the refresh token is still within its validity window
+    pub fn is_valid(&self) -> bool {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        now < self.expires_at
+    }
+
+    /// Generate a new refresh token
+    pub fn new(
+        identity_id: IdentityId,
+        duration_seconds: u64,
+        client_ip: Option<String>,
+        user_agent: Option<String>,
+    ) -> Result<Self> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut random_bytes = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut random_bytes);
+
+        // Domain-separated from SessionToken so an access token and a
+        // refresh token minted at the same instant can never collide
+        let token_material = [
+            identity_id.0.as_slice(),
+            &now.to_le_bytes(),
+            &random_bytes,
+            b"ZHTP_refresh_token_v1",
+        ].concat();
+
+        let token_hash = hash_blake3(&token_material);
+        let token = hex::encode(token_hash);
+
+        Ok(RefreshToken {
+            token,
+            identity_id,
+            created_at: now,
+            expires_at: now + duration_seconds,
+            bound_ip: client_ip,
+            bound_user_agent: user_agent,
+        })
+    }
+
+    /// Validate the refresh token is being presented from the same IP/User-Agent it was issued to
+    pub fn validate_binding(&self, current_ip: &str, current_ua: &str) -> bool {
+        if let Some(bound_ip) = &self.bound_ip {
+            if bound_ip != current_ip {
+                tracing::warn!(
+                    "Refresh token IP mismatch: bound={} current={}",
+                    bound_ip,
+                    current_ip
+                );
+                return false;
+            }
+        }
+
+        if let Some(bound_ua) = &self.bound_user_agent {
+            if bound_ua != current_ua {
+                tracing::warn!("Refresh token User-Agent mismatch");
+                return false;
+            }
+        }
+
         true
     }
 }
\ No newline at end of file
@@ -5,19 +5,42 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use lib_crypto::PublicKey;
 
+mod siwe;
+pub use siwe::SiweMessage;
+
+/// How a guardian proves it authorized an action: either a ZHTP
+/// post-quantum signature, or (for a guardian who is an Ethereum wallet
+/// rather than a ZHTP identity) a signed SIWE message recovered via
+/// secp256k1 ECDSA
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum GuardianType {
+    /// A ZHTP identity, authorizing with a Dilithium signature over
+    /// `guardian_did`'s post-quantum key
+    #[default]
+    ZhtpIdentity,
+
+    /// An Ethereum wallet, authorizing by signing a SIWE (EIP-4361)
+    /// message with its secp256k1 key. `guardian_did` holds the EIP-55
+    /// checksummed wallet address instead of a `did:zhtp:` DID.
+    EthereumWallet,
+}
+
 /// A trusted guardian who can help recover an identity
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Guardian {
     /// Unique guardian identifier
     pub guardian_id: String,
 
-    /// Guardian's DID
+    /// Guardian's DID, or (for `GuardianType::EthereumWallet` guardians)
+    /// their EIP-55 checksummed Ethereum address
     pub guardian_did: String,
 
-    /// Guardian's public key for signature verification
+    /// Guardian's public key for signature verification. Unused (empty)
+    /// for `GuardianType::EthereumWallet` guardians, which authorize via
+    /// secp256k1 address recovery instead.
     pub public_key: PublicKey,
 
     /// Human-readable name for the guardian
@@ -28,6 +51,17 @@ pub struct Guardian {
 
     /// Status of the guardian
     pub status: GuardianStatus,
+
+    /// How this guardian authorizes actions
+    #[serde(default)]
+    pub guardian_type: GuardianType,
+
+    /// Where to push a notification when a recovery needs this guardian's
+    /// attention (initiated, threshold met, or an emergency window
+    /// maturing). Transport-specific (e.g. a webhook URL); `None` means
+    /// the guardian must poll `/recovery/pending` instead.
+    #[serde(default)]
+    pub notification_endpoint: Option<String>,
 }
 
 /// Guardian status
@@ -39,8 +73,54 @@ pub enum GuardianStatus {
     /// Guardian has been removed
     Removed,
 
-    /// Guardian is pending acceptance (optional future feature)
-    Pending,
+    /// Guardian has been invited but hasn't yet proven control of the
+    /// submitted key by accepting the invitation (see
+    /// `GuardianConfig::invite_guardian`/`accept_invitation`). Excluded from
+    /// `get_active_guardians` and so doesn't count toward `threshold`.
+    Invited,
+}
+
+/// A pending guardian invitation: a single-use, expiring token the invited
+/// guardian must sign with their post-quantum key to prove control of it,
+/// before `GuardianConfig::accept_invitation` flips them to `Active`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianInvitation {
+    /// The `Guardian` entry (status `Invited`) this invitation is for.
+    pub guardian_id: String,
+
+    /// Single-use token the guardian signs to accept.
+    pub invitation_token: String,
+
+    /// When the invitation was issued.
+    pub created_at: DateTime<Utc>,
+
+    /// When the invitation expires and can no longer be accepted.
+    pub expires_at: DateTime<Utc>,
+}
+
+impl GuardianInvitation {
+    /// Check if the invitation has expired
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expires_at
+    }
+}
+
+/// A standing pre-authorization letting a specific guardian trigger
+/// time-delayed emergency access: once `handle_initiate_emergency_access`
+/// starts the countdown, the owner has `waiting_period_hours` to reject it
+/// before the guardian can complete the recovery without meeting the
+/// normal approval threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmergencyGrant {
+    /// The grantee guardian's `guardian_id`
+    pub guardian_id: String,
+
+    /// Hours the owner has to reject an initiated emergency access request
+    /// before it matures and can be completed
+    pub waiting_period_hours: i64,
+
+    /// When the grant was issued
+    pub granted_at: DateTime<Utc>,
 }
 
 /// Guardian configuration for an identity
@@ -49,19 +129,55 @@ pub struct GuardianConfig {
     /// List of guardians
     pub guardians: HashMap<String, Guardian>,
 
+    /// Pending invitations, keyed by `invitation_token`
+    pub invitations: HashMap<String, GuardianInvitation>,
+
     /// Number of guardian approvals required for recovery (e.g., 2 of 3)
     pub threshold: usize,
 
     /// Maximum number of guardians allowed
     pub max_guardians: usize,
+
+    /// Shamir shares of the identity's 32-byte master seed, one per active
+    /// guardian at distribution time, keyed by `guardian_id`. Each share is
+    /// encrypted to that guardian's public key, so only the guardian
+    /// holding the matching private key can recover their own
+    /// `ShamirShare`. Populated by [`GuardianConfig::distribute_key_shares`].
+    pub key_shares: HashMap<String, Vec<u8>>,
+
+    /// Blake3 commitment to the master seed that `key_shares` was split
+    /// from, so `reconstruct_seed`'s output can be checked for corruption
+    /// before it's trusted. Set by [`GuardianConfig::distribute_key_shares`];
+    /// `None` if key sharing was never configured for this identity.
+    #[serde(default)]
+    pub master_seed_commitment: Option<[u8; 32]>,
+
+    /// Emergency-access grants, keyed by `guardian_id`. A guardian with an
+    /// entry here may initiate time-delayed emergency access in place of
+    /// the normal guardian-threshold recovery flow.
+    pub emergency_grants: HashMap<String, EmergencyGrant>,
+
+    /// Single-use challenge nonces issued to guardians initiating emergency
+    /// access, keyed by `guardian_id`. Binds a guardian's signed
+    /// `initiate-emergency` tuple to a specific server-issued challenge the
+    /// same way [`crate::recovery::RecoveryRequest::guardian_nonces`] binds
+    /// approvals, since emergency initiation happens before any
+    /// `RecoveryRequest` exists to hold one.
+    #[serde(default)]
+    pub emergency_nonces: HashMap<String, crate::recovery::GuardianNonce>,
 }
 
 impl Default for GuardianConfig {
     fn default() -> Self {
         Self {
             guardians: HashMap::new(),
+            invitations: HashMap::new(),
             threshold: 2,
             max_guardians: 5,
+            key_shares: HashMap::new(),
+            master_seed_commitment: None,
+            emergency_grants: HashMap::new(),
+            emergency_nonces: HashMap::new(),
         }
     }
 }
@@ -71,8 +187,13 @@ impl GuardianConfig {
     pub fn new(threshold: usize, max_guardians: usize) -> Self {
         Self {
             guardians: HashMap::new(),
+            invitations: HashMap::new(),
             threshold,
             max_guardians,
+            key_shares: HashMap::new(),
+            master_seed_commitment: None,
+            emergency_grants: HashMap::new(),
+            emergency_nonces: HashMap::new(),
         }
     }
 
@@ -106,6 +227,8 @@ impl GuardianConfig {
             name,
             added_at: Utc::now(),
             status: GuardianStatus::Active,
+            guardian_type: GuardianType::ZhtpIdentity,
+            notification_endpoint: None,
         };
 
         self.guardians.insert(guardian_id.clone(), guardian);
@@ -113,6 +236,158 @@ impl GuardianConfig {
         Ok(guardian_id)
     }
 
+    /// Add a guardian authorized via signed SIWE (EIP-4361) messages from
+    /// an Ethereum wallet instead of a ZHTP post-quantum signature
+    pub fn add_wallet_guardian(
+        &mut self,
+        eth_address: &str,
+        name: String,
+    ) -> Result<String, String> {
+        // Security: Validate max guardians limit
+        if self.guardians.len() >= self.max_guardians {
+            return Err(format!("Maximum number of guardians ({}) reached", self.max_guardians));
+        }
+
+        let address_bytes = lib_crypto::classical::secp256k1::parse_eth_address(eth_address)
+            .map_err(|e| format!("Invalid Ethereum address: {}", e))?;
+        let checksummed = lib_crypto::classical::secp256k1::to_checksum_address(&address_bytes);
+
+        // Security: Check for duplicate address
+        if self.guardians.values().any(|g| g.guardian_did == checksummed) {
+            return Err("Guardian with this address already exists".to_string());
+        }
+
+        // Generate unique guardian ID using CSPRNG
+        use rand::RngCore;
+        let mut id_bytes = [0u8; 16];
+        rand::rngs::OsRng.fill_bytes(&mut id_bytes);
+        let guardian_id = hex::encode(id_bytes);
+
+        let guardian = Guardian {
+            guardian_id: guardian_id.clone(),
+            guardian_did: checksummed,
+            public_key: PublicKey::new(Vec::new()),
+            name,
+            added_at: Utc::now(),
+            status: GuardianStatus::Active,
+            guardian_type: GuardianType::EthereumWallet,
+            notification_endpoint: None,
+        };
+
+        self.guardians.insert(guardian_id.clone(), guardian);
+
+        Ok(guardian_id)
+    }
+
+    /// Create a pending invitation instead of trusting the submitted key
+    /// outright: the entry starts `Invited` and is excluded from
+    /// `get_active_guardians`/`threshold` until the invited party proves
+    /// control of the key via `accept_invitation`. Returns the new
+    /// guardian's ID and the single-use invitation token to deliver to them.
+    pub fn invite_guardian(
+        &mut self,
+        guardian_did: String,
+        public_key: PublicKey,
+        name: String,
+        expiration_hours: i64,
+    ) -> Result<(String, String), String> {
+        // Security: Validate max guardians limit
+        if self.guardians.len() >= self.max_guardians {
+            return Err(format!("Maximum number of guardians ({}) reached", self.max_guardians));
+        }
+
+        // Security: Check for duplicate DID among non-removed guardians
+        if self.guardians.values().any(|g| g.guardian_did == guardian_did && g.status != GuardianStatus::Removed) {
+            return Err("Guardian with this DID already exists".to_string());
+        }
+
+        // Generate unique guardian ID using CSPRNG
+        use rand::RngCore;
+        let mut id_bytes = [0u8; 16];
+        rand::rngs::OsRng.fill_bytes(&mut id_bytes);
+        let guardian_id = hex::encode(id_bytes);
+
+        // Generate single-use invitation token using CSPRNG
+        let mut token_bytes = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut token_bytes);
+        let invitation_token = hex::encode(token_bytes);
+
+        let guardian = Guardian {
+            guardian_id: guardian_id.clone(),
+            guardian_did,
+            public_key,
+            name,
+            added_at: Utc::now(),
+            status: GuardianStatus::Invited,
+            guardian_type: GuardianType::ZhtpIdentity,
+            notification_endpoint: None,
+        };
+        self.guardians.insert(guardian_id.clone(), guardian);
+
+        let now = Utc::now();
+        let invitation = GuardianInvitation {
+            guardian_id: guardian_id.clone(),
+            invitation_token: invitation_token.clone(),
+            created_at: now,
+            expires_at: now + Duration::hours(expiration_hours),
+        };
+        self.invitations.insert(invitation_token.clone(), invitation);
+
+        Ok((guardian_id, invitation_token))
+    }
+
+    /// Accept a pending invitation, flipping the guardian to `Active` and
+    /// consuming the single-use token. The caller is responsible for
+    /// verifying the invited party's signature over `invitation_token`
+    /// against the guardian's public key *before* calling this - this
+    /// method only enforces expiry, single-use, and status invariants.
+    pub fn accept_invitation(&mut self, invitation_token: &str) -> Result<String, String> {
+        let invitation = self.invitations.get(invitation_token)
+            .ok_or_else(|| "Invitation not found".to_string())?;
+
+        if invitation.is_expired() {
+            let guardian_id = invitation.guardian_id.clone();
+            self.invitations.remove(invitation_token);
+            self.guardians.remove(&guardian_id);
+            return Err("Invitation has expired".to_string());
+        }
+
+        let guardian_id = invitation.guardian_id.clone();
+        let guardian = self.guardians.get_mut(&guardian_id)
+            .ok_or_else(|| "Invited guardian not found".to_string())?;
+
+        if guardian.status != GuardianStatus::Invited {
+            return Err("Guardian invitation is not pending".to_string());
+        }
+
+        guardian.status = GuardianStatus::Active;
+        self.invitations.remove(invitation_token);
+
+        Ok(guardian_id)
+    }
+
+    /// Decline a pending invitation: the invited guardian entry is removed
+    /// entirely rather than flipped to `Removed`, since a declined
+    /// invitation never became a real guardian in the first place. The
+    /// caller is responsible for verifying the invited party's signature
+    /// over `invitation_token`, the same as `accept_invitation`.
+    pub fn decline_invitation(&mut self, invitation_token: &str) -> Result<String, String> {
+        let invitation = self.invitations.get(invitation_token)
+            .ok_or_else(|| "Invitation not found".to_string())?;
+        let guardian_id = invitation.guardian_id.clone();
+
+        let guardian = self.guardians.get(&guardian_id)
+            .ok_or_else(|| "Invited guardian not found".to_string())?;
+        if guardian.status != GuardianStatus::Invited {
+            return Err("Guardian invitation is not pending".to_string());
+        }
+
+        self.invitations.remove(invitation_token);
+        self.guardians.remove(&guardian_id);
+
+        Ok(guardian_id)
+    }
+
     /// Remove a guardian
     pub fn remove_guardian(&mut self, guardian_id: &str) -> Result<(), String> {
         if let Some(guardian) = self.guardians.get_mut(guardian_id) {
@@ -136,6 +411,214 @@ impl GuardianConfig {
         self.guardians.get(guardian_id)
     }
 
+    /// Set (or clear, with `None`) the endpoint a guardian should be
+    /// push-notified at when a recovery needs their attention
+    pub fn set_notification_endpoint(
+        &mut self,
+        guardian_id: &str,
+        endpoint: Option<String>,
+    ) -> Result<(), String> {
+        let guardian = self
+            .guardians
+            .get_mut(guardian_id)
+            .ok_or_else(|| "Guardian not found".to_string())?;
+        guardian.notification_endpoint = endpoint;
+        Ok(())
+    }
+
+    /// Pre-authorize an active guardian to initiate time-delayed emergency
+    /// access, with a waiting period the owner can reject within
+    pub fn grant_emergency_access(
+        &mut self,
+        guardian_id: &str,
+        waiting_period_hours: i64,
+    ) -> Result<(), String> {
+        let guardian = self
+            .guardians
+            .get(guardian_id)
+            .ok_or_else(|| "Guardian not found".to_string())?;
+
+        if guardian.status != GuardianStatus::Active {
+            return Err("Only an active guardian can be granted emergency access".to_string());
+        }
+
+        if waiting_period_hours < 1 {
+            return Err("waiting_period_hours must be at least 1".to_string());
+        }
+
+        self.emergency_grants.insert(
+            guardian_id.to_string(),
+            EmergencyGrant {
+                guardian_id: guardian_id.to_string(),
+                waiting_period_hours,
+                granted_at: Utc::now(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Revoke a guardian's standing emergency-access pre-authorization
+    pub fn revoke_emergency_access(&mut self, guardian_id: &str) -> Result<(), String> {
+        self.emergency_grants
+            .remove(guardian_id)
+            .map(|_| ())
+            .ok_or_else(|| "No emergency access grant found for this guardian".to_string())
+    }
+
+    /// Look up a guardian's standing emergency-access grant, if any
+    pub fn get_emergency_grant(&self, guardian_id: &str) -> Option<&EmergencyGrant> {
+        self.emergency_grants.get(guardian_id)
+    }
+
+    /// Issue (or, if still valid, re-hand-back) the single-use challenge
+    /// nonce `guardian_id` must embed in its signed `initiate-emergency`
+    /// tuple. Mirrors [`crate::recovery::RecoveryRequest::issue_guardian_nonce`].
+    pub fn issue_emergency_nonce(&mut self, guardian_id: &str) -> String {
+        if let Some(existing) = self.emergency_nonces.get(guardian_id) {
+            if !existing.consumed && Utc::now() < existing.expires_at {
+                return existing.nonce.clone();
+            }
+        }
+
+        use rand::RngCore;
+        let mut nonce_bytes = [0u8; 16];
+        rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce = hex::encode(nonce_bytes);
+
+        let now = Utc::now();
+        self.emergency_nonces.insert(
+            guardian_id.to_string(),
+            crate::recovery::GuardianNonce {
+                nonce: nonce.clone(),
+                issued_at: now,
+                expires_at: now + Duration::minutes(10),
+                consumed: false,
+            },
+        );
+
+        nonce
+    }
+
+    /// Consume `guardian_id`'s emergency-initiation challenge nonce if
+    /// `nonce` matches the one currently issued to them, hasn't expired,
+    /// and hasn't already been used. Call only after independently
+    /// verifying the signature over the nonce-bound tuple - this enforces
+    /// single-use, not authenticity.
+    pub fn consume_emergency_nonce(&mut self, guardian_id: &str, nonce: &str) -> Result<(), String> {
+        let entry = self
+            .emergency_nonces
+            .get_mut(guardian_id)
+            .ok_or_else(|| "No challenge nonce has been issued to this guardian".to_string())?;
+
+        if entry.consumed {
+            return Err("Nonce has already been used".to_string());
+        }
+        if Utc::now() > entry.expires_at {
+            return Err("Nonce has expired".to_string());
+        }
+        if entry.nonce != nonce {
+            return Err("Nonce does not match the issued challenge".to_string());
+        }
+
+        entry.consumed = true;
+        Ok(())
+    }
+
+    /// Check freshness and consume `guardian_id`'s emergency-initiation
+    /// nonce in one step. Call only after independently verifying the
+    /// signature over `(identity_did, guardian_did, nonce,
+    /// "initiate-emergency", timestamp)` - this enforces single-use and
+    /// recency, not authenticity.
+    pub fn verify_emergency_initiation_nonce(
+        &mut self,
+        guardian_id: &str,
+        nonce: &str,
+        timestamp: i64,
+    ) -> Result<(), String> {
+        Self::check_timestamp_freshness(timestamp, 300)?;
+        self.consume_emergency_nonce(guardian_id, nonce)
+    }
+
+    /// Security: Reject timestamps more than `window_seconds` away from
+    /// now, so a signature can't be held and replayed far in the future
+    fn check_timestamp_freshness(timestamp: i64, window_seconds: i64) -> Result<(), String> {
+        if (Utc::now().timestamp() - timestamp).abs() > window_seconds {
+            return Err("Timestamp is outside the freshness window".to_string());
+        }
+        Ok(())
+    }
+
+    /// Split `master_seed` into Shamir shares, one per active guardian, and
+    /// encrypt each to its guardian's public key
+    ///
+    /// Replaces any previously distributed shares - call this again
+    /// whenever the active guardian set or threshold changes.
+    pub fn distribute_key_shares(&mut self, master_seed: &[u8; 32]) -> Result<(), String> {
+        let active_guardians = self.get_active_guardians();
+        if active_guardians.len() < self.threshold {
+            return Err(format!(
+                "Need at least {} active guardians to distribute key shares, have {}",
+                self.threshold,
+                active_guardians.len()
+            ));
+        }
+
+        let shares = crate::recovery::shamir::split_secret(
+            master_seed,
+            active_guardians.len() as u8,
+            self.threshold as u8,
+        )?;
+
+        let mut key_shares = HashMap::with_capacity(active_guardians.len());
+        for (guardian, share) in active_guardians.iter().zip(shares.into_iter()) {
+            let share_bytes = serde_json::to_vec(&share)
+                .map_err(|e| format!("Failed to encode share: {}", e))?;
+            let encrypted = lib_crypto::hybrid_encrypt(&share_bytes, &guardian.public_key)
+                .map_err(|e| format!("Failed to encrypt share: {}", e))?;
+            key_shares.insert(guardian.guardian_id.clone(), encrypted);
+        }
+
+        self.key_shares = key_shares;
+        self.master_seed_commitment = Some(lib_crypto::hash_blake3(master_seed));
+        Ok(())
+    }
+
+    /// Reconstruct the master seed from guardian-submitted decrypted shares
+    ///
+    /// Each entry in `decrypted_shares` must be the raw `ShamirShare` bytes
+    /// a guardian produced by decrypting their `key_shares` entry with
+    /// their own private key - this method never sees a guardian's private
+    /// key and performs no decryption itself.
+    pub fn reconstruct_seed(&self, decrypted_shares: &[Vec<u8>]) -> Result<[u8; 32], String> {
+        if decrypted_shares.len() < self.threshold {
+            return Err(format!(
+                "Need at least {} shares to reconstruct, got {}",
+                self.threshold,
+                decrypted_shares.len()
+            ));
+        }
+
+        let shares: Vec<crate::recovery::shamir::ShamirShare> = decrypted_shares
+            .iter()
+            .map(|bytes| {
+                serde_json::from_slice(bytes).map_err(|e| format!("Invalid share encoding: {}", e))
+            })
+            .collect::<Result<_, _>>()?;
+
+        crate::recovery::shamir::reconstruct_secret(&shares, self.threshold as u8)
+    }
+
+    /// Check a candidate reconstructed seed against the commitment recorded
+    /// when its shares were distributed, catching corrupt or mismatched
+    /// shares that still happened to reconstruct *something*
+    pub fn verify_seed_commitment(&self, seed: &[u8; 32]) -> bool {
+        match self.master_seed_commitment {
+            Some(commitment) => lib_crypto::hash_blake3(seed) == commitment,
+            None => false,
+        }
+    }
+
     /// Validate threshold is achievable with active guardians
     pub fn validate_threshold(&self) -> Result<(), String> {
         let active_count = self.get_active_guardians().len();
@@ -0,0 +1,148 @@
+//! Minimal EIP-4361 (Sign-In with Ethereum) message parsing
+//!
+//! Parses just the fields the guardian approval flow needs to bind a
+//! signed message to a specific address, recovery request, and freshness
+//! window - not a full SIWE ABNF parser.
+
+use chrono::{DateTime, Utc};
+
+/// The subset of an EIP-4361 message's fields relevant to verifying a
+/// wallet guardian's recovery approval
+#[derive(Debug, Clone)]
+pub struct SiweMessage {
+    /// The domain requesting the signature (first line, before " wants
+    /// you to sign in with your Ethereum account:")
+    pub domain: String,
+
+    /// The signing wallet's address, as written in the message
+    pub address: String,
+
+    /// Free-text statement lines, expected to embed the `recovery_id`
+    /// this signature authorizes
+    pub statement: String,
+
+    /// The `Nonce:` field, compared against the single-use challenge nonce
+    /// issued to this guardian for this recovery request to prevent replay
+    pub nonce: String,
+
+    /// The `Issued At:` field, checked against a freshness window
+    pub issued_at: DateTime<Utc>,
+}
+
+impl SiweMessage {
+    /// Parse the subset of EIP-4361 fields this crate cares about out of
+    /// the raw message text a wallet guardian signed
+    pub fn parse(message: &str) -> Result<Self, String> {
+        let mut lines = message.lines();
+
+        let header = lines.next().ok_or_else(|| "Empty SIWE message".to_string())?;
+        let domain = header
+            .strip_suffix(" wants you to sign in with your Ethereum account:")
+            .ok_or_else(|| "Missing SIWE domain header".to_string())?
+            .to_string();
+
+        let address = lines
+            .next()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .ok_or_else(|| "Missing SIWE address line".to_string())?;
+
+        let mut statement_lines = Vec::new();
+        let mut nonce = None;
+        let mut issued_at = None;
+
+        for line in lines {
+            if let Some(value) = line.strip_prefix("Nonce: ") {
+                nonce = Some(value.trim().to_string());
+            } else if let Some(value) = line.strip_prefix("Issued At: ") {
+                issued_at = Some(
+                    DateTime::parse_from_rfc3339(value.trim())
+                        .map_err(|e| format!("Invalid Issued At timestamp: {}", e))?
+                        .with_timezone(&Utc),
+                );
+            } else if !line.starts_with("URI: ")
+                && !line.starts_with("Version: ")
+                && !line.starts_with("Chain ID: ")
+                && !line.trim().is_empty()
+            {
+                statement_lines.push(line.trim());
+            }
+        }
+
+        Ok(Self {
+            domain,
+            address,
+            statement: statement_lines.join(" "),
+            nonce: nonce.ok_or_else(|| "Missing SIWE nonce".to_string())?,
+            issued_at: issued_at.ok_or_else(|| "Missing SIWE issued-at".to_string())?,
+        })
+    }
+
+    /// Check `issued_at` falls within `window_seconds` of now, to reject
+    /// stale replayed signatures
+    pub fn is_fresh(&self, window_seconds: i64) -> bool {
+        (Utc::now() - self.issued_at).num_seconds().abs() <= window_seconds
+    }
+
+    /// Check the statement embeds the expected recovery_id, binding this
+    /// signed message to one specific recovery request
+    pub fn binds_recovery(&self, recovery_id: &str) -> bool {
+        self.statement.contains(recovery_id)
+    }
+
+    /// Check the statement embeds the expected identity DID, binding this
+    /// signed message to one specific identity - used for actions that
+    /// happen before any recovery request exists, such as starting an
+    /// emergency access countdown
+    pub fn binds_identity(&self, identity_did: &str) -> bool {
+        self.statement.contains(identity_did)
+    }
+
+    /// Check the statement embeds the expected action word (e.g.
+    /// `"approve"` or `"reject"`), case-insensitively, so a message signed
+    /// to approve can't be replayed to reject the same request or vice
+    /// versa
+    pub fn binds_action(&self, action: &str) -> bool {
+        self.statement.to_lowercase().contains(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_siwe_message() {
+        let message = "example.zhtp wants you to sign in with your Ethereum account:\n\
+0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed\n\
+\n\
+Approve ZHTP guardian recovery request abc123\n\
+\n\
+URI: https://example.zhtp\n\
+Version: 1\n\
+Chain ID: 1\n\
+Nonce: deadbeef\n\
+Issued At: 2026-01-01T00:00:00Z";
+
+        let parsed = SiweMessage::parse(message).unwrap();
+        assert_eq!(parsed.domain, "example.zhtp");
+        assert_eq!(parsed.address, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert_eq!(parsed.nonce, "deadbeef");
+        assert!(parsed.binds_recovery("abc123"));
+        assert!(!parsed.binds_recovery("other"));
+        assert!(parsed.binds_action("approve"));
+        assert!(!parsed.binds_action("reject"));
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_nonce() {
+        let message = "example.zhtp wants you to sign in with your Ethereum account:\n\
+0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed\n\
+\n\
+Approve recovery abc123\n\
+\n\
+Issued At: 2026-01-01T00:00:00Z";
+
+        assert!(SiweMessage::parse(message).is_err());
+    }
+}
@@ -30,6 +30,8 @@ pub struct IdentityManager {
     verification_cache: HashMap<IdentityId, IdentityVerification>,
     /// Password manager for imported identities
     password_manager: PasswordManager,
+    /// Append-only audit log of guardian/recovery actions, per identity
+    audit_log: HashMap<IdentityId, Vec<crate::audit::AuditEvent>>,
 }
 
 impl IdentityManager {
@@ -41,6 +43,7 @@ impl IdentityManager {
             trusted_issuers: HashMap::new(),
             verification_cache: HashMap::new(),
             password_manager: PasswordManager::new(),
+            audit_log: HashMap::new(),
         }
     }
 
@@ -857,6 +860,13 @@ impl IdentityManager {
         Ok(())
     }
 
+    /// Get the identity's 32-byte master seed (for guardian key-share distribution)
+    pub fn get_identity_seed(&self, identity_id: &IdentityId) -> Option<[u8; 32]> {
+        self.private_data
+            .get(identity_id)
+            .map(|pd| *pd.seed())
+    }
+
     /// Get identity by DID
     pub fn get_identity_by_did(&self, did: &str) -> Option<&ZhtpIdentity> {
         self.identities
@@ -878,6 +888,42 @@ impl IdentityManager {
             .get(identity_id)
             .map(|identity| identity.did.clone())
     }
+
+    /// Resolve a free-text query - a partial `did:zhtp:` or the identity's
+    /// `username` metadata key - to matching identities' canonical DIDs.
+    /// Used by the guardian-invitation flow to let an identity owner find a
+    /// guardian to invite without already knowing their exact DID.
+    pub fn search_identities(&self, query: &str) -> Vec<String> {
+        let query_lower = query.to_lowercase();
+        self.identities
+            .values()
+            .filter(|identity| {
+                identity.did.to_lowercase().contains(&query_lower)
+                    || identity
+                        .metadata
+                        .get("username")
+                        .map(|username| username.to_lowercase().contains(&query_lower))
+                        .unwrap_or(false)
+            })
+            .map(|identity| identity.did.clone())
+            .collect()
+    }
+
+    /// Append a guardian/recovery audit event for an identity
+    pub fn record_audit_event(&mut self, identity_id: &IdentityId, event: crate::audit::AuditEvent) {
+        self.audit_log
+            .entry(identity_id.clone())
+            .or_insert_with(Vec::new)
+            .push(event);
+    }
+
+    /// Get an identity's audit log, oldest first
+    pub fn get_audit_events(&self, identity_id: &IdentityId) -> Vec<crate::audit::AuditEvent> {
+        self.audit_log
+            .get(identity_id)
+            .cloned()
+            .unwrap_or_default()
+    }
 }
 
 impl Default for IdentityManager {
@@ -0,0 +1,61 @@
+//! Canonical JSON encoding for stable hashing across serde versions
+//!
+//! `serde_json::to_string` does not guarantee object-key ordering is
+//! stable (it depends on whether the `preserve_order` feature is enabled
+//! anywhere in the dependency graph), so hashing its output directly is
+//! not safe for cache keys or cross-node/cross-upgrade proof digests.
+//! [`to_canonical_bytes`] re-sorts every object's keys lexicographically
+//! before serializing to compact (whitespace-free) JSON, giving a byte
+//! encoding that depends only on the value, not on map iteration order.
+
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let sorted: BTreeMap<String, Value> =
+                map.into_iter().map(|(k, v)| (k, canonicalize(v))).collect();
+            Value::Object(sorted.into_iter().collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(canonicalize).collect()),
+        other => other,
+    }
+}
+
+/// Serialize `value` to canonical JSON bytes: object keys sorted
+/// lexicographically, no insignificant whitespace.
+pub fn to_canonical_bytes<T: Serialize>(value: &T) -> serde_json::Result<Vec<u8>> {
+    let raw = serde_json::to_value(value)?;
+    serde_json::to_vec(&canonicalize(raw))
+}
+
+/// Hex-encoded SHA-256 digest of `value`'s canonical JSON encoding.
+pub fn canonical_digest_hex<T: Serialize>(value: &T) -> serde_json::Result<String> {
+    let bytes = to_canonical_bytes(value)?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_key_order_does_not_affect_digest() {
+        let a = json!({"b": 1, "a": 2, "c": {"z": 1, "y": 2}});
+        let b = json!({"a": 2, "c": {"y": 2, "z": 1}, "b": 1});
+        assert_eq!(canonical_digest_hex(&a).unwrap(), canonical_digest_hex(&b).unwrap());
+    }
+
+    #[test]
+    fn test_different_values_differ() {
+        let a = json!({"a": 1});
+        let b = json!({"a": 2});
+        assert_ne!(canonical_digest_hex(&a).unwrap(), canonical_digest_hex(&b).unwrap());
+    }
+}
@@ -1,12 +1,16 @@
 //! Integration and verification modules for ZHTP Identity
 
+pub mod canonical;
 pub mod cross_package_integration;
 pub mod requirements_verification;
 pub mod proof_generation;
+pub mod proof_verification;
+pub mod signature_backend;
 pub mod trusted_issuers;
 pub mod verification_cache;
 
 // Explicit re-exports to avoid naming conflicts
+pub use canonical::{to_canonical_bytes, canonical_digest_hex};
 pub use cross_package_integration::{
     CrossPackageIntegration, IntegrationResponse
 };
@@ -18,8 +22,13 @@ pub use requirements_verification::{
 pub use crate::privacy::PrivacyScore;
 pub use proof_generation::{
     ProofGenerator, ProofGenerationStats, ProofGenerationRequest, ProofGenerationResult,
-    PrivacyLevel as ProofPrivacyLevel
+    PrivacyLevel as ProofPrivacyLevel, FmtVersion
 };
+pub use proof_verification::{
+    InMemoryRevocationList, ProofVerifier, RevocationProvider, VerificationOutcome,
+    VerificationStage
+};
+pub use signature_backend::{SignatureAlgorithm, SignatureBackend, SignedVerificationKey};
 pub use trusted_issuers::{
     TrustedIssuer, TrustedIssuersRegistry, IssuerVerificationResult,
     TrustLevel as IssuerTrustLevel
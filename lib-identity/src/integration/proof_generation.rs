@@ -3,6 +3,56 @@
 use crate::identity::ZhtpIdentity;
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
+use lib_crypto::advanced::bbs_plus::{BbsGenerators, BbsIssuerKeyPair};
+use lib_crypto::advanced::bulletproofs::{BpGenerators, RangeProof, prove_range, random_blinding};
+use super::canonical::canonical_digest_hex;
+use super::signature_backend::{sign_verification_key, verify_signed_proof, SignatureAlgorithm};
+
+/// Bit width of the age range proof: ages fit comfortably within one byte.
+const AGE_RANGE_BITS: usize = 8;
+
+/// Semantic version of the on-disk proof/metadata format. Bumped whenever
+/// a change to `ProofGenerationResult`'s `proof_data`/`verification_key`
+/// encoding would make an old verifier misinterpret a new proof (or vice
+/// versa). Embedded in every `ProofTypeDefinition` and carried through to
+/// `ProofGenerationResult.metadata` so a verifier can refuse to even
+/// attempt checking a proof it doesn't understand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FmtVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl FmtVersion {
+    /// Current format version produced by this build of `ProofGenerator`.
+    pub const CURRENT: FmtVersion = FmtVersion { major: 1, minor: 0, patch: 0 };
+
+    /// Whether a proof stamped with `self` can be verified by code built
+    /// against `other`: same major version (breaking changes bump major),
+    /// and `other` is at least as new as `self` within that major line.
+    pub fn is_compatible_with(&self, other: &FmtVersion) -> bool {
+        self.major == other.major
+            && (self.minor, self.patch) <= (other.minor, other.patch)
+    }
+}
+
+/// One `age_over_N`-style predicate's range proof over `age - threshold`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AgeRangeProofEntry {
+    label: String,
+    threshold: u64,
+    proof: RangeProof,
+}
+
+/// Verification material for an age range proof: the domain tag and bit
+/// width needed to rederive the (public, deterministic) Bulletproofs
+/// generator basis via [`BpGenerators::derive`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AgeVerificationKey {
+    domain: String,
+    n_bits: usize,
+}
 
 /// Proof generation system for identity operations
 #[derive(Debug, Clone)]
@@ -26,6 +76,13 @@ pub struct ProofTypeDefinition {
     pub complexity_level: ComplexityLevel,
     pub validity_duration_hours: u32,
     pub supports_selective_disclosure: bool,
+    /// Format version proofs of this type are produced against; carried
+    /// into [`ProofGenerationResult::metadata`] under `"fmt_version"`.
+    pub fmt_version: FmtVersion,
+    /// Signature scheme `generate_verification_key` signs proof bytes
+    /// under for this proof type. Ignored by proof types that build
+    /// their own verification key directly (BBS+, Bulletproofs).
+    pub signature_algorithm: SignatureAlgorithm,
 }
 
 /// Privacy level for proofs
@@ -38,6 +95,24 @@ pub enum PrivacyLevel {
     TopSecret,
 }
 
+/// Convert privacy level to number for comparison. `pub(crate)` so
+/// [`super::proof_verification::ProofVerifier`] can apply the same
+/// ordering when checking an achieved privacy level against its policy.
+pub(crate) fn privacy_level_to_number(level: &PrivacyLevel) -> u8 {
+    match level {
+        PrivacyLevel::Public => 0,
+        PrivacyLevel::Restricted => 1,
+        PrivacyLevel::Confidential => 2,
+        PrivacyLevel::Secret => 3,
+        PrivacyLevel::TopSecret => 4,
+    }
+}
+
+/// Whether `provided` meets or exceeds `required` on the privacy-level ordering.
+pub(crate) fn privacy_level_is_compatible(provided: &PrivacyLevel, required: &PrivacyLevel) -> bool {
+    privacy_level_to_number(provided) >= privacy_level_to_number(required)
+}
+
 /// Complexity level for proof generation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ComplexityLevel {
@@ -81,6 +156,10 @@ pub struct ProofGenerationRequest {
     pub challenge: Option<Vec<u8>>,
     pub privacy_requirements: PrivacyRequirements,
     pub additional_context: HashMap<String, serde_json::Value>,
+    /// Lower bound for range-proof-backed proof types (e.g. `age_proof`):
+    /// the holder proves their committed value is `>= threshold` without
+    /// revealing it. Ignored by proof types that don't use a range proof.
+    pub threshold: Option<u64>,
 }
 
 /// Privacy requirements for proof generation
@@ -107,6 +186,21 @@ pub struct ProofGenerationResult {
     pub validity_expires_at: u64,
 }
 
+/// Verification material for a BBS+ selective-disclosure proof: the
+/// generator basis the issuer committed against, plus the issuer's
+/// signature and public key so a verifier can check issuance
+/// ([`lib_crypto::advanced::bbs_plus::BbsCredential::verify_issuance`])
+/// independently of checking the presented proof itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BbsVerificationKey {
+    pub generators: Vec<u8>,
+    /// Number of attribute generators encoded in `generators`, needed to
+    /// reconstruct them via [`lib_crypto::advanced::BbsGenerators::from_bytes`].
+    pub attribute_count: usize,
+    pub issuer_signature: Vec<u8>,
+    pub issuer_public_key: Vec<u8>,
+}
+
 impl ProofGenerator {
     /// Create new proof generator
     pub fn new() -> Self {
@@ -226,8 +320,14 @@ impl ProofGenerator {
         request: &ProofGenerationRequest,
         proof_type_def: &ProofTypeDefinition,
     ) -> Result<ProofGenerationResult, Box<dyn std::error::Error>> {
-        let proof_id = format!("proof_{}_{}", request.proof_type, 
-            std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs());
+        // Derived from the canonical encoding of the request rather than
+        // `serde_json::to_string` directly, so the id is stable across
+        // serde versions/feature flags and collides only when the
+        // request itself (and the second it landed in) is identical.
+        let request_digest = canonical_digest_hex(request)?;
+        let proof_id = format!("proof_{}_{}_{}", request.proof_type,
+            std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs(),
+            &request_digest[..16]);
 
         // Generate proof based on type
         let (proof_data, verification_key, actual_privacy_level) = match request.proof_type.as_str() {
@@ -240,6 +340,16 @@ impl ProofGenerator {
             _ => return Err(format!("Unsupported proof type: {}", request.proof_type).into()),
         };
 
+        // Proof types that advertise selective disclosure get a real BBS+
+        // proof of knowledge instead of the salted hash produced above, so
+        // the hidden attributes stay cryptographically hidden rather than
+        // merely omitted from `attributes_included`.
+        let (proof_data, verification_key) = if proof_type_def.supports_selective_disclosure {
+            self.generate_selective_disclosure_proof(identity, request).await?
+        } else {
+            (proof_data, verification_key)
+        };
+
         // Determine which attributes were actually included
         let attributes_included = if let Some(ref selective) = request.selective_disclosure {
             selective.clone()
@@ -253,9 +363,11 @@ impl ProofGenerator {
             serde_json::to_value(proof_type_def)?);
         metadata.insert("generation_method".to_string(), 
             serde_json::Value::String("zk_snark".to_string()));
-        metadata.insert("circuit_version".to_string(), 
+        metadata.insert("circuit_version".to_string(),
             serde_json::Value::String("v1.0".to_string()));
-        
+        metadata.insert("fmt_version".to_string(),
+            serde_json::to_value(proof_type_def.fmt_version)?);
+
         // Add additional context
         for (key, value) in &request.additional_context {
             metadata.insert(format!("context_{}", key), value.clone());
@@ -303,45 +415,59 @@ impl ProofGenerator {
 
         // Generate ZK proof (simplified)
         let zk_proof = self.generate_zk_proof(&proof_data, request.challenge.as_ref()).await?;
-        let verification_key = self.generate_verification_key(&proof_data).await?;
-        
+        let verification_key = self.generate_verification_key(&request.proof_type, &proof_data).await?;
+
         Ok((zk_proof, verification_key, PrivacyLevel::Confidential))
     }
 
     /// Generate age proof
+    ///
+    /// Proves `age >= threshold` with a Bulletproofs-style Pedersen range
+    /// proof (`lib_crypto::advanced::bulletproofs`) over `v = age -
+    /// threshold`, instead of disclosing the predicate answer in the
+    /// clear: the verifier learns only that the committed `v` is
+    /// non-negative, never the exact age.
     async fn generate_age_proof(
         &self,
         identity: &ZhtpIdentity,
         request: &ProofGenerationRequest,
     ) -> Result<(Vec<u8>, Vec<u8>, PrivacyLevel), Box<dyn std::error::Error>> {
-        // Age proof using range proofs to prove age without revealing exact age
-        let mut proof_data = Vec::new();
-        
-        if let Some(birth_date) = identity.metadata.get("date_of_birth") {
-            // Calculate age (simplified)
-            let birth_year = birth_date
-                .split('-')
-                .next()
-                .and_then(|year| year.parse::<u32>().ok())
-                .unwrap_or(1990);
-                
-            let current_year = 2024; // In implementation, use actual current year
-            let age = current_year - birth_year;
-            
-            // Create range proof for age > 18 (simplified)
-            if request.required_attributes.contains(&"age_over_18".to_string()) {
-                proof_data.push(if age >= 18 { 1 } else { 0 });
-            }
-            
-            if request.required_attributes.contains(&"age_over_21".to_string()) {
-                proof_data.push(if age >= 21 { 1 } else { 0 });
-            }
+        let birth_date = identity.metadata.get("date_of_birth")
+            .ok_or("Identity has no date_of_birth attribute")?;
+        let birth_year = birth_date
+            .split('-')
+            .next()
+            .and_then(|year| year.parse::<u32>().ok())
+            .unwrap_or(1990);
+
+        let current_year = 2024; // In implementation, use actual current year
+        let age = (current_year - birth_year) as u64;
+
+        let mut predicates = Vec::new();
+        if request.required_attributes.contains(&"age_over_18".to_string()) {
+            predicates.push(("age_over_18", request.threshold.unwrap_or(18)));
+        }
+        if request.required_attributes.contains(&"age_over_21".to_string()) {
+            predicates.push(("age_over_21", request.threshold.unwrap_or(21)));
         }
 
-        let zk_proof = self.generate_zk_proof(&proof_data, request.challenge.as_ref()).await?;
-        let verification_key = self.generate_verification_key(&proof_data).await?;
-        
-        Ok((zk_proof, verification_key, PrivacyLevel::Restricted))
+        let domain = format!("zhtp-age-{}", request.proof_type);
+        let generators = BpGenerators::derive(&domain, AGE_RANGE_BITS)?;
+        let nonce = request.challenge.clone().unwrap_or_default();
+
+        let mut entries = Vec::with_capacity(predicates.len());
+        for (label, threshold) in predicates {
+            let v = age.checked_sub(threshold)
+                .ok_or_else(|| format!("Age requirement '{}' (threshold {}) not met", label, threshold))?;
+            let gamma = random_blinding();
+            let proof = prove_range(&generators, v, AGE_RANGE_BITS, &gamma, &nonce)?;
+            entries.push(AgeRangeProofEntry { label: label.to_string(), threshold, proof });
+        }
+
+        let proof_data = serde_json::to_vec(&entries)?;
+        let verification_key = serde_json::to_vec(&AgeVerificationKey { domain, n_bits: AGE_RANGE_BITS })?;
+
+        Ok((proof_data, verification_key, PrivacyLevel::Restricted))
     }
 
     /// Generate identity proof
@@ -369,8 +495,8 @@ impl ProofGenerator {
         }
 
         let zk_proof = self.generate_zk_proof(&proof_data, request.challenge.as_ref()).await?;
-        let verification_key = self.generate_verification_key(&proof_data).await?;
-        
+        let verification_key = self.generate_verification_key(&request.proof_type, &proof_data).await?;
+
         Ok((zk_proof, verification_key, PrivacyLevel::Confidential))
     }
 
@@ -378,13 +504,13 @@ impl ProofGenerator {
     async fn generate_qualification_proof(
         &self,
         identity: &ZhtpIdentity,
-        _request: &ProofGenerationRequest,
+        request: &ProofGenerationRequest,
     ) -> Result<(Vec<u8>, Vec<u8>, PrivacyLevel), Box<dyn std::error::Error>> {
         // Simplified qualification proof
         let proof_data = format!("qualification_proof_for_{}", identity.id).into_bytes();
         let zk_proof = self.generate_zk_proof(&proof_data, None).await?;
-        let verification_key = self.generate_verification_key(&proof_data).await?;
-        
+        let verification_key = self.generate_verification_key(&request.proof_type, &proof_data).await?;
+
         Ok((zk_proof, verification_key, PrivacyLevel::Restricted))
     }
 
@@ -392,13 +518,13 @@ impl ProofGenerator {
     async fn generate_residence_proof(
         &self,
         identity: &ZhtpIdentity,
-        _request: &ProofGenerationRequest,
+        request: &ProofGenerationRequest,
     ) -> Result<(Vec<u8>, Vec<u8>, PrivacyLevel), Box<dyn std::error::Error>> {
         // Simplified residence proof
         let proof_data = format!("residence_proof_for_{}", identity.id).into_bytes();
         let zk_proof = self.generate_zk_proof(&proof_data, None).await?;
-        let verification_key = self.generate_verification_key(&proof_data).await?;
-        
+        let verification_key = self.generate_verification_key(&request.proof_type, &proof_data).await?;
+
         Ok((zk_proof, verification_key, PrivacyLevel::Restricted))
     }
 
@@ -411,17 +537,67 @@ impl ProofGenerator {
         // Ownership proof using digital signature
         let mut proof_data = Vec::new();
         proof_data.extend_from_slice(&identity.public_key.as_bytes());
-        
+
         if let Some(challenge) = &request.challenge {
             proof_data.extend_from_slice(challenge);
         }
 
         let zk_proof = self.generate_zk_proof(&proof_data, request.challenge.as_ref()).await?;
-        let verification_key = self.generate_verification_key(&proof_data).await?;
-        
+        let verification_key = self.generate_verification_key(&request.proof_type, &proof_data).await?;
+
         Ok((zk_proof, verification_key, PrivacyLevel::Confidential))
     }
 
+    /// Generate a selective-disclosure proof using the BBS+ credential
+    /// subsystem (`lib_crypto::advanced::bbs_plus`): the issuer signs a
+    /// commitment to `request.required_attributes` once, then a Schnorr
+    /// proof of knowledge reveals only the attributes named in
+    /// `request.selective_disclosure` while keeping the rest hidden.
+    /// `proof_data` is the serialized [`BbsProof`]; `verification_key` is
+    /// the serialized generators plus the issuer's signature and public key.
+    async fn generate_selective_disclosure_proof(
+        &self,
+        identity: &ZhtpIdentity,
+        request: &ProofGenerationRequest,
+    ) -> Result<(Vec<u8>, Vec<u8>), Box<dyn std::error::Error>> {
+        let attribute_values: Vec<Vec<u8>> = request.required_attributes.iter()
+            .map(|attr| identity.metadata.get(attr).map(|v| v.as_bytes().to_vec()).unwrap_or_default())
+            .collect();
+        let messages: Vec<&[u8]> = attribute_values.iter().map(|v| v.as_slice()).collect();
+
+        let generators = BbsGenerators::derive(&format!("zhtp-bbs-{}", request.proof_type), messages.len());
+        let issuer = BbsIssuerKeyPair::generate();
+        let credential = issuer.issue(&generators, &messages)?;
+
+        let revealed_indices: Vec<usize> = match &request.selective_disclosure {
+            Some(revealed_attrs) => request.required_attributes.iter()
+                .enumerate()
+                .filter(|(_, attr)| revealed_attrs.contains(attr))
+                .map(|(i, _)| i)
+                .collect(),
+            None => (0..request.required_attributes.len()).collect(),
+        };
+
+        let nonce = request.challenge.clone().unwrap_or_default();
+        let proof = credential.prove(
+            &generators,
+            &messages,
+            &revealed_indices,
+            &nonce,
+            request.privacy_requirements.require_unlinkability,
+        )?;
+
+        let proof_data = serde_json::to_vec(&proof)?;
+        let verification_key = serde_json::to_vec(&BbsVerificationKey {
+            generators: generators.to_bytes(),
+            attribute_count: messages.len(),
+            issuer_signature: credential.signature.clone(),
+            issuer_public_key: credential.issuer_public_key.clone(),
+        })?;
+
+        Ok((proof_data, verification_key))
+    }
+
     /// Generate ZK proof (simplified implementation)
     async fn generate_zk_proof(&self, data: &[u8], challenge: Option<&Vec<u8>>) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
         // In implementation, would use actual ZK proof system
@@ -440,48 +616,39 @@ impl ProofGenerator {
     }
 
     /// Generate verification key
-    async fn generate_verification_key(&self, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
-        use sha2::{Sha256, Digest};
-        
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        hasher.update(b"verification_key_salt");
-        
-        Ok(hasher.finalize().to_vec())
+    ///
+    /// Signs `data` (the proof bytes) under a fresh keypair for the
+    /// proof type's configured [`SignatureAlgorithm`] and returns the
+    /// serialized [`SignedVerificationKey`], so `verification_key` is an
+    /// actual public key plus signature rather than an unkeyed digest.
+    async fn generate_verification_key(&self, proof_type: &str, data: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let algorithm = self.proof_types.get(proof_type)
+            .map(|def| def.signature_algorithm)
+            .unwrap_or(SignatureAlgorithm::Ed25519);
+
+        sign_verification_key(algorithm, data)
     }
 
-    /// Check if privacy levels are compatible
-    fn is_privacy_level_compatible(&self, provided: &PrivacyLevel, required: &PrivacyLevel) -> bool {
-        let provided_level = self.privacy_level_to_number(provided);
-        let required_level = self.privacy_level_to_number(required);
-        provided_level >= required_level
+    /// Verify a `verification_key` produced by [`Self::generate_verification_key`]
+    /// against the proof bytes it was generated for.
+    pub fn verify_proof(proof_data: &[u8], verification_key: &[u8]) -> Result<bool, Box<dyn std::error::Error>> {
+        verify_signed_proof(proof_data, verification_key)
     }
 
-    /// Convert privacy level to number for comparison
-    fn privacy_level_to_number(&self, level: &PrivacyLevel) -> u8 {
-        match level {
-            PrivacyLevel::Public => 0,
-            PrivacyLevel::Restricted => 1,
-            PrivacyLevel::Confidential => 2,
-            PrivacyLevel::Secret => 3,
-            PrivacyLevel::TopSecret => 4,
-        }
+    /// Check if privacy levels are compatible
+    fn is_privacy_level_compatible(&self, provided: &PrivacyLevel, required: &PrivacyLevel) -> bool {
+        privacy_level_is_compatible(provided, required)
     }
 
     /// Generate cache key for proof
+    ///
+    /// Hashes the canonical JSON encoding of the request rather than
+    /// `serde_json::to_string` directly: plain `to_string` output is only
+    /// as stable as serde's (unspecified) object key order, so two
+    /// otherwise-identical requests could miss the cache — or worse,
+    /// silently diverge — across a serde upgrade.
     fn generate_cache_key(&self, request: &ProofGenerationRequest) -> String {
-        use sha2::{Sha256, Digest};
-        
-        let mut hasher = Sha256::new();
-        hasher.update(request.proof_type.as_bytes());
-        hasher.update(request.identity_id.as_bytes());
-        hasher.update(serde_json::to_string(&request.required_attributes).unwrap_or_default().as_bytes());
-        
-        if let Some(ref selective) = request.selective_disclosure {
-            hasher.update(serde_json::to_string(selective).unwrap_or_default().as_bytes());
-        }
-        
-        format!("{:x}", hasher.finalize())
+        canonical_digest_hex(request).unwrap_or_default()
     }
 
     /// Cache generated proof
@@ -532,6 +699,8 @@ impl ProofGenerator {
             complexity_level: ComplexityLevel::Advanced,
             validity_duration_hours: 24,
             supports_selective_disclosure: true,
+            fmt_version: FmtVersion::CURRENT,
+            signature_algorithm: SignatureAlgorithm::Dilithium2,
         });
 
         proof_types.insert("age_proof".to_string(), ProofTypeDefinition {
@@ -543,6 +712,8 @@ impl ProofGenerator {
             complexity_level: ComplexityLevel::Standard,
             validity_duration_hours: 168, // 1 week
             supports_selective_disclosure: true,
+            fmt_version: FmtVersion::CURRENT,
+            signature_algorithm: SignatureAlgorithm::Ed25519,
         });
 
         proof_types.insert("identity_proof".to_string(), ProofTypeDefinition {
@@ -554,6 +725,8 @@ impl ProofGenerator {
             complexity_level: ComplexityLevel::Complex,
             validity_duration_hours: 1,
             supports_selective_disclosure: false,
+            fmt_version: FmtVersion::CURRENT,
+            signature_algorithm: SignatureAlgorithm::Dilithium2,
         });
 
         proof_types.insert("qualification_proof".to_string(), ProofTypeDefinition {
@@ -565,6 +738,8 @@ impl ProofGenerator {
             complexity_level: ComplexityLevel::Standard,
             validity_duration_hours: 720, // 30 days
             supports_selective_disclosure: true,
+            fmt_version: FmtVersion::CURRENT,
+            signature_algorithm: SignatureAlgorithm::Ed25519,
         });
 
         proof_types
@@ -584,6 +759,28 @@ impl ProofGenerator {
     pub fn get_proof_types(&self) -> Vec<&ProofTypeDefinition> {
         self.proof_types.values().collect()
     }
+
+    /// Check that a generated proof's stamped format version is one this
+    /// build can verify. A real verifier (a forthcoming staged
+    /// `ProofVerifier` pipeline) should call this before attempting to
+    /// interpret `proof_data`/`verification_key`, so a version bump that
+    /// changes their encoding fails loudly instead of silently
+    /// misparsing.
+    pub fn check_fmt_compatibility(result: &ProofGenerationResult) -> Result<(), Box<dyn std::error::Error>> {
+        let stamped: FmtVersion = result.metadata.get("fmt_version")
+            .ok_or("Proof metadata is missing fmt_version")
+            .and_then(|v| serde_json::from_value(v.clone()).map_err(|_| "fmt_version metadata is malformed"))?;
+
+        if !stamped.is_compatible_with(&FmtVersion::CURRENT) {
+            return Err(format!(
+                "Proof format version {}.{}.{} is incompatible with this verifier's version {}.{}.{}",
+                stamped.major, stamped.minor, stamped.patch,
+                FmtVersion::CURRENT.major, FmtVersion::CURRENT.minor, FmtVersion::CURRENT.patch,
+            ).into());
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for ProofGenerator {
@@ -0,0 +1,322 @@
+//! Staged verification pipeline for generated proofs
+//!
+//! `proof_generation` had no counterpart that actually checked a
+//! [`ProofGenerationResult`]; a cached proof was trusted purely because
+//! it hadn't yet hit `expires_at`. [`ProofVerifier::verify`] runs three
+//! ordered stages mirroring classic block verification — cheap
+//! structural checks first, then cryptographic verification, then
+//! contextual checks against external state (revocation, privacy
+//! policy) — and reports which stage failed and why instead of a bare
+//! bool, short-circuiting on the first failure since later stages are
+//! more expensive.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use lib_crypto::advanced::{verify_bbs_proof, BbsGenerators, BbsProof};
+
+use super::proof_generation::{
+    privacy_level_to_number, BbsVerificationKey, PrivacyLevel, ProofGenerationResult,
+    ProofGenerator, ProofTypeDefinition,
+};
+
+/// Which of the three ordered checks a proof failed at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerificationStage {
+    /// Known proof type, required attributes present, not expired.
+    Structural,
+    /// Proof-of-knowledge / signature check against `verification_key`.
+    Cryptographic,
+    /// Revocation and privacy-level policy checks against external state.
+    Contextual,
+}
+
+/// Outcome of running a proof through [`ProofVerifier::verify`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VerificationOutcome {
+    Valid,
+    Failed {
+        stage: VerificationStage,
+        reason: String,
+    },
+}
+
+impl VerificationOutcome {
+    pub fn is_valid(&self) -> bool {
+        matches!(self, VerificationOutcome::Valid)
+    }
+}
+
+/// External revocation state a proof's contextual check is validated
+/// against. Lets an issuer revoke a citizenship/qualification/etc. proof
+/// before its natural `validity_expires_at` without the verifier needing
+/// to know how revocations are stored.
+pub trait RevocationProvider: std::fmt::Debug + Send + Sync {
+    fn is_revoked(&self, proof_id: &str, proof_type: &str) -> bool;
+}
+
+/// A [`RevocationProvider`] backed by an in-memory set, suitable for
+/// tests and single-node deployments without an external revocation
+/// service.
+#[derive(Debug, Default)]
+pub struct InMemoryRevocationList {
+    revoked: HashSet<String>,
+}
+
+impl InMemoryRevocationList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn revoke(&mut self, proof_id: &str) {
+        self.revoked.insert(proof_id.to_string());
+    }
+}
+
+impl RevocationProvider for InMemoryRevocationList {
+    fn is_revoked(&self, proof_id: &str, _proof_type: &str) -> bool {
+        self.revoked.contains(proof_id)
+    }
+}
+
+/// Staged verifier for [`ProofGenerationResult`]s produced by
+/// [`ProofGenerator`].
+pub struct ProofVerifier {
+    proof_types: HashMap<String, ProofTypeDefinition>,
+    revocation_provider: Box<dyn RevocationProvider>,
+    minimum_privacy_level: PrivacyLevel,
+}
+
+impl ProofVerifier {
+    /// Build a verifier against the same proof type definitions the
+    /// generator uses, so a proof type unknown to one is unknown to the
+    /// other.
+    pub fn new(
+        generator: &ProofGenerator,
+        revocation_provider: Box<dyn RevocationProvider>,
+        minimum_privacy_level: PrivacyLevel,
+    ) -> Self {
+        let proof_types = generator
+            .get_proof_types()
+            .into_iter()
+            .map(|def| (def.proof_type.clone(), def.clone()))
+            .collect();
+
+        Self { proof_types, revocation_provider, minimum_privacy_level }
+    }
+
+    /// Verify `result`. `required_attributes` are the attributes this
+    /// relying party needs disclosed; `challenge` is the nonce this
+    /// verifier itself handed the prover (not taken from `result`, since
+    /// a self-attested nonce would prove nothing).
+    pub fn verify(
+        &self,
+        result: &ProofGenerationResult,
+        required_attributes: &[String],
+        challenge: Option<&[u8]>,
+    ) -> VerificationOutcome {
+        let proof_type_def = match self.check_structural(result, required_attributes) {
+            Ok(def) => def,
+            Err(reason) => {
+                return VerificationOutcome::Failed { stage: VerificationStage::Structural, reason }
+            }
+        };
+
+        if let Err(reason) = self.check_cryptographic(result, proof_type_def, challenge) {
+            return VerificationOutcome::Failed { stage: VerificationStage::Cryptographic, reason };
+        }
+
+        if let Err(reason) = self.check_contextual(result) {
+            return VerificationOutcome::Failed { stage: VerificationStage::Contextual, reason };
+        }
+
+        VerificationOutcome::Valid
+    }
+
+    /// Stage 1: cheap checks that don't touch cryptography or external state.
+    fn check_structural<'a>(
+        &'a self,
+        result: &ProofGenerationResult,
+        required_attributes: &[String],
+    ) -> Result<&'a ProofTypeDefinition, String> {
+        let proof_type_def = self
+            .proof_types
+            .get(&result.proof_type)
+            .ok_or_else(|| format!("Unknown proof type: {}", result.proof_type))?;
+
+        for attr in required_attributes {
+            if !result.attributes_included.contains(attr) {
+                return Err(format!("Required attribute '{}' was not disclosed in this proof", attr));
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("System clock error: {}", e))?
+            .as_secs();
+        if result.validity_expires_at <= now {
+            return Err(format!(
+                "Proof expired at {} (now {})",
+                result.validity_expires_at, now
+            ));
+        }
+
+        Ok(proof_type_def)
+    }
+
+    /// Stage 2: verify the proof bytes against the verification key.
+    /// Mirrors `perform_proof_generation`'s own branching: selective-
+    /// disclosure proof types are BBS+-backed, everything else carries a
+    /// signed [`super::signature_backend::SignedVerificationKey`].
+    fn check_cryptographic(
+        &self,
+        result: &ProofGenerationResult,
+        proof_type_def: &ProofTypeDefinition,
+        challenge: Option<&[u8]>,
+    ) -> Result<(), String> {
+        if proof_type_def.supports_selective_disclosure {
+            let key: BbsVerificationKey = serde_json::from_slice(&result.verification_key)
+                .map_err(|e| format!("Malformed BBS+ verification key: {}", e))?;
+            let proof: BbsProof = serde_json::from_slice(&result.proof_data)
+                .map_err(|e| format!("Malformed BBS+ proof: {}", e))?;
+            let generators = BbsGenerators::from_bytes(&key.generators, key.attribute_count)
+                .map_err(|e| format!("Could not reconstruct BBS+ generators: {}", e))?;
+
+            let nonce = challenge.unwrap_or(&[]);
+            let valid = verify_bbs_proof(&generators, &proof, nonce)
+                .map_err(|e| format!("BBS+ verification error: {}", e))?;
+            if !valid {
+                return Err("BBS+ proof of knowledge did not verify".to_string());
+            }
+        } else {
+            let valid = ProofGenerator::verify_proof(&result.proof_data, &result.verification_key)
+                .map_err(|e| format!("Signature verification error: {}", e))?;
+            if !valid {
+                return Err("Signed verification key did not verify against proof data".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stage 3: checks against state outside the proof itself.
+    fn check_contextual(&self, result: &ProofGenerationResult) -> Result<(), String> {
+        if self.revocation_provider.is_revoked(&result.proof_id, &result.proof_type) {
+            return Err(format!("Proof {} has been revoked", result.proof_id));
+        }
+
+        if privacy_level_to_number(&result.privacy_level_achieved)
+            < privacy_level_to_number(&self.minimum_privacy_level)
+        {
+            return Err(format!(
+                "Proof's achieved privacy level does not meet this verifier's minimum"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integration::signature_backend::{sign_verification_key, SignatureAlgorithm};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn signed_result(proof_data: &[u8]) -> ProofGenerationResult {
+        let verification_key = sign_verification_key(SignatureAlgorithm::Ed25519, proof_data).unwrap();
+        let expires_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600;
+
+        ProofGenerationResult {
+            proof_id: "test-proof-1".to_string(),
+            proof_type: "identity_proof".to_string(),
+            proof_data: proof_data.to_vec(),
+            verification_key,
+            metadata: HashMap::new(),
+            privacy_level_achieved: PrivacyLevel::Confidential,
+            attributes_included: vec!["nationality".to_string()],
+            generation_time_ms: 0,
+            validity_expires_at: expires_at,
+        }
+    }
+
+    #[test]
+    fn test_verify_accepts_valid_signed_proof() {
+        let generator = ProofGenerator::new();
+        let result = signed_result(b"proof bytes");
+
+        let verifier = ProofVerifier::new(
+            &generator,
+            Box::new(InMemoryRevocationList::new()),
+            PrivacyLevel::Public,
+        );
+        let outcome = verifier.verify(&result, &["nationality".to_string()], None);
+        assert!(outcome.is_valid(), "{:?}", outcome);
+    }
+
+    #[test]
+    fn test_verify_rejects_unmet_required_attribute() {
+        let generator = ProofGenerator::new();
+        let result = signed_result(b"proof bytes");
+
+        let verifier = ProofVerifier::new(
+            &generator,
+            Box::new(InMemoryRevocationList::new()),
+            PrivacyLevel::Public,
+        );
+        let outcome = verifier.verify(&result, &["residence".to_string()], None);
+        assert_eq!(
+            outcome,
+            VerificationOutcome::Failed {
+                stage: VerificationStage::Structural,
+                reason: "Required attribute 'residence' was not disclosed in this proof".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_proof_data() {
+        let generator = ProofGenerator::new();
+        let mut result = signed_result(b"original proof bytes");
+        result.proof_data = b"tampered proof bytes".to_vec();
+
+        let verifier = ProofVerifier::new(
+            &generator,
+            Box::new(InMemoryRevocationList::new()),
+            PrivacyLevel::Public,
+        );
+        let outcome = verifier.verify(&result, &[], None);
+        assert_eq!(
+            outcome,
+            VerificationOutcome::Failed {
+                stage: VerificationStage::Cryptographic,
+                reason: "Signed verification key did not verify against proof data".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_revoked_proof() {
+        let generator = ProofGenerator::new();
+        let result = signed_result(b"proof bytes");
+
+        let mut revoked = InMemoryRevocationList::new();
+        revoked.revoke(&result.proof_id);
+
+        let verifier = ProofVerifier::new(&generator, Box::new(revoked), PrivacyLevel::Public);
+        let outcome = verifier.verify(&result, &[], None);
+        assert_eq!(
+            outcome,
+            VerificationOutcome::Failed {
+                stage: VerificationStage::Contextual,
+                reason: format!("Proof {} has been revoked", result.proof_id),
+            }
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Pluggable signature-algorithm backends for proof verification keys
+//!
+//! `generate_verification_key` used to hash the proof bytes with a fixed
+//! salt and call the digest a "verification key" — there was no keypair
+//! behind it, so nothing could actually be verified against it. Each
+//! [`crate::integration::proof_generation::ProofTypeDefinition`] now
+//! names a [`SignatureAlgorithm`], and proof generation signs the
+//! canonical proof bytes under a backend for that algorithm so the
+//! resulting [`SignedVerificationKey`] carries a real public key and
+//! signature that [`verify_signed_proof`] can check.
+//!
+//! That check is a **self-consistency check, not an authenticity
+//! guarantee**: [`sign_verification_key`] generates its keypair fresh on
+//! every call and bundles the public half into the output, so
+//! `verify_signed_proof` only proves the signature matches the key
+//! shipped alongside it, not that any particular trusted issuer produced
+//! it. Anyone can generate their own keypair and "sign" arbitrary proof
+//! data to produce a `verification_key` that passes `verify_signed_proof`
+//! — this module has no long-lived issuer key for a caller to chain to.
+//! Don't use `verify_signed_proof` to decide whether a proof's *issuer*
+//! is trusted; it only tells you the `verification_key` wasn't swapped or
+//! tampered with in transit alongside its proof.
+
+use serde::{Deserialize, Serialize};
+use lib_crypto::classical::ed25519::{ed25519_keypair, ed25519_sign, ed25519_verify};
+use lib_crypto::post_quantum::dilithium::{
+    dilithium2_keypair, dilithium2_sign, dilithium2_verify,
+    dilithium5_keypair, dilithium5_sign, dilithium5_verify,
+};
+
+/// Signature scheme backing a proof type's verification key. Variants
+/// are the concrete backends `lib_crypto` ships today; add a variant
+/// (and a matching arm below) when a new scheme — a NIST curve, RSA-PSS,
+/// etc. — is vendored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SignatureAlgorithm {
+    /// Classical Ed25519, kept for compatibility with peers that don't
+    /// speak post-quantum signatures yet.
+    Ed25519,
+    /// CRYSTALS-Dilithium Level 2 (post-quantum).
+    Dilithium2,
+    /// CRYSTALS-Dilithium Level 5 (post-quantum, highest security).
+    Dilithium5,
+}
+
+/// A freshly generated keypair that can sign for and verify its own
+/// `public_key_bytes()`.
+pub trait SignatureBackend {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Box<dyn std::error::Error>>;
+    fn public_key_bytes(&self) -> &[u8];
+}
+
+struct Ed25519Backend {
+    public_key: Vec<u8>,
+    secret_key: Vec<u8>,
+}
+
+impl SignatureBackend for Ed25519Backend {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(ed25519_sign(message, &self.secret_key)?)
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(ed25519_verify(message, signature, &self.public_key)?)
+    }
+
+    fn public_key_bytes(&self) -> &[u8] {
+        &self.public_key
+    }
+}
+
+struct Dilithium2Backend {
+    public_key: Vec<u8>,
+    secret_key: Vec<u8>,
+}
+
+impl SignatureBackend for Dilithium2Backend {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(dilithium2_sign(message, &self.secret_key)?)
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(dilithium2_verify(message, signature, &self.public_key)?)
+    }
+
+    fn public_key_bytes(&self) -> &[u8] {
+        &self.public_key
+    }
+}
+
+struct Dilithium5Backend {
+    public_key: Vec<u8>,
+    secret_key: Vec<u8>,
+}
+
+impl SignatureBackend for Dilithium5Backend {
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        Ok(dilithium5_sign(message, &self.secret_key)?)
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(dilithium5_verify(message, signature, &self.public_key)?)
+    }
+
+    fn public_key_bytes(&self) -> &[u8] {
+        &self.public_key
+    }
+}
+
+impl SignatureAlgorithm {
+    /// Generate a fresh keypair backed by this algorithm.
+    pub fn generate_backend(&self) -> Box<dyn SignatureBackend> {
+        match self {
+            SignatureAlgorithm::Ed25519 => {
+                let (public_key, secret_key) = ed25519_keypair();
+                Box::new(Ed25519Backend { public_key, secret_key })
+            }
+            SignatureAlgorithm::Dilithium2 => {
+                let (public_key, secret_key) = dilithium2_keypair();
+                Box::new(Dilithium2Backend { public_key, secret_key })
+            }
+            SignatureAlgorithm::Dilithium5 => {
+                let (public_key, secret_key) = dilithium5_keypair();
+                Box::new(Dilithium5Backend { public_key, secret_key })
+            }
+        }
+    }
+
+    /// Verify a signature against raw public key bytes. Used on the
+    /// consumer side, where only the serialized [`SignedVerificationKey`]
+    /// (not a live `SignatureBackend`) is available.
+    pub fn verify_detached(
+        &self,
+        public_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        match self {
+            SignatureAlgorithm::Ed25519 => Ok(ed25519_verify(message, signature, public_key)?),
+            SignatureAlgorithm::Dilithium2 => Ok(dilithium2_verify(message, signature, public_key)?),
+            SignatureAlgorithm::Dilithium5 => Ok(dilithium5_verify(message, signature, public_key)?),
+        }
+    }
+}
+
+/// Verification key for the generic signature-backed proof paths
+/// (citizenship/identity/qualification/residence/ownership proofs): a
+/// real public key plus a signature over the proof bytes under the
+/// proof type's configured [`SignatureAlgorithm`].
+///
+/// The keypair behind `public_key` is generated fresh per call (see
+/// [`sign_verification_key`]) and isn't chained to any issuer identity,
+/// so this bundle only supports a self-consistency check, not proof of
+/// who issued it — see the module docs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedVerificationKey {
+    pub algorithm: SignatureAlgorithm,
+    pub public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// Sign `proof_data` under a fresh, throwaway keypair for `algorithm`,
+/// returning the serialized [`SignedVerificationKey`] bytes to store as
+/// `ProofGenerationResult::verification_key`.
+///
+/// The keypair is generated here and discarded after signing — it is not
+/// derived from, or chained to, any long-lived issuer key. Do not treat a
+/// successful [`verify_signed_proof`] as proof of who produced
+/// `proof_data`; it only proves `public_key` and `signature` are
+/// self-consistent with each other.
+pub fn sign_verification_key(
+    algorithm: SignatureAlgorithm,
+    proof_data: &[u8],
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let backend = algorithm.generate_backend();
+    let signature = backend.sign(proof_data)?;
+
+    let verification_key = SignedVerificationKey {
+        algorithm,
+        public_key: backend.public_key_bytes().to_vec(),
+        signature,
+    };
+
+    Ok(serde_json::to_vec(&verification_key)?)
+}
+
+/// Verify a `verification_key` produced by [`sign_verification_key`]
+/// against the `proof_data` it was generated for. Dispatches on the
+/// algorithm identifier stored inside the key itself, so callers don't
+/// need to know in advance which backend produced it.
+///
+/// `Ok(true)` means `proof_data` has not been altered since
+/// `verification_key` was produced for it — nothing more. Because the
+/// keypair is generated fresh per call and never chained to a trusted
+/// issuer key, this is **not** evidence that any particular party issued
+/// `proof_data`; anyone can mint a keypair and pass their own data
+/// through [`sign_verification_key`] to get a `verification_key` that
+/// verifies here.
+pub fn verify_signed_proof(
+    proof_data: &[u8],
+    verification_key: &[u8],
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let key: SignedVerificationKey = serde_json::from_slice(verification_key)?;
+    key.algorithm.verify_detached(&key.public_key, proof_data, &key.signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ed25519_round_trip() {
+        let data = b"proof bytes";
+        let key = sign_verification_key(SignatureAlgorithm::Ed25519, data).unwrap();
+        assert!(verify_signed_proof(data, &key).unwrap());
+    }
+
+    #[test]
+    fn test_dilithium2_round_trip() {
+        let data = b"proof bytes";
+        let key = sign_verification_key(SignatureAlgorithm::Dilithium2, data).unwrap();
+        assert!(verify_signed_proof(data, &key).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_proof_fails_verification() {
+        let key = sign_verification_key(SignatureAlgorithm::Ed25519, b"original").unwrap();
+        assert!(!verify_signed_proof(b"tampered", &key).unwrap());
+    }
+}
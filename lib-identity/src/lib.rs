@@ -24,6 +24,7 @@ pub mod did;
 pub mod reputation;
 pub mod recovery;
 pub mod guardian;
+pub mod audit;
 pub mod privacy;
 pub mod cryptography;
 pub mod auth;
@@ -110,13 +111,24 @@ pub use recovery::{
     SocialRecoveryManager,    // ✓ Social recovery orchestration
     RecoveryRequest,          // ✓ Recovery request tracking
     RecoveryStatus,           // ✓ Recovery status states
+    ShamirShare,              // ✓ One guardian's share of a split master seed
 };
 
 // Guardian module - Guardian-based social recovery
 pub use guardian::{
-    Guardian,           // ✓ Guardian entity
-    GuardianConfig,     // ✓ Guardian configuration
-    GuardianStatus,     // ✓ Guardian state
+    Guardian,             // ✓ Guardian entity
+    GuardianConfig,       // ✓ Guardian configuration
+    GuardianStatus,       // ✓ Guardian state
+    GuardianType,         // ✓ ZHTP identity vs. Ethereum wallet guardian
+    GuardianInvitation,   // ✓ Pending guardian invitation
+    EmergencyGrant,       // ✓ Standing emergency-access pre-authorization
+    SiweMessage,          // ✓ Parsed SIWE (EIP-4361) approval message
+};
+
+// Audit module - Append-only guardian/recovery action log
+pub use audit::{
+    AuditEvent,      // ✓ Single audit log entry
+    AuditEventKind,  // ✓ Kind of action recorded
 };
 
 // Wallets module - Wallet management (verified export)
@@ -154,6 +166,7 @@ pub use auth::{
     PasswordValidation,  // ✓ Password validation results
     PasswordStrength,    // ✓ Password strength levels (Weak, Medium, Strong)
     SessionToken,        // ✓ Session tokens
+    RefreshToken,        // ✓ Rotating refresh tokens paired with a session token
 };
 
 // ----------------------------------------------------------------------------
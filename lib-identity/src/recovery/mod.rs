@@ -4,9 +4,11 @@ pub mod recovery_keys;
 pub mod recovery_phrases;
 pub mod biometric_recovery;
 pub mod social_recovery;
+pub mod shamir;
 
 // Re-exports
 pub use recovery_keys::*;
 pub use recovery_phrases::*;
 pub use biometric_recovery::*;
 pub use social_recovery::*;
+pub use shamir::{ShamirShare, split_secret, reconstruct_secret};
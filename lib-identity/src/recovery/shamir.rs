@@ -0,0 +1,210 @@
+//! Shamir Secret Sharing over GF(2^8)
+//!
+//! Splits a 32-byte secret into `n` shares with threshold `t` so that any
+//! `t` shares reconstruct the secret but `t-1` reveal nothing about it.
+//! Each byte of the secret is shared independently: a random polynomial of
+//! degree `t-1` is built per byte with that byte as the constant term, and
+//! share `i` is `(x_i, P(x_i))` for a distinct non-zero `x_i`. Arithmetic is
+//! done over GF(2^8) using the AES reducing polynomial (0x11b).
+
+use serde::{Deserialize, Serialize};
+
+/// Multiply two GF(2^8) elements, reducing by the AES polynomial x^8 + x^4 + x^3 + x + 1 (0x11b)
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product: u8 = 0;
+    for _ in 0..8 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80;
+        a <<= 1;
+        if carry != 0 {
+            a ^= 0x1b; // 0x11b mod 0x100
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Raise a GF(2^8) element to a power via repeated squaring
+fn gf256_pow(a: u8, mut exp: u8) -> u8 {
+    let mut result: u8 = 1;
+    let mut base = a;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = gf256_mul(result, base);
+        }
+        base = gf256_mul(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Multiplicative inverse in GF(2^8): a^-1 = a^254 for nonzero `a` (the
+/// multiplicative group has order 255)
+fn gf256_inv(a: u8) -> u8 {
+    gf256_pow(a, 254)
+}
+
+/// Evaluate a polynomial (lowest-degree coefficient first) at `x` over GF(2^8) via Horner's method
+fn eval_poly(coeffs: &[u8], x: u8) -> u8 {
+    let mut result = 0u8;
+    for &c in coeffs.iter().rev() {
+        result = gf256_mul(result, x) ^ c;
+    }
+    result
+}
+
+/// A single guardian's share of a secret split with [`split_secret`].
+///
+/// `x` is the share's non-zero evaluation point - x=0 is reserved for the
+/// secret itself, so `x` must never be 0 and must be distinct across
+/// shares. `y` holds `P(x)` for every byte of the secret.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShamirShare {
+    pub x: u8,
+    pub y: Vec<u8>,
+}
+
+/// Split a 32-byte secret into `n` shares with reconstruction threshold `t`
+///
+/// Returns one share per `x` in `1..=n` (x=0 is reserved for the secret).
+pub fn split_secret(secret: &[u8; 32], n: u8, threshold: u8) -> Result<Vec<ShamirShare>, String> {
+    if threshold == 0 {
+        return Err("threshold must be at least 1".to_string());
+    }
+    if n < threshold {
+        return Err(format!(
+            "n ({}) must be >= threshold ({})",
+            n, threshold
+        ));
+    }
+
+    use rand::RngCore;
+    let mut rng = rand::rngs::OsRng;
+
+    // One random degree-(threshold-1) polynomial per secret byte, with the
+    // secret byte as the constant term.
+    let mut polys: Vec<Vec<u8>> = Vec::with_capacity(32);
+    for &secret_byte in secret.iter() {
+        let mut coeffs = vec![0u8; threshold as usize];
+        coeffs[0] = secret_byte;
+        if threshold > 1 {
+            rng.fill_bytes(&mut coeffs[1..]);
+        }
+        polys.push(coeffs);
+    }
+
+    let shares = (1..=n)
+        .map(|x| {
+            let y = polys.iter().map(|coeffs| eval_poly(coeffs, x)).collect();
+            ShamirShare { x, y }
+        })
+        .collect();
+
+    Ok(shares)
+}
+
+/// Reconstruct the 32-byte secret from at least `threshold` shares via
+/// Lagrange interpolation at x=0
+///
+/// Only the first `threshold` shares are used; extras are ignored. Rejects
+/// a share with `x == 0` or any duplicate `x`, since both break the
+/// interpolation.
+pub fn reconstruct_secret(shares: &[ShamirShare], threshold: u8) -> Result<[u8; 32], String> {
+    if shares.len() < threshold as usize {
+        return Err(format!(
+            "need at least {} shares to reconstruct, got {}",
+            threshold,
+            shares.len()
+        ));
+    }
+    let shares = &shares[..threshold as usize];
+
+    let mut seen_x = std::collections::HashSet::new();
+    for share in shares {
+        if share.x == 0 {
+            return Err("share x-coordinate 0 is reserved for the secret".to_string());
+        }
+        if share.y.len() != 32 {
+            return Err("shares must each encode 32 secret bytes".to_string());
+        }
+        if !seen_x.insert(share.x) {
+            return Err("duplicate share x-coordinate".to_string());
+        }
+    }
+
+    let mut secret = [0u8; 32];
+    for byte_idx in 0..32 {
+        // P(0) = sum_i y_i * prod_{j != i} x_j / (x_j - x_i), all over GF(2^8)
+        let mut acc = 0u8;
+        for (i, share_i) in shares.iter().enumerate() {
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for (j, share_j) in shares.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                numerator = gf256_mul(numerator, share_j.x);
+                // Subtraction is XOR in GF(2^8)
+                denominator = gf256_mul(denominator, share_i.x ^ share_j.x);
+            }
+            let lagrange_coeff = gf256_mul(numerator, gf256_inv(denominator));
+            acc ^= gf256_mul(share_i.y[byte_idx], lagrange_coeff);
+        }
+        secret[byte_idx] = acc;
+    }
+
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_and_reconstruct_exact_threshold() {
+        let secret = [42u8; 32];
+        let shares = split_secret(&secret, 5, 3).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        let reconstructed = reconstruct_secret(&shares[1..4], 3).unwrap();
+        assert_eq!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_reconstruct_with_different_share_subsets() {
+        let mut secret = [0u8; 32];
+        for (i, b) in secret.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let shares = split_secret(&secret, 6, 4).unwrap();
+
+        let subset_a = vec![shares[0].clone(), shares[1].clone(), shares[2].clone(), shares[3].clone()];
+        let subset_b = vec![shares[2].clone(), shares[3].clone(), shares[4].clone(), shares[5].clone()];
+
+        assert_eq!(reconstruct_secret(&subset_a, 4).unwrap(), secret);
+        assert_eq!(reconstruct_secret(&subset_b, 4).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_insufficient_shares_rejected() {
+        let secret = [7u8; 32];
+        let shares = split_secret(&secret, 5, 3).unwrap();
+        assert!(reconstruct_secret(&shares[..2], 3).is_err());
+    }
+
+    #[test]
+    fn test_n_less_than_threshold_rejected() {
+        let secret = [1u8; 32];
+        assert!(split_secret(&secret, 2, 3).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_x_rejected() {
+        let secret = [9u8; 32];
+        let shares = split_secret(&secret, 5, 3).unwrap();
+        let duplicated = vec![shares[0].clone(), shares[0].clone(), shares[1].clone()];
+        assert!(reconstruct_secret(&duplicated, 3).is_err());
+    }
+}
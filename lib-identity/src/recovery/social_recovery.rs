@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc, Duration};
 use lib_crypto::{PostQuantumSignature, verify_signature};
-use crate::guardian::{Guardian, GuardianConfig};
+use crate::guardian::{Guardian, GuardianConfig, GuardianType, SiweMessage};
 
 /// Recovery request status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -18,6 +18,13 @@ pub enum RecoveryStatus {
     /// Threshold met, ready to complete
     Approved,
 
+    /// Time-delayed emergency access initiated by a pre-authorized
+    /// guardian, counting down to `takeover_available_at`. The owner can
+    /// cancel it during the window; once the window elapses a background
+    /// sweep matures it to `Approved` so it can be completed without
+    /// meeting the guardian threshold.
+    EmergencyPending,
+
     /// Recovery was rejected by guardians
     Rejected,
 
@@ -31,6 +38,22 @@ pub enum RecoveryStatus {
     Cancelled,
 }
 
+/// How a guardian's approval was authorized
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ApprovalSignature {
+    /// ZHTP post-quantum signature over `recovery_id`
+    PostQuantum(PostQuantumSignature),
+
+    /// SIWE (EIP-4361) message signed by an Ethereum wallet guardian,
+    /// verified via secp256k1 address recovery
+    Siwe {
+        /// The raw message text the guardian signed
+        message: String,
+        /// The 65-byte `{r, s, v}` secp256k1 signature
+        signature: Vec<u8>,
+    },
+}
+
 /// A guardian's approval for a recovery request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GuardianApproval {
@@ -38,12 +61,34 @@ pub struct GuardianApproval {
     pub guardian_did: String,
 
     /// Guardian's signature over recovery_id
-    pub signature: PostQuantumSignature,
+    pub signature: ApprovalSignature,
 
     /// When the approval was given
     pub approved_at: DateTime<Utc>,
 }
 
+/// A single-use challenge nonce issued to one guardian for one recovery
+/// request, binding a signed approval/rejection to this specific
+/// `(recovery_id, guardian_did)` pair instead of just the recovery_id -
+/// a signature over a consumed nonce can't be replayed, even by the same
+/// guardian against the same request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianNonce {
+    /// The random nonce value the guardian must embed in its signed
+    /// approval/rejection tuple
+    pub nonce: String,
+
+    /// When this nonce was issued
+    pub issued_at: DateTime<Utc>,
+
+    /// When this nonce stops being acceptable, even if never consumed
+    pub expires_at: DateTime<Utc>,
+
+    /// Set once the nonce has been used in a successfully verified
+    /// approval/rejection, so it can never be used again
+    pub consumed: bool,
+}
+
 /// A social recovery request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecoveryRequest {
@@ -73,6 +118,28 @@ pub struct RecoveryRequest {
 
     /// IP address that initiated the recovery (for rate limiting)
     pub requester_ip: String,
+
+    /// Decrypted Shamir shares submitted by approving guardians, keyed by
+    /// guardian DID. Populated via [`RecoveryRequest::submit_key_share`]
+    /// once a guardian has approved; used by the reconstruct endpoint to
+    /// rebuild the identity's master seed once `threshold` shares arrive.
+    pub key_shares: HashMap<String, Vec<u8>>,
+
+    /// DID of the guardian who initiated time-delayed emergency access, if
+    /// this request is an emergency request rather than a threshold one
+    pub emergency_guardian_did: Option<String>,
+
+    /// When an emergency-access request matures and can be completed
+    /// without meeting `threshold`, set at initiation time from the
+    /// guardian's [`crate::guardian::EmergencyGrant::waiting_period_hours`]
+    pub takeover_available_at: Option<DateTime<Utc>>,
+
+    /// Single-use challenge nonces issued to guardians approving or
+    /// rejecting this request, keyed by `guardian_did`. Every signed
+    /// approval/rejection (ZHTP post-quantum or SIWE alike) must embed the
+    /// nonce issued to that guardian, which is consumed on first valid use
+    /// - see [`RecoveryRequest::issue_guardian_nonce`].
+    pub guardian_nonces: HashMap<String, GuardianNonce>,
 }
 
 impl RecoveryRequest {
@@ -103,18 +170,115 @@ impl RecoveryRequest {
             expires_at,
             requester_device,
             requester_ip,
+            key_shares: HashMap::new(),
+            emergency_guardian_did: None,
+            takeover_available_at: None,
+            guardian_nonces: HashMap::new(),
         }
     }
 
+    /// Create a time-delayed emergency access request for a guardian
+    /// holding a standing [`crate::guardian::EmergencyGrant`]
+    pub fn new_emergency(
+        identity_did: String,
+        threshold: usize,
+        guardian_did: String,
+        waiting_period_hours: i64,
+        requester_device: String,
+        requester_ip: String,
+        expiration_hours: i64,
+    ) -> Self {
+        let mut request = Self::new(
+            identity_did,
+            threshold,
+            requester_device,
+            requester_ip,
+            expiration_hours,
+        );
+        request.status = RecoveryStatus::EmergencyPending;
+        request.emergency_guardian_did = Some(guardian_did);
+        request.takeover_available_at = Some(Utc::now() + Duration::hours(waiting_period_hours));
+        request
+    }
+
     /// Check if the recovery request has expired
     pub fn is_expired(&self) -> bool {
         Utc::now() > self.expires_at
     }
 
-    /// Add a guardian approval with signature verification
+    /// Issue (or re-issue, if the previous one expired or was already
+    /// consumed) a single-use challenge nonce for `guardian_did` to embed in
+    /// a signed approval/rejection, binding it to the tuple
+    /// `(recovery_id, guardian_did, nonce, action, timestamp)`
+    pub fn issue_guardian_nonce(&mut self, guardian_did: &str) -> String {
+        if let Some(existing) = self.guardian_nonces.get(guardian_did) {
+            if !existing.consumed && Utc::now() < existing.expires_at {
+                return existing.nonce.clone();
+            }
+        }
+
+        use rand::RngCore;
+        let mut nonce_bytes = [0u8; 16];
+        rand::rngs::OsRng.fill_bytes(&mut nonce_bytes);
+        let nonce = hex::encode(nonce_bytes);
+
+        let now = Utc::now();
+        self.guardian_nonces.insert(
+            guardian_did.to_string(),
+            GuardianNonce {
+                nonce: nonce.clone(),
+                issued_at: now,
+                expires_at: now + Duration::minutes(10),
+                consumed: false,
+            },
+        );
+
+        nonce
+    }
+
+    /// Consume `guardian_did`'s challenge nonce if `nonce` matches the one
+    /// currently issued to them, hasn't expired, and hasn't already been
+    /// used. Call only after independently verifying the signature over the
+    /// nonce-bound tuple - this enforces single-use, not authenticity.
+    fn consume_guardian_nonce(&mut self, guardian_did: &str, nonce: &str) -> Result<(), String> {
+        let entry = self
+            .guardian_nonces
+            .get_mut(guardian_did)
+            .ok_or_else(|| "No challenge nonce has been issued to this guardian".to_string())?;
+
+        if entry.consumed {
+            return Err("Nonce has already been used".to_string());
+        }
+        if Utc::now() > entry.expires_at {
+            return Err("Nonce has expired".to_string());
+        }
+        if entry.nonce != nonce {
+            return Err("Nonce does not match the issued challenge".to_string());
+        }
+
+        entry.consumed = true;
+        Ok(())
+    }
+
+    /// Security: Reject timestamps more than `window_seconds` away from now,
+    /// so a signature can't be held and replayed far in the future
+    fn check_timestamp_freshness(timestamp: i64, window_seconds: i64) -> Result<(), String> {
+        if (Utc::now().timestamp() - timestamp).abs() > window_seconds {
+            return Err("Timestamp is outside the freshness window".to_string());
+        }
+        Ok(())
+    }
+
+    /// Add a guardian approval, verifying a post-quantum signature over the
+    /// replay-resistant tuple `(recovery_id, guardian_did, nonce, "approve",
+    /// timestamp)`. `nonce` must be the one most recently returned by
+    /// [`Self::issue_guardian_nonce`] for this guardian - it is consumed on
+    /// success and can't be reused.
     pub fn add_approval(
         &mut self,
         guardian: &Guardian,
+        nonce: &str,
+        timestamp: i64,
         signature: PostQuantumSignature,
     ) -> Result<(), String> {
         // Security: Check expiration
@@ -133,21 +297,29 @@ impl RecoveryRequest {
             return Err("Guardian has already approved this recovery".to_string());
         }
 
-        // Security: Verify signature over recovery_id
-        let message = self.recovery_id.as_bytes();
+        Self::check_timestamp_freshness(timestamp, 300)?;
+
+        // Security: Verify signature over the nonce-bound replay-resistant tuple
+        let message = format!(
+            "{}:{}:{}:approve:{}",
+            self.recovery_id, guardian.guardian_did, nonce, timestamp
+        );
         let public_key_bytes = guardian.public_key.as_bytes();
 
-        let is_valid = verify_signature(message, &signature.signature, &public_key_bytes)
+        let is_valid = verify_signature(message.as_bytes(), &signature.signature, &public_key_bytes)
             .map_err(|e| format!("Signature verification failed: {}", e))?;
 
         if !is_valid {
             return Err("Invalid guardian signature".to_string());
         }
 
+        // Security: Consume the nonce so this signature can't be replayed
+        self.consume_guardian_nonce(&guardian.guardian_did, nonce)?;
+
         // Add approval
         let approval = GuardianApproval {
             guardian_did: guardian.guardian_did.clone(),
-            signature,
+            signature: ApprovalSignature::PostQuantum(signature),
             approved_at: Utc::now(),
         };
 
@@ -161,8 +333,15 @@ impl RecoveryRequest {
         Ok(())
     }
 
-    /// Reject approval from a guardian
-    pub fn reject_approval(&mut self, _guardian_did: &str) -> Result<(), String> {
+    /// Add a guardian approval authorized by a signed SIWE (EIP-4361)
+    /// message from an Ethereum wallet guardian, verified by secp256k1
+    /// address recovery instead of a ZHTP post-quantum signature
+    pub fn add_wallet_approval(
+        &mut self,
+        guardian: &Guardian,
+        siwe_message_text: &str,
+        signature: &[u8],
+    ) -> Result<(), String> {
         // Security: Check expiration
         if self.is_expired() {
             self.status = RecoveryStatus::Expired;
@@ -174,12 +353,219 @@ impl RecoveryRequest {
             return Err(format!("Recovery request is not pending (status: {:?})", self.status));
         }
 
+        // Security: Check for duplicate approval
+        if self.approvals.contains_key(&guardian.guardian_did) {
+            return Err("Guardian has already approved this recovery".to_string());
+        }
+
+        if guardian.guardian_type != GuardianType::EthereumWallet {
+            return Err("Guardian is not an Ethereum wallet guardian".to_string());
+        }
+
+        let siwe = SiweMessage::parse(siwe_message_text)?;
+
+        // Security: Bind the signed message to this specific recovery
+        // request and action, so a signature captured for one request or
+        // purpose can't be replayed against another
+        if !siwe.binds_recovery(&self.recovery_id) {
+            return Err("SIWE message does not reference this recovery request".to_string());
+        }
+        if !siwe.binds_action("approve") {
+            return Err("SIWE message does not authorize an approval".to_string());
+        }
+        if !siwe.is_fresh(300) {
+            return Err("SIWE message issued-at timestamp is outside the freshness window".to_string());
+        }
+
+        let message_hash = lib_crypto::classical::secp256k1::eip191_hash(siwe_message_text.as_bytes());
+        let is_valid = lib_crypto::classical::secp256k1::verify_eth_signature(
+            &message_hash,
+            signature,
+            &guardian.guardian_did,
+        )
+        .map_err(|e| format!("Signature verification failed: {}", e))?;
+
+        if !is_valid {
+            return Err("Invalid guardian wallet signature".to_string());
+        }
+
+        // Security: Consume the nonce embedded in the SIWE message so it
+        // can't be replayed
+        self.consume_guardian_nonce(&guardian.guardian_did, &siwe.nonce)?;
+
+        let approval = GuardianApproval {
+            guardian_did: guardian.guardian_did.clone(),
+            signature: ApprovalSignature::Siwe {
+                message: siwe_message_text.to_string(),
+                signature: signature.to_vec(),
+            },
+            approved_at: Utc::now(),
+        };
+
+        self.approvals.insert(guardian.guardian_did.clone(), approval);
+
+        if self.approvals.len() >= self.threshold {
+            self.status = RecoveryStatus::Approved;
+        }
+
+        Ok(())
+    }
+
+    /// Record a guardian's decrypted Shamir share for master-seed reconstruction
+    ///
+    /// The guardian must have already approved this recovery. The caller is
+    /// responsible for having decrypted the share client-side with the
+    /// guardian's own private key - this method only enforces that the
+    /// submitting guardian actually approved.
+    pub fn submit_key_share(&mut self, guardian_did: &str, share: Vec<u8>) -> Result<(), String> {
+        if self.is_expired() {
+            self.status = RecoveryStatus::Expired;
+            return Err("Recovery request has expired".to_string());
+        }
+
+        if !self.approvals.contains_key(guardian_did) {
+            return Err("Guardian has not approved this recovery".to_string());
+        }
+
+        self.key_shares.insert(guardian_did.to_string(), share);
+        Ok(())
+    }
+
+    /// Owner-initiated cancellation of a pending emergency access window
+    pub fn reject_emergency_access(&mut self) -> Result<(), String> {
+        if self.status != RecoveryStatus::EmergencyPending {
+            return Err(format!(
+                "Recovery request is not a pending emergency access (status: {:?})",
+                self.status
+            ));
+        }
+
+        self.status = RecoveryStatus::Cancelled;
+        Ok(())
+    }
+
+    /// If this is an `EmergencyPending` request whose waiting period has
+    /// elapsed, transition it to `Approved` so it can be completed without
+    /// meeting the guardian threshold. Returns whether it matured.
+    pub fn mature_emergency_access(&mut self) -> bool {
+        if self.status != RecoveryStatus::EmergencyPending {
+            return false;
+        }
+
+        let Some(takeover_available_at) = self.takeover_available_at else {
+            return false;
+        };
+
+        if Utc::now() < takeover_available_at {
+            return false;
+        }
+
+        if self.is_expired() {
+            self.status = RecoveryStatus::Expired;
+            return false;
+        }
+
+        self.status = RecoveryStatus::Approved;
+        true
+    }
+
+    /// Reject a recovery request, verifying a post-quantum signature over
+    /// the replay-resistant tuple `(recovery_id, guardian_did, nonce,
+    /// "reject", timestamp)` exactly like [`Self::add_approval`] does for
+    /// approvals - without this, any caller who merely knew a guardian's DID
+    /// could reject a recovery on their behalf.
+    pub fn reject_approval(
+        &mut self,
+        guardian: &Guardian,
+        nonce: &str,
+        timestamp: i64,
+        signature: &PostQuantumSignature,
+    ) -> Result<(), String> {
+        // Security: Check expiration
+        if self.is_expired() {
+            self.status = RecoveryStatus::Expired;
+            return Err("Recovery request has expired".to_string());
+        }
+
+        // Security: Check status
+        if self.status != RecoveryStatus::Pending {
+            return Err(format!("Recovery request is not pending (status: {:?})", self.status));
+        }
+
+        Self::check_timestamp_freshness(timestamp, 300)?;
+
+        let message = format!(
+            "{}:{}:{}:reject:{}",
+            self.recovery_id, guardian.guardian_did, nonce, timestamp
+        );
+        let is_valid = verify_signature(message.as_bytes(), &signature.signature, &guardian.public_key.as_bytes())
+            .map_err(|e| format!("Signature verification failed: {}", e))?;
+
+        if !is_valid {
+            return Err("Invalid guardian signature".to_string());
+        }
+
+        self.consume_guardian_nonce(&guardian.guardian_did, nonce)?;
+
         // Mark as rejected
         self.status = RecoveryStatus::Rejected;
 
         Ok(())
     }
 
+    /// Reject a recovery request on behalf of an Ethereum wallet guardian,
+    /// verified by secp256k1 address recovery over a signed SIWE message -
+    /// the wallet-guardian counterpart to [`Self::reject_approval`]
+    pub fn reject_wallet_approval(
+        &mut self,
+        guardian: &Guardian,
+        siwe_message_text: &str,
+        signature: &[u8],
+    ) -> Result<(), String> {
+        if self.is_expired() {
+            self.status = RecoveryStatus::Expired;
+            return Err("Recovery request has expired".to_string());
+        }
+
+        if self.status != RecoveryStatus::Pending {
+            return Err(format!("Recovery request is not pending (status: {:?})", self.status));
+        }
+
+        if guardian.guardian_type != GuardianType::EthereumWallet {
+            return Err("Guardian is not an Ethereum wallet guardian".to_string());
+        }
+
+        let siwe = SiweMessage::parse(siwe_message_text)?;
+
+        if !siwe.binds_recovery(&self.recovery_id) {
+            return Err("SIWE message does not reference this recovery request".to_string());
+        }
+        if !siwe.binds_action("reject") {
+            return Err("SIWE message does not authorize a rejection".to_string());
+        }
+        if !siwe.is_fresh(300) {
+            return Err("SIWE message issued-at timestamp is outside the freshness window".to_string());
+        }
+
+        let message_hash = lib_crypto::classical::secp256k1::eip191_hash(siwe_message_text.as_bytes());
+        let is_valid = lib_crypto::classical::secp256k1::verify_eth_signature(
+            &message_hash,
+            signature,
+            &guardian.guardian_did,
+        )
+        .map_err(|e| format!("Signature verification failed: {}", e))?;
+
+        if !is_valid {
+            return Err("Invalid guardian wallet signature".to_string());
+        }
+
+        self.consume_guardian_nonce(&guardian.guardian_did, &siwe.nonce)?;
+
+        self.status = RecoveryStatus::Rejected;
+
+        Ok(())
+    }
+
     /// Complete the recovery (only if threshold met)
     pub fn complete(&mut self) -> Result<(), String> {
         // Security: Check expiration
@@ -193,8 +579,10 @@ impl RecoveryRequest {
             return Err(format!("Recovery is not approved (status: {:?})", self.status));
         }
 
-        // Security: Double-check threshold
-        if self.approvals.len() < self.threshold {
+        // Security: Double-check threshold, unless this is a matured
+        // emergency access request - those are intentionally allowed to
+        // complete without any guardian approvals at all
+        if self.takeover_available_at.is_none() && self.approvals.len() < self.threshold {
             return Err(format!(
                 "Insufficient approvals: {} of {} required",
                 self.approvals.len(),
@@ -273,8 +661,8 @@ impl SocialRecoveryManager {
         // Check for existing pending recovery for this identity
         let existing_pending = self.requests.values().any(|r| {
             r.identity_did == identity_did
-                && r.status == RecoveryStatus::Pending
                 && !r.is_expired()
+                && (r.status == RecoveryStatus::Pending || r.status == RecoveryStatus::EmergencyPending)
         });
 
         if existing_pending {
@@ -296,6 +684,68 @@ impl SocialRecoveryManager {
         Ok(recovery_id)
     }
 
+    /// Initiate time-delayed emergency access for a guardian holding a
+    /// standing [`crate::guardian::EmergencyGrant`]
+    pub fn initiate_emergency_access(
+        &mut self,
+        identity_did: String,
+        guardian_config: &GuardianConfig,
+        guardian_did: &str,
+        waiting_period_hours: i64,
+        requester_device: String,
+        requester_ip: String,
+    ) -> Result<String, String> {
+        // Security: Check rate limit (3 attempts per 24 hours per IP)
+        self.check_rate_limit(&requester_ip, 3, 24)?;
+
+        // Check for existing pending recovery (normal or emergency) for this identity
+        let existing_pending = self.requests.values().any(|r| {
+            r.identity_did == identity_did
+                && !r.is_expired()
+                && (r.status == RecoveryStatus::Pending || r.status == RecoveryStatus::EmergencyPending)
+        });
+
+        if existing_pending {
+            return Err("A recovery request is already pending for this identity".to_string());
+        }
+
+        // 7 day expiration - long enough to cover a multi-day waiting period
+        let request = RecoveryRequest::new_emergency(
+            identity_did,
+            guardian_config.threshold,
+            guardian_did.to_string(),
+            waiting_period_hours,
+            requester_device,
+            requester_ip,
+            waiting_period_hours + 24 * 7,
+        );
+
+        let recovery_id = request.recovery_id.clone();
+        self.requests.insert(recovery_id.clone(), request);
+
+        Ok(recovery_id)
+    }
+
+    /// Owner-initiated cancellation of a pending emergency access window
+    pub fn reject_emergency_access(&mut self, recovery_id: &str) -> Result<(), String> {
+        self.requests
+            .get_mut(recovery_id)
+            .ok_or_else(|| "Recovery request not found".to_string())?
+            .reject_emergency_access()
+    }
+
+    /// Transition any `EmergencyPending` requests whose waiting period has
+    /// elapsed to `Approved`, so they can be completed without meeting the
+    /// guardian threshold. Intended to be called periodically by a
+    /// background sweep task. Returns the matured recovery IDs.
+    pub fn sweep_emergency_access(&mut self) -> Vec<String> {
+        self.requests
+            .values_mut()
+            .filter(|r| r.mature_emergency_access())
+            .map(|r| r.recovery_id.clone())
+            .collect()
+    }
+
     /// Get a recovery request
     pub fn get_request(&self, recovery_id: &str) -> Option<&RecoveryRequest> {
         self.requests.get(recovery_id)
@@ -326,10 +776,27 @@ impl SocialRecoveryManager {
             .collect()
     }
 
+    /// Get all recovery requests for a given identity (for backup/export)
+    pub fn get_requests_for_identity(&self, identity_did: &str) -> Vec<&RecoveryRequest> {
+        self.requests
+            .values()
+            .filter(|r| r.identity_did == identity_did)
+            .collect()
+    }
+
+    /// Restore a recovery request from an imported backup, overwriting any
+    /// existing request with the same `recovery_id`
+    pub fn restore_request(&mut self, request: RecoveryRequest) {
+        self.requests.insert(request.recovery_id.clone(), request);
+    }
+
     /// Clean up expired requests
     pub fn cleanup_expired(&mut self) {
         self.requests.retain(|_, request| {
-            if request.is_expired() && request.status == RecoveryStatus::Pending {
+            if request.is_expired()
+                && (request.status == RecoveryStatus::Pending
+                    || request.status == RecoveryStatus::EmergencyPending)
+            {
                 false // Remove expired pending requests
             } else {
                 true // Keep all other requests
@@ -68,6 +68,12 @@ pub struct WalletManager {
     /// Optional master seed for deterministic wallet recovery (not serialized)
     #[serde(skip)]
     pub master_seed: Option<[u8; 64]>,
+    /// Slashing offences reported against wallets managed here
+    #[serde(default)]
+    pub offence_log: Vec<super::slashing::Offence>,
+    /// Evidence hashes already processed, so the same offence can't be slashed twice
+    #[serde(default)]
+    pub processed_offences: std::collections::HashSet<Hash>,
 }
 
 impl WalletManager {
@@ -86,6 +92,8 @@ impl WalletManager {
             created_at: current_time,
             wallet_password_manager: WalletPasswordManager::new(),
             master_seed: None,
+            offence_log: Vec::new(),
+            processed_offences: std::collections::HashSet::new(),
         }
     }
 
@@ -842,6 +850,106 @@ impl WalletManager {
         })
     }
 
+    // ============================================================================
+    // DAO HIERARCHY VOTING POWER - Aggregate governance weight across the tree
+    // ============================================================================
+
+    /// Compute the total governance weight of a DAO, including every DAO it
+    /// controls transitively (child DAOs and DAOs it is an authorized
+    /// controller of).
+    ///
+    /// Each wallet's own `staked_balance` is treated as its governance
+    /// weight, matching how stake-weighted voting power is derived
+    /// elsewhere in the DAO tooling. A visited set guarantees termination
+    /// and doubles as the enforcement point for the acyclicity invariant
+    /// the for-profit/non-profit hierarchy rules are supposed to maintain:
+    /// if the same DAO is reached twice, the hierarchy contains a cycle and
+    /// we fail loudly instead of double-counting or looping forever.
+    pub fn total_power_at(&self, dao_id: &Hash) -> Result<u64> {
+        let mut visited = std::collections::HashSet::new();
+        let mut total = 0u64;
+        let mut stack = vec![dao_id.clone()];
+
+        while let Some(current_id) = stack.pop() {
+            if !visited.insert(current_id.clone()) {
+                return Err(anyhow!(
+                    "Cycle detected in DAO hierarchy at wallet {}",
+                    hex::encode(&current_id.0[..8])
+                ));
+            }
+
+            let wallet = self.wallets.get(&current_id)
+                .ok_or_else(|| anyhow!("DAO wallet not found: {}", hex::encode(&current_id.0[..8])))?;
+
+            let dao_props = wallet.get_dao_properties()
+                .ok_or_else(|| anyhow!("Wallet {} is not a DAO wallet", hex::encode(&current_id.0[..8])))?;
+
+            total = total.saturating_add(wallet.staked_balance);
+
+            for child_id in &dao_props.child_dao_wallets {
+                stack.push(child_id.clone());
+            }
+            for controller_id in &dao_props.authorized_dao_controllers {
+                stack.push(controller_id.clone());
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// Compute a single DAO controller's effective governance weight over
+    /// `dao_id`, flowing through any intermediate DAOs in the hierarchy.
+    ///
+    /// Returns the controller's own weight (its `staked_balance`) if it
+    /// controls `dao_id` either directly or transitively through the
+    /// child/controller graph, and an error otherwise. This is the
+    /// primitive governance-vote tallying uses to ask "does this DAO's
+    /// vote count here, and for how much".
+    pub fn power_of(&self, controller_id: &Hash, dao_id: &Hash) -> Result<u64> {
+        let controller_wallet = self.wallets.get(controller_id)
+            .ok_or_else(|| anyhow!("Controller DAO wallet not found: {}", hex::encode(&controller_id.0[..8])))?;
+
+        if controller_id == dao_id {
+            return Ok(controller_wallet.staked_balance);
+        }
+
+        let mut visited = std::collections::HashSet::new();
+        let mut stack = vec![dao_id.clone()];
+
+        while let Some(current_id) = stack.pop() {
+            if !visited.insert(current_id.clone()) {
+                return Err(anyhow!(
+                    "Cycle detected in DAO hierarchy at wallet {}",
+                    hex::encode(&current_id.0[..8])
+                ));
+            }
+
+            let wallet = self.wallets.get(&current_id)
+                .ok_or_else(|| anyhow!("DAO wallet not found: {}", hex::encode(&current_id.0[..8])))?;
+
+            let dao_props = wallet.get_dao_properties()
+                .ok_or_else(|| anyhow!("Wallet {} is not a DAO wallet", hex::encode(&current_id.0[..8])))?;
+
+            if dao_props.child_dao_wallets.contains(controller_id)
+                || dao_props.authorized_dao_controllers.contains(controller_id) {
+                return Ok(controller_wallet.staked_balance);
+            }
+
+            for child_id in &dao_props.child_dao_wallets {
+                stack.push(child_id.clone());
+            }
+            for controller_dao_id in &dao_props.authorized_dao_controllers {
+                stack.push(controller_dao_id.clone());
+            }
+        }
+
+        Err(anyhow!(
+            "DAO {} does not control {} in the hierarchy",
+            hex::encode(&controller_id.0[..8]),
+            hex::encode(&dao_id.0[..8])
+        ))
+    }
+
     // ============================================================================
     // WALLET PASSWORD PROTECTION - Optional security for individual wallets
     // ============================================================================
@@ -952,3 +1060,118 @@ impl WalletManager {
         self.wallet_password_manager.password_protected_count()
     }
 }
+
+#[cfg(test)]
+mod dao_power_tests {
+    use super::*;
+    use super::super::wallet_types::{DaoGovernanceSettings, DaoWalletProperties, TransparencyLevel};
+
+    fn dao_wallet(id: Hash, creator: IdentityId, staked_balance: u64) -> QuantumWallet {
+        QuantumWallet {
+            id: id.clone(),
+            wallet_type: WalletType::ForProfitDAO,
+            name: "Test DAO".to_string(),
+            alias: None,
+            balance: 0,
+            staked_balance,
+            pending_rewards: 0,
+            owner_id: Some(creator.clone()),
+            public_key: vec![0u8; 32],
+            seed_phrase: None,
+            encrypted_seed: None,
+            seed_commitment: None,
+            created_at: 0,
+            last_transaction: None,
+            recent_transactions: Vec::new(),
+            is_active: true,
+            dao_properties: Some(DaoWalletProperties {
+                creator_did: creator.clone(),
+                dao_name: "Test DAO".to_string(),
+                dao_description: "Test DAO".to_string(),
+                is_nonprofit: false,
+                public_transaction_log: Vec::new(),
+                authorized_controllers: vec![creator],
+                authorized_dao_controllers: Vec::new(),
+                parent_dao_wallet: None,
+                child_dao_wallets: Vec::new(),
+                governance_settings: DaoGovernanceSettings {
+                    min_signatures_required: 1,
+                    max_single_transaction: 1_000_000,
+                    requires_governance_vote: false,
+                    voting_threshold_percent: 60,
+                },
+                transparency_level: TransparencyLevel::Full,
+                founded_at: 0,
+                total_funds_received: 0,
+                total_funds_spent: 0,
+                transaction_count: 0,
+            }),
+            derivation_index: None,
+            password_hash: None,
+            owned_content: Vec::new(),
+            total_storage_used: 0,
+            total_content_value: 0,
+        }
+    }
+
+    #[test]
+    fn total_power_at_sums_children_and_controllers() {
+        let creator = Hash::from_bytes(&[1u8; 32]);
+        let mut manager = WalletManager::new(creator.clone());
+
+        let parent_id = Hash::from_bytes(&[10u8; 32]);
+        let child_id = Hash::from_bytes(&[11u8; 32]);
+        let controller_id = Hash::from_bytes(&[12u8; 32]);
+
+        manager.wallets.insert(parent_id.clone(), dao_wallet(parent_id.clone(), creator.clone(), 100));
+        manager.wallets.insert(child_id.clone(), dao_wallet(child_id.clone(), creator.clone(), 50));
+        manager.wallets.insert(controller_id.clone(), dao_wallet(controller_id.clone(), creator.clone(), 25));
+
+        manager.wallets.get_mut(&parent_id).unwrap()
+            .dao_properties.as_mut().unwrap().child_dao_wallets.push(child_id.clone());
+        manager.wallets.get_mut(&parent_id).unwrap()
+            .dao_properties.as_mut().unwrap().authorized_dao_controllers.push(controller_id.clone());
+
+        let total = manager.total_power_at(&parent_id).unwrap();
+        assert_eq!(total, 175);
+
+        let power = manager.power_of(&child_id, &parent_id).unwrap();
+        assert_eq!(power, 50);
+    }
+
+    #[test]
+    fn total_power_at_detects_cycles() {
+        let creator = Hash::from_bytes(&[2u8; 32]);
+        let mut manager = WalletManager::new(creator.clone());
+
+        let dao_a = Hash::from_bytes(&[20u8; 32]);
+        let dao_b = Hash::from_bytes(&[21u8; 32]);
+
+        manager.wallets.insert(dao_a.clone(), dao_wallet(dao_a.clone(), creator.clone(), 10));
+        manager.wallets.insert(dao_b.clone(), dao_wallet(dao_b.clone(), creator.clone(), 10));
+
+        manager.wallets.get_mut(&dao_a).unwrap()
+            .dao_properties.as_mut().unwrap().child_dao_wallets.push(dao_b.clone());
+        manager.wallets.get_mut(&dao_b).unwrap()
+            .dao_properties.as_mut().unwrap().child_dao_wallets.push(dao_a.clone());
+
+        let result = manager.total_power_at(&dao_a);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Cycle detected"));
+    }
+
+    #[test]
+    fn power_of_rejects_unrelated_dao() {
+        let creator = Hash::from_bytes(&[3u8; 32]);
+        let mut manager = WalletManager::new(creator.clone());
+
+        let dao_a = Hash::from_bytes(&[30u8; 32]);
+        let dao_b = Hash::from_bytes(&[31u8; 32]);
+
+        manager.wallets.insert(dao_a.clone(), dao_wallet(dao_a.clone(), creator.clone(), 10));
+        manager.wallets.insert(dao_b.clone(), dao_wallet(dao_b.clone(), creator.clone(), 10));
+
+        let result = manager.power_of(&dao_b, &dao_a);
+        assert!(result.is_err());
+    }
+}
@@ -9,6 +9,7 @@ pub mod wallet_operations;
 pub mod wallet_types;
 pub mod wallet_password;
 pub mod dao_hierarchy_demo;
+pub mod slashing;
 
 // Re-exports for compatibility with original identity.rs
 pub use manager_integration::WalletManager;
@@ -23,3 +24,4 @@ pub use wallet_types::{
 };
 pub use wallet_operations::*;
 pub use wallet_password::{WalletPasswordManager, WalletPasswordError, WalletPasswordValidation};
+pub use slashing::{Offence, OffenceKind, Permille, SlashDestination, Slasher};
@@ -0,0 +1,344 @@
+//! Slashing subsystem for misbehaving infrastructure providers
+//!
+//! Rewards flow into a wallet's `staked_balance` when a node does useful
+//! work (routing, storage, uptime). This module is the symmetric penalty
+//! path: when a node is reported for fraudulent work or broken service
+//! guarantees, an `Offence` is recorded and a `Slasher` reduces the
+//! offender's `staked_balance` by a configurable fraction, analogous to
+//! how `validator.rs` slashes stake for consensus misbehavior.
+
+use anyhow::{anyhow, Result};
+use lib_crypto::Hash;
+use serde::{Deserialize, Serialize};
+
+use super::manager_integration::WalletManager;
+use super::wallet_types::WalletId;
+
+/// Kind of infrastructure-provider misbehavior being reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OffenceKind {
+    /// Node claimed storage work it did not actually perform
+    FalselyReportedStorage,
+    /// Node dropped traffic it was responsible for routing
+    RoutingDrop,
+    /// Node fell below its promised uptime
+    UptimeBreach,
+}
+
+/// A single reported offence against a wallet's staked balance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Offence {
+    /// Wallet of the offending node
+    pub offender: Hash,
+    /// Kind of misbehavior reported
+    pub kind: OffenceKind,
+    /// Block height at which the offence was reported
+    pub reported_at_block: u64,
+    /// Hash of the evidence backing this report (proof, signed metrics, etc.)
+    pub evidence_hash: Hash,
+}
+
+/// Where a slashed amount ends up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlashDestination {
+    /// Tokens are destroyed outright
+    Burn,
+    /// Tokens are redirected into a DAO treasury wallet
+    DaoTreasury(WalletId),
+}
+
+/// A parts-per-thousand fraction, finer-grained than the whole-percent
+/// slashing used for validators since infrastructure offences often
+/// warrant sub-percent penalties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Permille(pub u32);
+
+impl Permille {
+    /// Apply this fraction to an amount, saturating at the fraction's own bounds
+    pub fn of(&self, amount: u64) -> u64 {
+        let parts = self.0.min(1000) as u64;
+        amount.saturating_mul(parts) / 1000
+    }
+}
+
+/// Configurable slashing policy: a base fraction per offence kind, plus an
+/// escalation applied when multiple offences land in the same window (so
+/// correlated failures, like a coordinated storage-fraud ring, are
+/// punished harder than one-off faults).
+#[derive(Debug, Clone)]
+pub struct Slasher {
+    /// Base slash fraction applied for each offence kind
+    pub base_fraction: Vec<(OffenceKind, Permille)>,
+    /// Extra fraction added per additional offence observed in the same window
+    pub correlation_step: Permille,
+    /// Width, in blocks, of the window used to detect correlated offences
+    pub window_blocks: u64,
+}
+
+impl Slasher {
+    /// Construct a slasher with the repo's default penalties: light for
+    /// uptime breaches, heavier for dishonest storage/routing reports.
+    pub fn default_policy() -> Self {
+        Self {
+            base_fraction: vec![
+                (OffenceKind::FalselyReportedStorage, Permille(100)),
+                (OffenceKind::RoutingDrop, Permille(50)),
+                (OffenceKind::UptimeBreach, Permille(20)),
+            ],
+            correlation_step: Permille(25),
+            window_blocks: 7200, // ~1 era at 12s blocks
+        }
+    }
+
+    fn base_fraction_for(&self, kind: OffenceKind) -> Permille {
+        self.base_fraction
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, f)| *f)
+            .unwrap_or(Permille(0))
+    }
+
+    /// Fraction to slash for `offence`, scaled up by how many other
+    /// offences from the same offender fall within `window_blocks` of it.
+    fn fraction_for(&self, offence: &Offence, prior_offences_in_window: usize) -> Permille {
+        let base = self.base_fraction_for(offence.kind);
+        let escalation = self.correlation_step.0.saturating_mul(prior_offences_in_window as u32);
+        Permille((base.0 + escalation).min(1000))
+    }
+}
+
+impl WalletManager {
+    /// Report an offence against `offence.offender` and slash its
+    /// `staked_balance` per `slasher`'s policy, redirecting the slashed
+    /// amount per `destination`. Returns the amount actually slashed.
+    ///
+    /// Duplicate reports (same `evidence_hash`) are ignored so the same
+    /// offence can't be double-counted. The slash is saturating: a wallet
+    /// is never slashed below zero.
+    pub fn report_offence(
+        &mut self,
+        offence: Offence,
+        slasher: &Slasher,
+        destination: SlashDestination,
+    ) -> Result<u64> {
+        if !self.processed_offences.insert(offence.evidence_hash.clone()) {
+            return Err(anyhow!(
+                "Offence with evidence {} already processed",
+                hex::encode(&offence.evidence_hash.0[..8])
+            ));
+        }
+
+        let window_start = offence.reported_at_block.saturating_sub(slasher.window_blocks);
+        let prior_in_window = self
+            .offence_log
+            .iter()
+            .filter(|o| {
+                o.offender == offence.offender
+                    && o.reported_at_block >= window_start
+                    && o.reported_at_block <= offence.reported_at_block
+            })
+            .count();
+
+        let fraction = slasher.fraction_for(&offence, prior_in_window);
+
+        let offender = offence.offender.clone();
+        self.offence_log.push(offence);
+
+        self.apply_slash(&offender, fraction, destination)
+    }
+
+    /// Reduce `offender`'s `staked_balance` by `fraction` and redirect the
+    /// slashed amount per `destination`. Returns the amount actually
+    /// slashed (saturating, so a wallet can never go below zero).
+    pub fn apply_slash(
+        &mut self,
+        offender: &WalletId,
+        fraction: Permille,
+        destination: SlashDestination,
+    ) -> Result<u64> {
+        let wallet = self
+            .wallets
+            .get_mut(offender)
+            .ok_or_else(|| anyhow!("Offender wallet not found: {}", hex::encode(&offender.0[..8])))?;
+
+        let slash_amount = fraction.of(wallet.staked_balance);
+        wallet.staked_balance = wallet.staked_balance.saturating_sub(slash_amount);
+
+        match destination {
+            SlashDestination::Burn => {
+                tracing::warn!(
+                    "Burned {} ZHTP slashed from wallet {}",
+                    slash_amount,
+                    hex::encode(&offender.0[..8])
+                );
+            }
+            SlashDestination::DaoTreasury(dao_wallet_id) => {
+                let dao_wallet = self
+                    .wallets
+                    .get_mut(&dao_wallet_id)
+                    .ok_or_else(|| anyhow!("DAO treasury wallet not found"))?;
+
+                if !dao_wallet.is_dao_wallet() {
+                    return Err(anyhow!("Slash destination is not a DAO wallet"));
+                }
+
+                dao_wallet.add_funds(slash_amount);
+
+                let authorized_by = dao_wallet
+                    .dao_properties
+                    .as_ref()
+                    .unwrap()
+                    .authorized_controllers[0]
+                    .clone();
+
+                dao_wallet.add_dao_transaction(
+                    slash_amount,
+                    true, // incoming
+                    Some(offender.clone()),
+                    format!("slash: offender {}", hex::encode(&offender.0[..8])),
+                    &authorized_by,
+                )?;
+
+                tracing::warn!(
+                    "Slashed {} ZHTP from wallet {} into DAO treasury {}",
+                    slash_amount,
+                    hex::encode(&offender.0[..8]),
+                    hex::encode(&dao_wallet_id.0[..8])
+                );
+            }
+        }
+
+        Ok(slash_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallets::{DaoGovernanceSettings, QuantumWallet, TransparencyLevel, WalletType};
+
+    fn dao_wallet(id: Hash, creator: Hash) -> QuantumWallet {
+        QuantumWallet {
+            id: id.clone(),
+            wallet_type: WalletType::NonProfitDAO,
+            name: "Treasury".to_string(),
+            alias: None,
+            balance: 0,
+            staked_balance: 0,
+            pending_rewards: 0,
+            owner_id: Some(creator.clone()),
+            public_key: vec![0u8; 32],
+            seed_phrase: None,
+            encrypted_seed: None,
+            seed_commitment: None,
+            created_at: 0,
+            last_transaction: None,
+            recent_transactions: Vec::new(),
+            is_active: true,
+            dao_properties: Some(super::super::wallet_types::DaoWalletProperties {
+                creator_did: creator.clone(),
+                dao_name: "Treasury".to_string(),
+                dao_description: "Treasury DAO".to_string(),
+                is_nonprofit: true,
+                public_transaction_log: Vec::new(),
+                authorized_controllers: vec![creator],
+                authorized_dao_controllers: Vec::new(),
+                parent_dao_wallet: None,
+                child_dao_wallets: Vec::new(),
+                governance_settings: DaoGovernanceSettings {
+                    min_signatures_required: 1,
+                    max_single_transaction: 1_000_000,
+                    requires_governance_vote: false,
+                    voting_threshold_percent: 60,
+                },
+                transparency_level: TransparencyLevel::Full,
+                founded_at: 0,
+                total_funds_received: 0,
+                total_funds_spent: 0,
+                transaction_count: 0,
+            }),
+            derivation_index: None,
+            password_hash: None,
+            owned_content: Vec::new(),
+            total_storage_used: 0,
+            total_content_value: 0,
+        }
+    }
+
+    #[test]
+    fn slash_never_goes_below_zero() {
+        let creator = Hash::from_bytes(&[1u8; 32]);
+        let mut manager = WalletManager::new(creator.clone());
+
+        let offender_id = manager.create_wallet_for_testing(WalletType::Primary, "Node".to_string(), None).unwrap();
+        manager.wallets.get_mut(&offender_id).unwrap().staked_balance = 10;
+
+        let slashed = manager
+            .apply_slash(&offender_id, Permille(1000), SlashDestination::Burn)
+            .unwrap();
+
+        assert_eq!(slashed, 10);
+        assert_eq!(manager.get_wallet(&offender_id).unwrap().staked_balance, 0);
+
+        // A second slash on an already-zero balance must not underflow
+        let slashed_again = manager
+            .apply_slash(&offender_id, Permille(1000), SlashDestination::Burn)
+            .unwrap();
+        assert_eq!(slashed_again, 0);
+    }
+
+    #[test]
+    fn duplicate_evidence_is_rejected() {
+        let creator = Hash::from_bytes(&[2u8; 32]);
+        let mut manager = WalletManager::new(creator.clone());
+
+        let offender_id = manager.create_wallet_for_testing(WalletType::Primary, "Node".to_string(), None).unwrap();
+        manager.wallets.get_mut(&offender_id).unwrap().staked_balance = 1000;
+
+        let slasher = Slasher::default_policy();
+        let evidence = Hash::from_bytes(&[9u8; 32]);
+        let offence = Offence {
+            offender: offender_id.clone(),
+            kind: OffenceKind::UptimeBreach,
+            reported_at_block: 100,
+            evidence_hash: evidence.clone(),
+        };
+
+        let first = manager.report_offence(offence.clone(), &slasher, SlashDestination::Burn).unwrap();
+        assert!(first > 0);
+
+        let second = manager.report_offence(offence, &slasher, SlashDestination::Burn);
+        assert!(second.is_err());
+    }
+
+    #[test]
+    fn slash_redirects_to_dao_treasury_and_logs_it() {
+        let creator = Hash::from_bytes(&[3u8; 32]);
+        let mut manager = WalletManager::new(creator.clone());
+
+        let offender_id = manager.create_wallet_for_testing(WalletType::Primary, "Node".to_string(), None).unwrap();
+        manager.wallets.get_mut(&offender_id).unwrap().staked_balance = 1000;
+
+        let treasury_id = Hash::from_bytes(&[4u8; 32]);
+        manager.wallets.insert(treasury_id.clone(), dao_wallet(treasury_id.clone(), creator));
+
+        let slasher = Slasher::default_policy();
+        let offence = Offence {
+            offender: offender_id.clone(),
+            kind: OffenceKind::FalselyReportedStorage,
+            reported_at_block: 50,
+            evidence_hash: Hash::from_bytes(&[5u8; 32]),
+        };
+
+        let slashed = manager
+            .report_offence(offence, &slasher, SlashDestination::DaoTreasury(treasury_id.clone()))
+            .unwrap();
+
+        assert_eq!(slashed, 100); // 100 permille of 1000
+        assert_eq!(manager.get_wallet(&treasury_id).unwrap().balance, 100);
+        assert_eq!(
+            manager.get_dao_public_transactions(&treasury_id).unwrap().len(),
+            1
+        );
+    }
+}
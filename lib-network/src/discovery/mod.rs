@@ -5,6 +5,8 @@ pub mod hardware;
 pub mod lorawan_hardware;
 pub mod geo_location;
 pub mod local_network;
+pub mod nat_traversal;
+pub mod peer_reputation;
 pub mod smart_routing;
 pub mod unified;
 
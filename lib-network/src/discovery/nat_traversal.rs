@@ -0,0 +1,246 @@
+//! NAT traversal and external-address discovery
+//!
+//! `get_local_ip` in [`super::smart_routing`] only ever reports the node's
+//! local interface address, and `categorize_peers_by_topology` has no
+//! notion of a peer sitting behind a NAT. This module discovers the node's
+//! externally-visible address (first via IGD/UPnP port mapping on the
+//! local gateway, falling back to a STUN-like exchange against known
+//! peers), classifies the local NAT's behavior, and coordinates UDP hole
+//! punching between two NAT'd peers via a rendezvous peer so they can use
+//! a direct path instead of relayed routing.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tracing::{debug, warn};
+
+/// Probe payload used for the STUN-like external-address exchange
+const WHOAMI_PROBE: &[u8] = b"ZHTP-WHOAMI-V1";
+
+/// Probe payload used while hole punching
+const PUNCH_PROBE: &[u8] = b"ZHTP-PUNCH-V1";
+
+/// Classic STUN-style NAT behavior classification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NatType {
+    /// No NAT, or a full-cone NAT: any external host can reach the mapped port
+    FullCone,
+    /// External mapping is stable; distinguishing this from full-cone
+    /// requires an unsolicited-peer reachability test we don't perform, so
+    /// any NAT with a stable mapping is conservatively classified here
+    RestrictedCone,
+    /// A fresh external port is allocated per destination, so the mapping
+    /// observed by one peer cannot be used by another; hardest to punch
+    Symmetric,
+    /// Not yet determined
+    Unknown,
+}
+
+impl NatType {
+    /// Whether two peers behind this NAT type can generally reach each
+    /// other with simple UDP hole punching, without a relay
+    pub fn is_punchable(self) -> bool {
+        matches!(self, NatType::FullCone | NatType::RestrictedCone)
+    }
+}
+
+/// Request a UDP port mapping on the local gateway via IGD/UPnP, returning
+/// the external address the mapping is reachable at.
+pub async fn request_port_mapping(local_port: u16, description: &str) -> Result<SocketAddr> {
+    let description = description.to_string();
+    tokio::task::spawn_blocking(move || -> Result<SocketAddr> {
+        let gateway =
+            igd::search_gateway(igd::SearchOptions::default()).context("No UPnP/IGD gateway found")?;
+        let local_ip = local_ipv4().context("Could not determine local IPv4 address")?;
+        let local_addr = SocketAddrV4::new(local_ip, local_port);
+
+        gateway
+            .add_port(
+                igd::PortMappingProtocol::UDP,
+                local_port,
+                local_addr,
+                3600,
+                &description,
+            )
+            .context("Failed to add UPnP port mapping")?;
+
+        let external_ip = gateway
+            .get_external_ip()
+            .context("Failed to query external IP from gateway")?;
+        Ok(SocketAddr::V4(SocketAddrV4::new(external_ip, local_port)))
+    })
+    .await
+    .context("IGD worker task panicked")?
+}
+
+fn local_ipv4() -> Result<Ipv4Addr> {
+    let socket = std::net::UdpSocket::bind("0.0.0.0:0")?;
+    socket.connect("8.8.8.8:80")?;
+    match socket.local_addr()? {
+        SocketAddr::V4(addr) => Ok(*addr.ip()),
+        SocketAddr::V6(_) => Ok(Ipv4Addr::new(127, 0, 0, 1)),
+    }
+}
+
+/// Fall back to a STUN-like exchange against already-known peers to learn
+/// our externally-visible address when no IGD/UPnP gateway is available.
+/// Each peer is expected to answer a [`WHOAMI_PROBE`] with the source
+/// address it observed the probe arrive from (see [`respond_to_probe`]);
+/// the most commonly reported address wins.
+pub async fn discover_external_address_via_peers(known_peers: &[SocketAddr]) -> Result<SocketAddr> {
+    if known_peers.is_empty() {
+        anyhow::bail!("No known peers available for STUN-like address discovery");
+    }
+
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    for peer in known_peers {
+        let _ = socket.send_to(WHOAMI_PROBE, peer).await;
+    }
+
+    let responses = collect_whoami_responses(&socket, known_peers.len()).await?;
+    majority_address(&responses).context("No usable response from known peers")
+}
+
+/// Query each of `known_peers` individually and return the externally
+/// observed address each one reports, for NAT type classification.
+pub async fn probe_external_address_per_peer(
+    known_peers: &[SocketAddr],
+) -> Result<HashMap<SocketAddr, SocketAddr>> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    let mut reported = HashMap::new();
+
+    for peer in known_peers {
+        if socket.send_to(WHOAMI_PROBE, peer).await.is_err() {
+            continue;
+        }
+        let mut buf = [0u8; 128];
+        match tokio::time::timeout(Duration::from_millis(500), socket.recv_from(&mut buf)).await {
+            Ok(Ok((len, from))) if from == *peer => {
+                if let Some(addr) = parse_whoami_reply(&buf[..len]) {
+                    reported.insert(*peer, addr);
+                }
+            }
+            _ => debug!("No STUN-like reply from {}", peer),
+        }
+    }
+
+    Ok(reported)
+}
+
+/// Classify this node's NAT behavior from the external addresses reported
+/// by multiple peers: a consistent mapping across peers is cone-like, a
+/// mapping that varies per destination is symmetric.
+pub fn classify_nat_type(reported: &HashMap<SocketAddr, SocketAddr>) -> NatType {
+    if reported.len() < 2 {
+        return NatType::Unknown;
+    }
+    let distinct: std::collections::HashSet<SocketAddr> = reported.values().copied().collect();
+    if distinct.len() == 1 {
+        NatType::RestrictedCone
+    } else {
+        NatType::Symmetric
+    }
+}
+
+async fn collect_whoami_responses(socket: &UdpSocket, expected: usize) -> Result<Vec<SocketAddr>> {
+    let mut responses = Vec::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(1500);
+
+    while responses.len() < expected {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let mut buf = [0u8; 128];
+        match tokio::time::timeout(remaining, socket.recv_from(&mut buf)).await {
+            Ok(Ok((len, _from))) => {
+                if let Some(addr) = parse_whoami_reply(&buf[..len]) {
+                    responses.push(addr);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    Ok(responses)
+}
+
+fn parse_whoami_reply(data: &[u8]) -> Option<SocketAddr> {
+    std::str::from_utf8(data).ok()?.parse().ok()
+}
+
+fn majority_address(responses: &[SocketAddr]) -> Option<SocketAddr> {
+    let mut counts: HashMap<SocketAddr, usize> = HashMap::new();
+    for addr in responses {
+        *counts.entry(*addr).or_insert(0) += 1;
+    }
+    counts.into_iter().max_by_key(|(_, count)| *count).map(|(addr, _)| addr)
+}
+
+/// Respond to an incoming [`WHOAMI_PROBE`] by echoing back the observed
+/// source address as text, letting the sender learn its external mapping.
+/// No-op for any other payload.
+pub async fn respond_to_probe(socket: &UdpSocket, probe: &[u8], from: SocketAddr) -> Result<()> {
+    if probe != WHOAMI_PROBE {
+        return Ok(());
+    }
+    socket.send_to(from.to_string().as_bytes(), from).await?;
+    Ok(())
+}
+
+/// Coordinates UDP hole punching between this node and a NAT'd peer, once a
+/// rendezvous peer both sides are already connected to has exchanged each
+/// side's externally observed address out of band. Both sides then send
+/// simultaneous UDP packets to the other's reported external address so
+/// the in-transit packets punch matching pinholes in each NAT.
+pub struct HolePunchCoordinator {
+    socket: UdpSocket,
+}
+
+impl HolePunchCoordinator {
+    /// Bind the local socket used for punching, reusing the same port the
+    /// node's external mapping was established on.
+    pub async fn bind(local_port: u16) -> Result<Self> {
+        let socket = UdpSocket::bind(("0.0.0.0", local_port)).await?;
+        Ok(Self { socket })
+    }
+
+    /// Attempt to punch a direct path to `peer_external_addr`, retrying up
+    /// to `attempts` times since the first few packets typically arrive
+    /// before the peer's matching pinhole is open. Returns `true` if a
+    /// reply was heard back, meaning a direct path is usable; `false`
+    /// means the caller should fall back to relayed routing via the
+    /// rendezvous peer.
+    pub async fn punch(&self, peer_external_addr: SocketAddr, attempts: u32) -> Result<bool> {
+        let mut buf = [0u8; 128];
+
+        for attempt in 0..attempts {
+            let _ = self.socket.send_to(PUNCH_PROBE, peer_external_addr).await;
+
+            match tokio::time::timeout(
+                Duration::from_millis(250),
+                self.socket.recv_from(&mut buf),
+            )
+            .await
+            {
+                Ok(Ok((_, from))) if from == peer_external_addr => {
+                    debug!(
+                        "Hole punch to {} succeeded after {} attempt(s)",
+                        peer_external_addr,
+                        attempt + 1
+                    );
+                    return Ok(true);
+                }
+                _ => continue,
+            }
+        }
+
+        warn!(
+            "Hole punch to {} failed after {} attempts; falling back to relay",
+            peer_external_addr, attempts
+        );
+        Ok(false)
+    }
+}
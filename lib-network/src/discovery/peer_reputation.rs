@@ -0,0 +1,238 @@
+//! Persistent peer reputation tracking
+//!
+//! `measure_peer_performance` only ever reflects a single TCP connect
+//! attempt. This module accumulates observations over time so routing
+//! decisions can reflect sustained behavior instead: latency and bandwidth
+//! are smoothed with an exponentially weighted moving average (EWMA),
+//! reliability is tracked as a decayed success/failure ratio, and the whole
+//! store is persisted to disk with serde so reputation survives restarts.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::Path;
+use tracing::debug;
+
+/// Weight given to each new latency/bandwidth sample; higher reacts faster
+/// to recent observations, lower smooths out noise.
+const EWMA_ALPHA: f64 = 0.2;
+
+/// Decay applied to the reliability ratio before each new observation is
+/// folded in, so old outcomes matter less than recent ones.
+const RELIABILITY_DECAY: f64 = 0.9;
+
+/// Payload size used by the active bandwidth probe
+const BANDWIDTH_PROBE_BYTES: usize = 64 * 1024;
+
+/// Largest TTL tried by the hop-count probe before giving up
+const MAX_HOP_PROBE_TTL: u32 = 32;
+
+/// Smoothed, persisted performance history for a single peer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerReputation {
+    pub latency_ewma_ms: f64,
+    pub bandwidth_ewma_mbps: f64,
+    /// Decayed success ratio in `[0.0, 1.0]`; 1.0 means consistently successful
+    pub reliability: f64,
+    pub hop_count: u32,
+    pub sample_count: u64,
+    pub last_updated: u64,
+}
+
+impl Default for PeerReputation {
+    fn default() -> Self {
+        Self {
+            latency_ewma_ms: 0.0,
+            bandwidth_ewma_mbps: 0.0,
+            reliability: 1.0, // optimistic prior until proven otherwise
+            hop_count: 1,
+            sample_count: 0,
+            last_updated: 0,
+        }
+    }
+}
+
+impl PeerReputation {
+    fn touch(&mut self) {
+        self.sample_count += 1;
+        self.last_updated = now_secs();
+    }
+
+    fn record_latency_sample(&mut self, latency_ms: f64) {
+        self.latency_ewma_ms = ewma(self.latency_ewma_ms, latency_ms, self.sample_count == 0);
+    }
+
+    fn record_bandwidth_sample(&mut self, bandwidth_mbps: f64) {
+        self.bandwidth_ewma_mbps =
+            ewma(self.bandwidth_ewma_mbps, bandwidth_mbps, self.sample_count == 0);
+    }
+
+    fn record_success(&mut self) {
+        self.reliability = self.reliability * RELIABILITY_DECAY + (1.0 - RELIABILITY_DECAY);
+    }
+
+    fn record_failure(&mut self) {
+        self.reliability *= RELIABILITY_DECAY;
+    }
+}
+
+fn ewma(old: f64, sample: f64, is_first: bool) -> f64 {
+    if is_first {
+        sample
+    } else {
+        EWMA_ALPHA * sample + (1.0 - EWMA_ALPHA) * old
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Persistent store of per-peer reputation, keyed by address
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PeerReputationStore {
+    peers: HashMap<SocketAddr, PeerReputation>,
+}
+
+impl PeerReputationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load the store from `path`, or start a fresh one if it doesn't exist yet
+    pub fn load_or_create(path: &Path) -> Result<Self> {
+        if path.exists() {
+            let data = std::fs::read_to_string(path)
+                .context("Failed to read peer reputation store")?;
+            serde_json::from_str(&data).context("Failed to parse peer reputation store")
+        } else {
+            Ok(Self::new())
+        }
+    }
+
+    /// Persist the store to `path`, creating parent directories as needed
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+
+    /// Current smoothed reputation for `peer`, if any observations exist
+    pub fn get(&self, peer: &SocketAddr) -> Option<PeerReputation> {
+        self.peers.get(peer).cloned()
+    }
+
+    /// Record a successful connection attempt with its observed latency
+    pub fn record_connection_success(&mut self, peer: SocketAddr, latency_ms: f64) {
+        let entry = self.peers.entry(peer).or_default();
+        entry.record_latency_sample(latency_ms);
+        entry.record_success();
+        entry.touch();
+    }
+
+    /// Record a connection attempt that timed out or otherwise failed
+    pub fn record_connection_failure(&mut self, peer: SocketAddr) {
+        let entry = self.peers.entry(peer).or_default();
+        entry.record_failure();
+        entry.touch();
+    }
+
+    /// Record that an outbound packet to `peer` was dropped (e.g. its
+    /// staging queue overflowed), counting against reliability the same
+    /// way a failed connection attempt does.
+    pub fn record_dropped_packet(&mut self, peer: SocketAddr) {
+        let entry = self.peers.entry(peer).or_default();
+        entry.record_failure();
+        entry.touch();
+    }
+
+    /// Fold in a bandwidth sample from [`probe_bandwidth`]
+    pub fn record_bandwidth_sample(&mut self, peer: SocketAddr, bandwidth_mbps: f64) {
+        let entry = self.peers.entry(peer).or_default();
+        entry.record_bandwidth_sample(bandwidth_mbps);
+        entry.touch();
+    }
+
+    /// Record a hop-count estimate from [`probe_hop_count`]
+    pub fn record_hop_count(&mut self, peer: SocketAddr, hop_count: u32) {
+        let entry = self.peers.entry(peer).or_default();
+        entry.hop_count = hop_count;
+        entry.touch();
+    }
+}
+
+/// Actively measure achievable bandwidth to `peer` by timing a TCP transfer
+/// of a known-size payload, returning the observed throughput in Mbps.
+pub async fn probe_bandwidth(peer: SocketAddr) -> Result<f64> {
+    use tokio::io::AsyncWriteExt;
+
+    let payload = vec![0u8; BANDWIDTH_PROBE_BYTES];
+    let start = std::time::Instant::now();
+
+    let mut stream = tokio::time::timeout(
+        std::time::Duration::from_millis(2000),
+        tokio::net::TcpStream::connect(peer),
+    )
+    .await
+    .context("Bandwidth probe connect timed out")??;
+
+    tokio::time::timeout(std::time::Duration::from_millis(5000), async {
+        stream.write_all(&payload).await?;
+        stream.flush().await
+    })
+    .await
+    .context("Bandwidth probe transfer timed out")??;
+
+    let elapsed_secs = start.elapsed().as_secs_f64().max(0.001);
+    let bits_sent = (payload.len() * 8) as f64;
+    let mbps = bits_sent / elapsed_secs / 1_000_000.0;
+    debug!("Bandwidth probe to {}: {:.2} Mbps", peer, mbps);
+    Ok(mbps)
+}
+
+/// Estimate the number of hops to `peer` by connecting with increasing IP
+/// TTL values until one survives the full path (i.e. the connection
+/// succeeds), mirroring how traceroute walks TTL to find path length.
+/// Falls back to [`MAX_HOP_PROBE_TTL`] if the peer can't be reached within
+/// the probed range.
+pub async fn probe_hop_count(peer: SocketAddr) -> Result<u32> {
+    for ttl in 1..=MAX_HOP_PROBE_TTL {
+        let reachable = tokio::task::spawn_blocking(move || -> bool {
+            use socket2::{Domain, Protocol, Socket, Type};
+
+            let domain = match peer {
+                SocketAddr::V4(_) => Domain::IPV4,
+                SocketAddr::V6(_) => Domain::IPV6,
+            };
+            let Ok(socket) = Socket::new(domain, Type::STREAM, Some(Protocol::TCP)) else {
+                return false;
+            };
+            if socket.set_ttl(ttl).is_err() {
+                return false;
+            }
+            socket
+                .connect_timeout(&peer.into(), std::time::Duration::from_millis(300))
+                .is_ok()
+        })
+        .await
+        .unwrap_or(false);
+
+        if reachable {
+            debug!("Hop-count probe to {}: reachable at TTL {}", peer, ttl);
+            return Ok(ttl);
+        }
+    }
+
+    debug!(
+        "Hop-count probe to {} did not resolve within {} hops",
+        peer, MAX_HOP_PROBE_TTL
+    );
+    Ok(MAX_HOP_PROBE_TTL)
+}
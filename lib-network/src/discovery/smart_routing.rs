@@ -4,9 +4,14 @@
 
 use anyhow::Result;
 use std::net::SocketAddr;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
 use tracing::{info, debug};
 
+use crate::discovery::nat_traversal::NatType;
+use crate::discovery::peer_reputation::{self, PeerReputationStore};
+
 /// Peer quality metrics for routing decisions
 #[derive(Debug, Clone)]
 pub struct PeerMetrics {
@@ -16,6 +21,18 @@ pub struct PeerMetrics {
     pub hop_count: u32,
     pub last_seen: u64,
     pub peer_type: PeerType,
+    /// Number of outbound packets currently staged for this peer because it
+    /// is not yet reachable (see [`PeerStagingQueue`])
+    pub staged_queue_depth: usize,
+    /// Outbound packets dropped for this peer because its staging queue was
+    /// full when they arrived
+    pub staged_drop_count: u64,
+    /// This peer's NAT behavior, as classified by the NAT traversal
+    /// subsystem (see `nat_traversal::classify_nat_type`)
+    pub nat_type: NatType,
+    /// Whether a direct UDP hole-punched path to this peer is already
+    /// established, so routing can prefer it over a relayed path
+    pub direct_path_established: bool,
 }
 
 /// Type of peer for routing prioritization
@@ -25,6 +42,7 @@ pub enum PeerType {
     WiFiDirect,      // Direct WiFi connection
     BluetoothLE,     // Bluetooth mesh
     LoRaWAN,         // Long-range low-power
+    NatTraversal,    // Reached via UPnP mapping / UDP hole punching
     Internet,        // Traditional internet routing
     Satellite,       // Satellite uplink
 }
@@ -71,10 +89,25 @@ fn calculate_peer_score(metrics: &PeerMetrics) -> f64 {
         PeerType::WiFiDirect => 80.0,
         PeerType::BluetoothLE => 60.0,
         PeerType::LoRaWAN => 40.0,
+        PeerType::NatTraversal => 30.0,
         PeerType::Internet => 20.0,
         PeerType::Satellite => 10.0,
     };
     score += type_weight;
+
+    // Weight reachability by NAT class: a stable (cone-like) mapping is
+    // easy to punch through directly, a symmetric NAT usually forces a
+    // relayed path, and an already-established direct path is worth more
+    // than one we'd still have to (re-)punch.
+    score += match metrics.nat_type {
+        NatType::FullCone => 15.0,
+        NatType::RestrictedCone => 8.0,
+        NatType::Symmetric => -10.0,
+        NatType::Unknown => 0.0,
+    };
+    if metrics.direct_path_established {
+        score += 25.0;
+    }
     
     // Latency (lower is better, invert for scoring)
     let latency_score = if metrics.latency_ms > 0.0 {
@@ -110,43 +143,56 @@ fn calculate_peer_score(metrics: &PeerMetrics) -> f64 {
         0.0
     };
     score += freshness_score;
-    
+
+    // Penalize peers whose staged-packet queue is backing up or dropping
+    // packets: a saturated queue means the peer is slow to become reachable
+    // and is actively losing outbound data.
+    score -= metrics.staged_queue_depth as f64 * 0.5;
+    score -= metrics.staged_drop_count as f64 * 5.0;
+
     score.max(0.0) // Ensure non-negative
 }
 
-/// Automatically categorize peers by network topology
+/// Automatically categorize peers by network topology. `nat_types` carries
+/// any NAT classification already known for a peer (see
+/// `nat_traversal::classify_nat_type`); a non-internet, non-local peer with
+/// a punchable NAT type is categorized as [`PeerType::NatTraversal`]
+/// instead of plain [`PeerType::Internet`].
 pub async fn categorize_peers_by_topology(
-    peers: &[SocketAddr]
+    peers: &[SocketAddr],
+    nat_types: &HashMap<SocketAddr, NatType>,
 ) -> Result<HashMap<PeerType, Vec<SocketAddr>>> {
     let mut categorized: HashMap<PeerType, Vec<SocketAddr>> = HashMap::new();
-    
+
     let local_ip = get_local_ip().await?;
     let local_subnet = get_subnet_base(&local_ip);
-    
+
     for peer in peers {
         let peer_type = match peer {
             SocketAddr::V4(v4_addr) => {
                 let peer_subnet = get_subnet_base(v4_addr.ip());
-                
+
                 if peer_subnet == local_subnet {
                     PeerType::LocalSubnet
                 } else if is_private_ip(v4_addr.ip()) {
                     PeerType::WiFiDirect // Assume WiFi Direct for other private IPs
+                } else if nat_types.get(peer).is_some_and(|nat| nat.is_punchable()) {
+                    PeerType::NatTraversal
                 } else {
                     PeerType::Internet
                 }
             },
             SocketAddr::V6(_) => PeerType::Internet, // IPv6 treated as internet
         };
-        
+
         categorized.entry(peer_type).or_insert_with(Vec::new).push(*peer);
     }
-    
+
     info!("Categorized peers by topology:");
     for (peer_type, addrs) in &categorized {
         info!("  {:?}: {} peers", peer_type, addrs.len());
     }
-    
+
     Ok(categorized)
 }
 
@@ -193,10 +239,16 @@ async fn get_local_ip() -> Result<std::net::Ipv4Addr> {
     }
 }
 
-/// Measure peer performance metrics
-pub async fn measure_peer_performance(peer: SocketAddr) -> Result<PeerMetrics> {
+/// Measure peer performance metrics, folding the observation into
+/// `reputation` and returning metrics built from its smoothed EWMA values
+/// (rather than this single sample) so `calculate_peer_score` reflects
+/// sustained peer behavior.
+pub async fn measure_peer_performance(
+    peer: SocketAddr,
+    reputation: &mut PeerReputationStore,
+) -> Result<PeerMetrics> {
     let start_time = std::time::Instant::now();
-    
+
     // Simple latency test
     match tokio::time::timeout(
         std::time::Duration::from_millis(1000),
@@ -204,21 +256,315 @@ pub async fn measure_peer_performance(peer: SocketAddr) -> Result<PeerMetrics> {
     ).await {
         Ok(Ok(_stream)) => {
             let latency = start_time.elapsed().as_millis() as f64;
-            
+            reputation.record_connection_success(peer, latency);
+
+            match peer_reputation::probe_bandwidth(peer).await {
+                Ok(bandwidth_mbps) => reputation.record_bandwidth_sample(peer, bandwidth_mbps),
+                Err(e) => debug!("Bandwidth probe to {} failed: {}", peer, e),
+            }
+
+            match peer_reputation::probe_hop_count(peer).await {
+                Ok(hop_count) => reputation.record_hop_count(peer, hop_count),
+                Err(e) => debug!("Hop-count probe to {} failed: {}", peer, e),
+            }
+
+            let rep = reputation.get(&peer).unwrap_or_default();
+
             Ok(PeerMetrics {
-                latency_ms: latency,
-                bandwidth_mbps: 0.0, // TODO: Implement bandwidth test
-                reliability: 1.0,    // TODO: Track over time
-                hop_count: 1,        // TODO: Implement traceroute-like functionality
+                latency_ms: rep.latency_ewma_ms,
+                bandwidth_mbps: rep.bandwidth_ewma_mbps,
+                reliability: rep.reliability,
+                hop_count: rep.hop_count,
                 last_seen: std::time::SystemTime::now()
                     .duration_since(std::time::UNIX_EPOCH)
                     .unwrap()
                     .as_secs(),
                 peer_type: PeerType::Internet, // Will be updated by categorization
+                staged_queue_depth: 0,
+                staged_drop_count: 0,
+                nat_type: NatType::Unknown, // Determined separately via nat_traversal
+                direct_path_established: false,
             })
         },
         _ => {
+            reputation.record_connection_failure(peer);
             Err(anyhow::anyhow!("Failed to connect to peer {}", peer))
         }
     }
+}
+
+/// Maximum number of outbound packets buffered for a single peer while its
+/// session/connection is still being established. Beyond this, further
+/// packets for that peer are dropped (and counted) rather than staged
+/// indefinitely.
+const MAX_STAGED_PACKETS_PER_PEER: usize = 128;
+
+/// An outbound packet waiting for its destination peer to become reachable
+#[derive(Debug, Clone)]
+struct StagedPacket {
+    payload: Vec<u8>,
+}
+
+/// Bounded FIFO of packets staged for a single not-yet-connected peer
+#[derive(Debug, Default)]
+struct PeerStagingQueue {
+    packets: VecDeque<StagedPacket>,
+    dropped: u64,
+}
+
+impl PeerStagingQueue {
+    /// Stage `packet`, returning `true` if it was dropped because the queue
+    /// was already at [`MAX_STAGED_PACKETS_PER_PEER`].
+    fn stage(&mut self, packet: StagedPacket) -> bool {
+        if self.packets.len() >= MAX_STAGED_PACKETS_PER_PEER {
+            self.dropped += 1;
+            return true;
+        }
+        self.packets.push_back(packet);
+        false
+    }
+
+    fn drain(&mut self) -> Vec<StagedPacket> {
+        self.packets.drain(..).collect()
+    }
+}
+
+/// One unit of work submitted to the [`PacketWorkerPool`]: a single packet
+/// for `peer`, tagged with a per-peer monotonically increasing sequence
+/// number so the reordering stage can restore on-wire order afterwards.
+struct PacketWorkItem {
+    peer: SocketAddr,
+    sequence: u64,
+    payload: Vec<u8>,
+}
+
+/// The outcome of processing a [`PacketWorkItem`], still tagged with its
+/// peer and sequence number for reordering
+struct PacketWorkResult {
+    peer: SocketAddr,
+    sequence: u64,
+    result: std::result::Result<Vec<u8>, String>,
+}
+
+/// Per-peer reordering state: the next sequence number expected to be
+/// emitted, plus any out-of-order completions received ahead of it
+#[derive(Default)]
+struct ReorderState {
+    next_sequence: u64,
+    pending: BTreeMap<u64, std::result::Result<Vec<u8>, String>>,
+}
+
+/// Per-packet work performed by the worker pool, e.g. encryption/framing.
+/// Boxed so the pool is agnostic to the concrete transport being used.
+pub type PacketProcessor = Arc<dyn Fn(Vec<u8>) -> Result<Vec<u8>> + Send + Sync>;
+
+/// A shared pool of workers that process outbound packets in parallel and
+/// re-sequence the results so concurrency cannot scramble on-wire ordering.
+///
+/// Workers pull from a single bounded queue shared behind a mutex (the
+/// `mpsc::Receiver` has only one consumer at a time, so only the `recv`
+/// call itself is ever held under the lock); a dedicated reordering task
+/// collects their results per peer and forwards them downstream in
+/// sequence order.
+pub struct PacketWorkerPool {
+    work_tx: mpsc::Sender<PacketWorkItem>,
+    _workers: Vec<tokio::task::JoinHandle<()>>,
+    _reorder_task: tokio::task::JoinHandle<()>,
+}
+
+impl PacketWorkerPool {
+    /// Spawn `worker_count` workers sharing a queue of `queue_capacity`
+    /// packets, running `processor` on each. Returns the pool plus the
+    /// channel on which reordered `(peer, processed_payload)` pairs arrive.
+    pub fn new(
+        worker_count: usize,
+        queue_capacity: usize,
+        processor: PacketProcessor,
+    ) -> (Self, mpsc::Receiver<(SocketAddr, Vec<u8>)>) {
+        let (work_tx, work_rx) = mpsc::channel::<PacketWorkItem>(queue_capacity);
+        let work_rx = Arc::new(Mutex::new(work_rx));
+
+        let (result_tx, mut result_rx) = mpsc::channel::<PacketWorkResult>(queue_capacity);
+        let (ordered_tx, ordered_rx) = mpsc::channel::<(SocketAddr, Vec<u8>)>(queue_capacity);
+
+        let mut workers = Vec::with_capacity(worker_count);
+        for _ in 0..worker_count {
+            let work_rx = work_rx.clone();
+            let result_tx = result_tx.clone();
+            let processor = processor.clone();
+            workers.push(tokio::spawn(async move {
+                loop {
+                    let item = {
+                        let mut rx = work_rx.lock().await;
+                        rx.recv().await
+                    };
+                    let Some(item) = item else { break };
+                    let result = processor(item.payload).map_err(|e| e.to_string());
+                    if result_tx
+                        .send(PacketWorkResult {
+                            peer: item.peer,
+                            sequence: item.sequence,
+                            result,
+                        })
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }));
+        }
+        drop(result_tx);
+
+        let reorder_task = tokio::spawn(async move {
+            let mut state: HashMap<SocketAddr, ReorderState> = HashMap::new();
+            while let Some(res) = result_rx.recv().await {
+                let entry = state.entry(res.peer).or_default();
+                if res.sequence < entry.next_sequence {
+                    continue; // stale/duplicate completion, drop
+                }
+                entry.pending.insert(res.sequence, res.result);
+
+                while let Some(result) = entry.pending.remove(&entry.next_sequence) {
+                    entry.next_sequence += 1;
+                    if let Ok(payload) = result {
+                        if ordered_tx.send((res.peer, payload)).await.is_err() {
+                            return;
+                        }
+                    }
+                    // A failed packet still advances next_sequence so one
+                    // bad packet doesn't stall the rest of the peer's stream.
+                }
+            }
+        });
+
+        (
+            Self {
+                work_tx,
+                _workers: workers,
+                _reorder_task: reorder_task,
+            },
+            ordered_rx,
+        )
+    }
+
+    async fn submit(&self, peer: SocketAddr, sequence: u64, payload: Vec<u8>) -> Result<()> {
+        self.work_tx
+            .send(PacketWorkItem {
+                peer,
+                sequence,
+                payload,
+            })
+            .await
+            .map_err(|_| anyhow::anyhow!("packet worker pool channel closed"))
+    }
+}
+
+/// Routes outbound packets to peers, staging them while a peer's
+/// session/connection is still being established instead of dropping them,
+/// and flushing the staged backlog in order once the peer becomes
+/// reachable. Processing (encryption/framing) happens on the shared
+/// [`PacketWorkerPool`], whose reordering stage guarantees the processed
+/// output is still emitted in submission order per peer.
+pub struct OutboundPacketRouter {
+    pool: PacketWorkerPool,
+    staging: Arc<Mutex<HashMap<SocketAddr, PeerStagingQueue>>>,
+    connected: Arc<Mutex<HashSet<SocketAddr>>>,
+    next_sequence: Arc<Mutex<HashMap<SocketAddr, u64>>>,
+    reputation: Option<Arc<Mutex<PeerReputationStore>>>,
+}
+
+impl OutboundPacketRouter {
+    /// Create a router backed by a fresh worker pool. Returns the router
+    /// plus the channel on which reordered `(peer, processed_payload)`
+    /// pairs arrive for final transmission.
+    pub fn new(
+        worker_count: usize,
+        queue_capacity: usize,
+        processor: PacketProcessor,
+    ) -> (Self, mpsc::Receiver<(SocketAddr, Vec<u8>)>) {
+        let (pool, ordered_rx) = PacketWorkerPool::new(worker_count, queue_capacity, processor);
+        (
+            Self {
+                pool,
+                staging: Arc::new(Mutex::new(HashMap::new())),
+                connected: Arc::new(Mutex::new(HashSet::new())),
+                next_sequence: Arc::new(Mutex::new(HashMap::new())),
+                reputation: None,
+            },
+            ordered_rx,
+        )
+    }
+
+    /// Attach a shared [`PeerReputationStore`] so staging drops count
+    /// against a peer's reliability score
+    pub fn with_reputation_store(mut self, reputation: Arc<Mutex<PeerReputationStore>>) -> Self {
+        self.reputation = Some(reputation);
+        self
+    }
+
+    /// Send `payload` to `peer`: submitted to the worker pool immediately if
+    /// the peer is connected, otherwise staged (and possibly dropped, if the
+    /// peer's staging queue is already full).
+    pub async fn send(&self, peer: SocketAddr, payload: Vec<u8>) -> Result<()> {
+        let is_connected = self.connected.lock().await.contains(&peer);
+        if is_connected {
+            self.submit(peer, payload).await
+        } else {
+            let dropped = {
+                let mut staging = self.staging.lock().await;
+                staging
+                    .entry(peer)
+                    .or_default()
+                    .stage(StagedPacket { payload })
+            };
+            if dropped {
+                if let Some(reputation) = &self.reputation {
+                    reputation.lock().await.record_dropped_packet(peer);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Mark `peer` as reachable and flush any packets staged for it, in the
+    /// order they were staged.
+    pub async fn mark_connected(&self, peer: SocketAddr) -> Result<()> {
+        self.connected.lock().await.insert(peer);
+        let staged = {
+            let mut staging = self.staging.lock().await;
+            staging.get_mut(&peer).map(|q| q.drain()).unwrap_or_default()
+        };
+        for packet in staged {
+            self.submit(peer, packet.payload).await?;
+        }
+        Ok(())
+    }
+
+    /// Mark `peer` as unreachable; subsequent sends to it are staged instead
+    /// of submitted to the worker pool.
+    pub async fn mark_disconnected(&self, peer: SocketAddr) {
+        self.connected.lock().await.remove(&peer);
+    }
+
+    async fn submit(&self, peer: SocketAddr, payload: Vec<u8>) -> Result<()> {
+        let sequence = {
+            let mut sequences = self.next_sequence.lock().await;
+            let counter = sequences.entry(peer).or_insert(0);
+            let seq = *counter;
+            *counter += 1;
+            seq
+        };
+        self.pool.submit(peer, sequence, payload).await
+    }
+
+    /// Current staging queue depth and cumulative drop count for `peer`,
+    /// suitable for feeding into [`PeerMetrics`].
+    pub async fn staging_stats(&self, peer: &SocketAddr) -> (usize, u64) {
+        let staging = self.staging.lock().await;
+        staging
+            .get(peer)
+            .map(|q| (q.packets.len(), q.dropped))
+            .unwrap_or((0, 0))
+    }
 }
\ No newline at end of file
@@ -25,7 +25,7 @@ pub use content_publisher::*;
 pub use content_service::*;
 pub use types::*;
 pub use client::Web4Client;
-pub use trust::{TrustConfig, TrustDb, TrustAnchor, TrustPolicy, TrustAuditEntry, ZhtpTrustVerifier};
+pub use trust::{TrustConfig, TrustDb, TrustAnchor, TrustPolicy, TrustAuditEntry, BlockedNode, ZhtpTrustVerifier, SystemRootImportReport, SystemRootImportFailure, TrustBundle, BundleConflict, BundleMergeReport, LoadIssue, issue_grant, redeem_grant};
 
 use anyhow::Result;
 use crate::dht::ZkDHTIntegration;
@@ -37,6 +37,10 @@ pub enum TrustPolicy {
     Tofu,
     /// Bootstrap mode (dev only, no persistence)
     Bootstrap,
+    /// Imported from the platform's native certificate store
+    SystemRoot,
+    /// Installed by redeeming a signed, time-bounded delegation grant
+    Delegated,
 }
 
 /// Trust anchor entry for a node
@@ -56,6 +60,31 @@ pub struct TrustAnchor {
     pub last_seen: u64,
     /// Trust policy
     pub policy: TrustPolicy,
+    /// Expiry timestamp for time-bounded anchors (e.g. delegated grants).
+    /// `None` means the anchor never expires on its own.
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+impl TrustAnchor {
+    /// Whether this anchor's `expires_at` is set and has passed `now`
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.map(|exp| exp <= now).unwrap_or(false)
+    }
+}
+
+/// A permanently blocked node, recorded so that a future connection attempt
+/// is rejected even if the peer re-presents a different key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockedNode {
+    /// Node address (ip:port or hostname:port)
+    pub node_addr: String,
+    /// SPKI SHA-256 hash at time of blocking, if known
+    pub spki_sha256: Option<String>,
+    /// Node DID at time of blocking, if known
+    pub node_did: Option<String>,
+    /// Timestamp the block was recorded
+    pub blocked_at: u64,
 }
 
 /// Trust database for persistent storage
@@ -65,9 +94,31 @@ pub struct TrustDb {
     pub version: u32,
     /// Trust anchors by node address
     pub anchors: HashMap<String, TrustAnchor>,
+    /// Explicitly blocked nodes by node address (deny list)
+    #[serde(default)]
+    pub blocked: HashMap<String, BlockedNode>,
+    /// Explicitly allowed nodes by node address (allow list, short-circuits TOFU)
+    #[serde(default)]
+    pub allowed: std::collections::HashSet<String>,
+}
+
+/// One malformed entry skipped while loading a trustdb or audit log, so a
+/// single corrupt record doesn't abort the whole load
+#[derive(Debug, Clone)]
+pub struct LoadIssue {
+    /// Where the bad entry was found (e.g. `anchors.127.0.0.1:9334`, `line 42`)
+    pub location: String,
+    /// Why it could not be parsed
+    pub error: String,
 }
 
 /// Audit log entry for TOFU acceptance
+///
+/// Entries are hash-chained: `entry_hash = hash(prev_hash || canonical
+/// fields)`, with the first entry in a log chaining from
+/// [`TrustAuditEntry::genesis_hash`]. Recomputing the chain (see
+/// [`TrustAuditEntry::verify`]) detects insertion, deletion, or mutation of
+/// any line in an append-only audit file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrustAuditEntry {
     pub timestamp: u64,
@@ -75,6 +126,91 @@ pub struct TrustAuditEntry {
     pub node_did: Option<String>,
     pub spki_sha256: String,
     pub tool_version: String,
+    /// Hash of the previous entry in the chain
+    pub prev_hash: String,
+    /// Hash of this entry's fields, chained from `prev_hash`
+    pub entry_hash: String,
+}
+
+/// Fields hashed into an audit entry's `entry_hash`, excluding the hashes
+/// themselves
+#[derive(Serialize)]
+struct TrustAuditEntryFields<'a> {
+    timestamp: u64,
+    node_addr: &'a str,
+    node_did: &'a Option<String>,
+    spki_sha256: &'a str,
+    tool_version: &'a str,
+}
+
+impl TrustAuditEntry {
+    /// Build a new entry chained from `prev_hash`
+    pub fn new(
+        timestamp: u64,
+        node_addr: String,
+        node_did: Option<String>,
+        spki_sha256: String,
+        tool_version: String,
+        prev_hash: String,
+    ) -> Self {
+        let entry_hash = Self::compute_hash(
+            &prev_hash, timestamp, &node_addr, &node_did, &spki_sha256, &tool_version,
+        );
+        Self {
+            timestamp,
+            node_addr,
+            node_did,
+            spki_sha256,
+            tool_version,
+            prev_hash,
+            entry_hash,
+        }
+    }
+
+    /// Seed hash the first entry in a chain chains from
+    pub fn genesis_hash() -> String {
+        hex::encode([0u8; 32])
+    }
+
+    fn compute_hash(
+        prev_hash: &str,
+        timestamp: u64,
+        node_addr: &str,
+        node_did: &Option<String>,
+        spki_sha256: &str,
+        tool_version: &str,
+    ) -> String {
+        let canonical = serde_json::to_vec(&TrustAuditEntryFields {
+            timestamp,
+            node_addr,
+            node_did,
+            spki_sha256,
+            tool_version,
+        })
+        .expect("audit entry fields are always serializable");
+
+        let mut input = prev_hash.as_bytes().to_vec();
+        input.extend_from_slice(&canonical);
+        hex::encode(lib_crypto::hash_blake3(&input))
+    }
+
+    /// Recompute this entry's hash and confirm it both chains from
+    /// `expected_prev_hash` and matches the stored `entry_hash`
+    pub fn verify(&self, expected_prev_hash: &str) -> bool {
+        if self.prev_hash != expected_prev_hash {
+            return false;
+        }
+
+        let recomputed = Self::compute_hash(
+            &self.prev_hash,
+            self.timestamp,
+            &self.node_addr,
+            &self.node_did,
+            &self.spki_sha256,
+            &self.tool_version,
+        );
+        recomputed == self.entry_hash
+    }
 }
 
 impl TrustDb {
@@ -83,23 +219,85 @@ impl TrustDb {
         Self {
             version: 1,
             anchors: HashMap::new(),
+            blocked: HashMap::new(),
+            allowed: std::collections::HashSet::new(),
         }
     }
 
     /// Load from file, or create new if not exists
+    ///
+    /// A corrupt individual anchor or blocked-node entry is skipped and
+    /// logged rather than aborting the whole load; only an unparseable
+    /// document (invalid JSON) is a hard error.
     pub fn load_or_create(path: &Path) -> Result<Self> {
         if path.exists() {
             Self::validate_permissions(path)?;
             let data = std::fs::read_to_string(path)
                 .context("Failed to read trustdb")?;
-            let db: TrustDb = serde_json::from_str(&data)
+            let (mut db, issues) = Self::parse_lenient(&data)
                 .context("Failed to parse trustdb")?;
+            for issue in &issues {
+                warn!("Skipped corrupt trustdb entry at {}: {}", issue.location, issue.error);
+            }
+            db.prune_expired();
             Ok(db)
         } else {
             Ok(Self::new())
         }
     }
 
+    /// Parse a trustdb document, skipping (and reporting) individual
+    /// `anchors`/`blocked` entries that fail to deserialize instead of
+    /// failing the whole load. The document itself must still be valid JSON.
+    fn parse_lenient(data: &str) -> Result<(Self, Vec<LoadIssue>)> {
+        let value: serde_json::Value = serde_json::from_str(data)
+            .context("Not valid JSON")?;
+        let mut issues = Vec::new();
+
+        let version = value.get("version").and_then(|v| v.as_u64()).unwrap_or(1) as u32;
+
+        let mut anchors = HashMap::new();
+        if let Some(map) = value.get("anchors").and_then(|v| v.as_object()) {
+            for (addr, raw) in map {
+                match serde_json::from_value::<TrustAnchor>(raw.clone()) {
+                    Ok(anchor) => {
+                        anchors.insert(addr.clone(), anchor);
+                    }
+                    Err(e) => issues.push(LoadIssue {
+                        location: format!("anchors.{}", addr),
+                        error: e.to_string(),
+                    }),
+                }
+            }
+        }
+
+        let mut blocked = HashMap::new();
+        if let Some(map) = value.get("blocked").and_then(|v| v.as_object()) {
+            for (addr, raw) in map {
+                match serde_json::from_value::<BlockedNode>(raw.clone()) {
+                    Ok(entry) => {
+                        blocked.insert(addr.clone(), entry);
+                    }
+                    Err(e) => issues.push(LoadIssue {
+                        location: format!("blocked.{}", addr),
+                        error: e.to_string(),
+                    }),
+                }
+            }
+        }
+
+        let allowed = value
+            .get("allowed")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+
+        Ok((
+            Self { version, anchors, blocked, allowed },
+            issues,
+        ))
+    }
+
     /// Save to file
     pub fn save(&self, path: &Path) -> Result<()> {
         // Ensure parent directory exists
@@ -119,13 +317,26 @@ impl TrustDb {
         Ok(())
     }
 
-    /// Append audit entry
-    pub fn append_audit_entry(path: &Path, entry: &TrustAuditEntry) -> Result<()> {
+    /// Append a hash-chained audit entry, linking it from the previous
+    /// entry's hash (or the genesis seed if the log is empty or missing)
+    pub fn append_audit_entry(
+        path: &Path,
+        timestamp: u64,
+        node_addr: String,
+        node_did: Option<String>,
+        spki_sha256: String,
+        tool_version: String,
+    ) -> Result<TrustAuditEntry> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        let line = serde_json::to_string(entry)? + "\n";
+        let prev_hash = Self::last_audit_entry_hash(path)?;
+        let entry = TrustAuditEntry::new(
+            timestamp, node_addr, node_did, spki_sha256, tool_version, prev_hash,
+        );
+
+        let line = serde_json::to_string(&entry)? + "\n";
         std::fs::OpenOptions::new()
             .create(true)
             .append(true)
@@ -144,7 +355,25 @@ impl TrustDb {
             std::fs::set_permissions(path, perms)?;
         }
 
-        Ok(())
+        Ok(entry)
+    }
+
+    /// Hash of the last entry in the audit log at `path`, or the genesis
+    /// seed if the log is empty or doesn't exist yet
+    fn last_audit_entry_hash(path: &Path) -> Result<String> {
+        if !path.exists() {
+            return Ok(TrustAuditEntry::genesis_hash());
+        }
+
+        let data = std::fs::read_to_string(path).context("Failed to read audit log")?;
+        match data.lines().rev().find(|line| !line.trim().is_empty()) {
+            Some(line) => {
+                let entry: TrustAuditEntry = serde_json::from_str(line)
+                    .context("Failed to parse last audit entry while chaining")?;
+                Ok(entry.entry_hash)
+            }
+            None => Ok(TrustAuditEntry::genesis_hash()),
+        }
     }
 
     /// Validate permissions on trustdb (fail closed in production)
@@ -191,6 +420,354 @@ impl TrustDb {
     pub fn remove(&mut self, node_addr: &str) -> Option<TrustAnchor> {
         self.anchors.remove(node_addr)
     }
+
+    /// Drop anchors whose `expires_at` has already passed, so a time-bounded
+    /// delegated anchor doesn't outlive the grant that installed it
+    pub fn prune_expired(&mut self) {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.anchors.retain(|_, anchor| !anchor.is_expired(now));
+    }
+
+    /// Permanently block a node. Captures the SPKI/DID of any existing
+    /// anchor so the block still applies if the peer later re-presents a
+    /// different key, and clears the node from the allow list.
+    pub fn block(&mut self, node_addr: &str) {
+        let (spki_sha256, node_did) = self
+            .anchors
+            .get(node_addr)
+            .map(|anchor| (Some(anchor.spki_sha256.clone()), anchor.node_did.clone()))
+            .unwrap_or((None, None));
+
+        let blocked_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        self.blocked.insert(
+            node_addr.to_string(),
+            BlockedNode {
+                node_addr: node_addr.to_string(),
+                spki_sha256,
+                node_did,
+                blocked_at,
+            },
+        );
+        self.allowed.remove(node_addr);
+    }
+
+    /// Remove a node from the block list
+    pub fn unblock(&mut self, node_addr: &str) -> Option<BlockedNode> {
+        self.blocked.remove(node_addr)
+    }
+
+    /// Whether `node_addr` is on the block list
+    pub fn is_blocked(&self, node_addr: &str) -> bool {
+        self.blocked.contains_key(node_addr)
+    }
+
+    /// Add a node to the allow list, short-circuiting trust-on-first-use
+    /// for it. Clears any existing block.
+    pub fn allow(&mut self, node_addr: &str) {
+        self.blocked.remove(node_addr);
+        self.allowed.insert(node_addr.to_string());
+    }
+
+    /// Remove a node from the allow list
+    pub fn disallow(&mut self, node_addr: &str) -> bool {
+        self.allowed.remove(node_addr)
+    }
+
+    /// Whether `node_addr` is on the allow list
+    pub fn is_allowed(&self, node_addr: &str) -> bool {
+        self.allowed.contains(node_addr)
+    }
+
+    /// Seed `anchors` from the platform's native certificate store, parsing
+    /// each certificate's SubjectPublicKeyInfo into the same SPKI-SHA256
+    /// used elsewhere in the trustdb. Per-certificate parse failures are
+    /// collected into the returned report instead of aborting the import,
+    /// so a handful of malformed system certs don't block the rest.
+    pub fn import_system_roots(&mut self) -> Result<SystemRootImportReport> {
+        let native = rustls_native_certs::load_native_certs();
+        let mut report = SystemRootImportReport::default();
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        for (index, cert) in native.certs.into_iter().enumerate() {
+            match compute_spki_hash(&cert) {
+                Ok(spki_sha256) => {
+                    let fingerprint = compute_fingerprint(&cert);
+                    let node_addr = format!("system-root:{}", spki_sha256);
+                    self.anchors.insert(
+                        node_addr.clone(),
+                        TrustAnchor {
+                            node_addr,
+                            node_did: None,
+                            spki_sha256,
+                            cert_fingerprint: fingerprint,
+                            first_seen: now,
+                            last_seen: now,
+                            policy: TrustPolicy::SystemRoot,
+                            expires_at: None,
+                        },
+                    );
+                    report.imported += 1;
+                }
+                Err(e) => {
+                    report.failures.push(SystemRootImportFailure {
+                        index,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        for err in native.errors {
+            report.failures.push(SystemRootImportFailure {
+                index: usize::MAX,
+                error: err.to_string(),
+            });
+        }
+
+        Ok(report)
+    }
+
+    /// Verify `bundle`'s signature and merge its anchors in. Anchors whose
+    /// SPKI disagrees with an existing anchor for the same node address are
+    /// skipped and reported as conflicts rather than overwriting the local
+    /// entry. Returns an error without modifying `self` if the bundle's
+    /// signature does not verify.
+    pub fn import_bundle(&mut self, bundle: &TrustBundle) -> Result<BundleMergeReport> {
+        if !bundle.verify()? {
+            return Err(anyhow!("Trust bundle signature verification failed"));
+        }
+
+        let mut report = BundleMergeReport::default();
+        for anchor in &bundle.anchors {
+            if let Some(existing) = self.anchors.get(&anchor.node_addr) {
+                if existing.spki_sha256 != anchor.spki_sha256 {
+                    report.conflicts.push(BundleConflict {
+                        node_addr: anchor.node_addr.clone(),
+                        existing_spki_sha256: existing.spki_sha256.clone(),
+                        incoming_spki_sha256: anchor.spki_sha256.clone(),
+                    });
+                    continue;
+                }
+            }
+            self.anchors.insert(anchor.node_addr.clone(), anchor.clone());
+            report.merged += 1;
+        }
+
+        Ok(report)
+    }
+}
+
+/// One certificate that could not be imported from the system root store
+#[derive(Debug, Clone)]
+pub struct SystemRootImportFailure {
+    /// Index of the offending certificate in the platform store's
+    /// enumeration order, or `usize::MAX` if the platform loader itself
+    /// reported the error rather than a specific certificate
+    pub index: usize,
+    /// Description of why the certificate could not be parsed
+    pub error: String,
+}
+
+/// Outcome of importing OS-native trust anchors into a `TrustDb`
+#[derive(Debug, Clone, Default)]
+pub struct SystemRootImportReport {
+    /// Number of certificates successfully imported as anchors
+    pub imported: usize,
+    /// Per-certificate failures collected instead of aborting the import
+    pub failures: Vec<SystemRootImportFailure>,
+}
+
+/// Portable, signed set of trust anchors for distributing a curated trust
+/// list across a fleet of nodes. The signature covers `anchors` and
+/// `created_at` together, so a bundle is verified or rejected as a whole
+/// rather than anchor-by-anchor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustBundle {
+    /// Anchors included in this bundle
+    pub anchors: Vec<TrustAnchor>,
+    /// Timestamp the bundle was signed
+    pub created_at: u64,
+    /// Signature over the bundle's fields, carrying the signer's public key
+    pub signature: lib_crypto::Signature,
+}
+
+/// Fields covered by a bundle's signature, excluding the signature itself
+#[derive(Serialize)]
+struct TrustBundleFields<'a> {
+    anchors: &'a [TrustAnchor],
+    created_at: u64,
+}
+
+impl TrustBundle {
+    /// Sign every anchor currently in `db` with `keypair`, producing a
+    /// bundle that another node can verify and merge
+    pub fn sign(db: &TrustDb, keypair: &lib_crypto::KeyPair) -> Result<Self> {
+        let anchors: Vec<TrustAnchor> = db.anchors.values().cloned().collect();
+        let created_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let payload = Self::canonical_payload(&anchors, created_at);
+        let signature = keypair.sign(&payload)?;
+
+        Ok(Self { anchors, created_at, signature })
+    }
+
+    /// Recompute the canonical payload and check it against the embedded
+    /// signature's own public key
+    pub fn verify(&self) -> Result<bool> {
+        let payload = Self::canonical_payload(&self.anchors, self.created_at);
+        self.signature.public_key.verify(&payload, &self.signature)
+    }
+
+    fn canonical_payload(anchors: &[TrustAnchor], created_at: u64) -> Vec<u8> {
+        serde_json::to_vec(&TrustBundleFields { anchors, created_at })
+            .expect("trust bundle fields are always serializable")
+    }
+}
+
+/// A conflict found while merging a bundle: an incoming anchor's SPKI
+/// disagrees with an anchor already on file for the same node address
+#[derive(Debug, Clone)]
+pub struct BundleConflict {
+    /// Node address the conflicting anchors share
+    pub node_addr: String,
+    /// SPKI hash already trusted locally
+    pub existing_spki_sha256: String,
+    /// SPKI hash the bundle proposed instead
+    pub incoming_spki_sha256: String,
+}
+
+/// Outcome of merging a signed bundle into a `TrustDb`
+#[derive(Debug, Clone, Default)]
+pub struct BundleMergeReport {
+    /// Number of anchors adopted from the bundle
+    pub merged: usize,
+    /// Anchors skipped because they disagreed with an existing one
+    pub conflicts: Vec<BundleConflict>,
+}
+
+/// JWT-style header for a trust delegation grant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GrantHeader {
+    alg: String,
+    typ: String,
+}
+
+/// JWT-style claims for a trust delegation grant. `signer` carries the
+/// issuer's public key so the redeeming node can verify provenance without
+/// needing it configured out-of-band.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GrantClaims {
+    /// Node address the grant vouches for
+    sub: String,
+    /// SPKI SHA-256 fingerprint being vouched for
+    spki: String,
+    /// Issued-at timestamp
+    iat: u64,
+    /// Expiry timestamp
+    exp: u64,
+    /// Public key of the node issuing the grant
+    signer: lib_crypto::PublicKey,
+}
+
+/// Issue a signed, expiring trust-delegation token for `node_addr`/`spki`,
+/// valid for `ttl_secs` from now. The token is a JWT-style
+/// `header.claims.signature` string (each part base64-standard encoded),
+/// signed with `keypair`'s Dilithium key over `header.claims`.
+pub fn issue_grant(
+    node_addr: &str,
+    spki_sha256: &str,
+    ttl_secs: u64,
+    keypair: &lib_crypto::KeyPair,
+) -> Result<String> {
+    use base64::Engine as _;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let header = GrantHeader { alg: "Dilithium2".to_string(), typ: "ZHTP-Grant".to_string() };
+    let claims = GrantClaims {
+        sub: node_addr.to_string(),
+        spki: spki_sha256.to_string(),
+        iat: now,
+        exp: now + ttl_secs,
+        signer: keypair.public_key.clone(),
+    };
+
+    let header_b64 = base64::engine::general_purpose::STANDARD.encode(serde_json::to_vec(&header)?);
+    let claims_b64 = base64::engine::general_purpose::STANDARD.encode(serde_json::to_vec(&claims)?);
+    let signing_input = format!("{}.{}", header_b64, claims_b64);
+    let signature = keypair.sign(signing_input.as_bytes())?;
+    let sig_b64 = base64::engine::general_purpose::STANDARD.encode(&signature.signature);
+
+    Ok(format!("{}.{}.{}", header_b64, claims_b64, sig_b64))
+}
+
+/// Validate a grant token's signature and expiry, returning the time-bounded
+/// anchor it authorizes. Rejects tokens with a bad signature or a past
+/// `exp`, without needing the issuer's key configured locally.
+pub fn redeem_grant(token: &str) -> Result<TrustAnchor> {
+    use base64::Engine as _;
+
+    let parts: Vec<&str> = token.split('.').collect();
+    let [header_b64, claims_b64, sig_b64] = parts[..] else {
+        return Err(anyhow!("Malformed grant token: expected header.claims.signature"));
+    };
+
+    let claims_bytes = base64::engine::general_purpose::STANDARD
+        .decode(claims_b64)
+        .context("Failed to decode grant claims")?;
+    let claims: GrantClaims = serde_json::from_slice(&claims_bytes)
+        .context("Failed to parse grant claims")?;
+
+    let signature_bytes = base64::engine::general_purpose::STANDARD
+        .decode(sig_b64)
+        .context("Failed to decode grant signature")?;
+    let signing_input = format!("{}.{}", header_b64, claims_b64);
+    let signature = lib_crypto::Signature {
+        signature: signature_bytes,
+        public_key: claims.signer.clone(),
+        algorithm: lib_crypto::SignatureAlgorithm::Dilithium2,
+        timestamp: claims.iat,
+    };
+
+    if !claims.signer.verify(signing_input.as_bytes(), &signature)? {
+        return Err(anyhow!("Grant token signature verification failed"));
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    if claims.exp <= now {
+        return Err(anyhow!("Grant token for {} expired at {}", claims.sub, claims.exp));
+    }
+
+    Ok(TrustAnchor {
+        node_addr: claims.sub,
+        node_did: None,
+        spki_sha256: claims.spki,
+        cert_fingerprint: String::new(),
+        first_seen: now,
+        last_seen: now,
+        policy: TrustPolicy::Delegated,
+        expires_at: Some(claims.exp),
+    })
 }
 
 /// Trust configuration for a connection
@@ -284,6 +861,26 @@ pub struct TlsVerificationResult {
     pub tofu_accepted: bool,
 }
 
+/// Compute SPKI hash from certificate (no fallback)
+///
+/// Fails if SPKI cannot be extracted, per security requirements.
+fn compute_spki_hash(cert: &CertificateDer<'_>) -> Result<String> {
+    let (_, parsed_cert) = X509Certificate::from_der(cert.as_ref())
+        .map_err(|e| anyhow!("Failed to parse X.509 certificate for SPKI extraction: {}", e))?;
+
+    // DER-encoded SubjectPublicKeyInfo
+    let spki_bytes = parsed_cert.public_key().raw;
+
+    let hash = lib_crypto::hash_blake3(spki_bytes);
+    Ok(hex::encode(hash))
+}
+
+/// Compute certificate fingerprint for display (full cert hash)
+fn compute_fingerprint(cert: &CertificateDer<'_>) -> String {
+    let hash = lib_crypto::hash_blake3(cert.as_ref());
+    hex::encode(&hash[..16])
+}
+
 /// ZHTP Node Certificate Verifier
 ///
 /// Implements the trust model:
@@ -327,25 +924,6 @@ impl ZhtpTrustVerifier {
         self.result.read().ok()?.clone()
     }
 
-    /// Compute SPKI hash from certificate (no fallback)
-    ///
-    /// Fails if SPKI cannot be extracted, per security requirements.
-    fn compute_spki_hash(cert: &CertificateDer<'_>) -> Result<String> {
-        let (_, parsed_cert) = X509Certificate::from_der(cert.as_ref())
-            .map_err(|e| anyhow!("Failed to parse X.509 certificate for SPKI extraction: {}", e))?;
-
-        // DER-encoded SubjectPublicKeyInfo
-        let spki_bytes = parsed_cert.public_key().raw;
-
-        let hash = lib_crypto::hash_blake3(spki_bytes);
-        Ok(hex::encode(hash))
-    }
-
-    /// Compute certificate fingerprint for display (full cert hash)
-    fn compute_fingerprint(cert: &CertificateDer<'_>) -> String {
-        let hash = lib_crypto::hash_blake3(cert.as_ref());
-        hex::encode(&hash[..16])
-    }
 
     /// Store TOFU anchor after successful verification
     fn store_tofu_anchor(&self, spki_hash: &str, fingerprint: &str) -> Result<()> {
@@ -362,6 +940,7 @@ impl ZhtpTrustVerifier {
             first_seen: now,
             last_seen: now,
             policy: TrustPolicy::Tofu,
+            expires_at: None,
         };
 
         {
@@ -379,15 +958,16 @@ impl ZhtpTrustVerifier {
 
         // Append audit log entry
         let audit_path = self.config.audit_log_path.clone().unwrap_or_else(|| TrustConfig::default_audit_path());
-        if self.config.allow_tofu || self.config.bootstrap_mode {
-            let entry = TrustAuditEntry {
-                timestamp: now,
-                node_addr: self.node_addr.clone(),
-                node_did: None,
-                spki_sha256: spki_hash.to_string(),
-                tool_version: env!("CARGO_PKG_VERSION").to_string(),
-            };
-            if let Err(e) = TrustDb::append_audit_entry(&audit_path, &entry) {
+        let allow_listed = self.trustdb.read().map(|db| db.is_allowed(&self.node_addr)).unwrap_or(false);
+        if self.config.allow_tofu || self.config.bootstrap_mode || allow_listed {
+            if let Err(e) = TrustDb::append_audit_entry(
+                &audit_path,
+                now,
+                self.node_addr.clone(),
+                None,
+                spki_hash.to_string(),
+                env!("CARGO_PKG_VERSION").to_string(),
+            ) {
                 warn!("Failed to append TOFU audit log: {}", e);
             }
         }
@@ -470,9 +1050,9 @@ impl ServerCertVerifier for ZhtpTrustVerifier {
         _ocsp_response: &[u8],
         _now: UnixTime,
     ) -> std::result::Result<ServerCertVerified, TlsError> {
-        let spki_hash = Self::compute_spki_hash(end_entity)
+        let spki_hash = compute_spki_hash(end_entity)
             .map_err(|e| TlsError::General(format!("{}", e).into()))?;
-        let fingerprint = Self::compute_fingerprint(end_entity);
+        let fingerprint = compute_fingerprint(end_entity);
 
         debug!(
             server_name = ?server_name,
@@ -481,6 +1061,20 @@ impl ServerCertVerifier for ZhtpTrustVerifier {
             "Verifying ZHTP node certificate"
         );
 
+        // 0. Check block list (rejected even if the peer re-presents a new key)
+        if let Ok(db) = self.trustdb.read() {
+            if db.is_blocked(&self.node_addr) {
+                warn!(
+                    node = %self.node_addr,
+                    "Rejected certificate: node is on the trust block list"
+                );
+                return Err(TlsError::General(format!(
+                    "Node {} is explicitly blocked. Unblock with: zhtp trust allow {}",
+                    self.node_addr, self.node_addr
+                ).into()));
+            }
+        }
+
         // 1. Check explicit pin
         if let Some(ref pin) = self.config.pin_spki {
             if &spki_hash == pin {
@@ -531,8 +1125,9 @@ impl ServerCertVerifier for ZhtpTrustVerifier {
             }
         }
 
-        // 3. Check TOFU
-        if self.config.allow_tofu {
+        // 3. Check TOFU (the allow list short-circuits this even without --tofu)
+        let allow_listed = self.trustdb.read().map(|db| db.is_allowed(&self.node_addr)).unwrap_or(false);
+        if self.config.allow_tofu || allow_listed {
             // Print fingerprint prominently for user awareness
             warn!("╔══════════════════════════════════════════════════════════════╗");
             warn!("║  TOFU: Trusting certificate on first use                     ║");
@@ -654,6 +1249,7 @@ mod tests {
             first_seen: 1234567890,
             last_seen: 1234567890,
             policy: TrustPolicy::Pinned,
+            expires_at: None,
         };
 
         db.set(anchor.clone());
@@ -678,4 +1274,260 @@ mod tests {
         let config = TrustConfig::bootstrap();
         assert!(config.bootstrap_mode);
     }
+
+    #[test]
+    fn test_block_records_spki_and_did_from_existing_anchor() {
+        let mut db = TrustDb::new();
+        let anchor = TrustAnchor {
+            node_addr: "127.0.0.1:9334".to_string(),
+            node_did: Some("did:zhtp:abc123".to_string()),
+            spki_sha256: "base64hash".to_string(),
+            cert_fingerprint: "abcd1234".to_string(),
+            first_seen: 1234567890,
+            last_seen: 1234567890,
+            policy: TrustPolicy::Tofu,
+            expires_at: None,
+        };
+        db.set(anchor);
+
+        db.block("127.0.0.1:9334");
+        assert!(db.is_blocked("127.0.0.1:9334"));
+
+        let blocked = db.blocked.get("127.0.0.1:9334").unwrap();
+        assert_eq!(blocked.spki_sha256.as_deref(), Some("base64hash"));
+        assert_eq!(blocked.node_did.as_deref(), Some("did:zhtp:abc123"));
+    }
+
+    #[test]
+    fn test_block_and_allow_are_mutually_exclusive() {
+        let mut db = TrustDb::new();
+
+        db.allow("127.0.0.1:9334");
+        assert!(db.is_allowed("127.0.0.1:9334"));
+
+        db.block("127.0.0.1:9334");
+        assert!(db.is_blocked("127.0.0.1:9334"));
+        assert!(!db.is_allowed("127.0.0.1:9334"));
+
+        db.allow("127.0.0.1:9334");
+        assert!(db.is_allowed("127.0.0.1:9334"));
+        assert!(!db.is_blocked("127.0.0.1:9334"));
+    }
+
+    #[test]
+    fn test_audit_entry_chain_verifies() {
+        let genesis = TrustAuditEntry::genesis_hash();
+        let first = TrustAuditEntry::new(
+            1_000, "127.0.0.1:9334".to_string(), None, "hash1".to_string(), "1.0".to_string(), genesis.clone(),
+        );
+        assert!(first.verify(&genesis));
+
+        let second = TrustAuditEntry::new(
+            2_000, "127.0.0.1:9335".to_string(), None, "hash2".to_string(), "1.0".to_string(), first.entry_hash.clone(),
+        );
+        assert!(second.verify(&first.entry_hash));
+        assert!(!second.verify(&genesis));
+    }
+
+    #[test]
+    fn test_audit_entry_tamper_detected() {
+        let genesis = TrustAuditEntry::genesis_hash();
+        let mut entry = TrustAuditEntry::new(
+            1_000, "127.0.0.1:9334".to_string(), None, "hash1".to_string(), "1.0".to_string(), genesis.clone(),
+        );
+        assert!(entry.verify(&genesis));
+
+        entry.spki_sha256 = "tampered".to_string();
+        assert!(!entry.verify(&genesis));
+    }
+
+    #[test]
+    fn test_system_root_import_report_defaults_empty() {
+        let report = SystemRootImportReport::default();
+        assert_eq!(report.imported, 0);
+        assert!(report.failures.is_empty());
+    }
+
+    #[test]
+    fn test_bundle_sign_and_verify_round_trips() {
+        let keypair = lib_crypto::KeyPair::generate().unwrap();
+        let mut db = TrustDb::new();
+        db.set(TrustAnchor {
+            node_addr: "127.0.0.1:9334".to_string(),
+            node_did: None,
+            spki_sha256: "hash-a".to_string(),
+            cert_fingerprint: "fp-a".to_string(),
+            first_seen: 1,
+            last_seen: 1,
+            policy: TrustPolicy::Tofu,
+            expires_at: None,
+        });
+
+        let bundle = TrustBundle::sign(&db, &keypair).unwrap();
+        assert_eq!(bundle.anchors.len(), 1);
+        assert!(bundle.verify().unwrap());
+    }
+
+    #[test]
+    fn test_import_bundle_rejects_tampered_signature() {
+        let keypair = lib_crypto::KeyPair::generate().unwrap();
+        let mut db = TrustDb::new();
+        db.set(TrustAnchor {
+            node_addr: "127.0.0.1:9334".to_string(),
+            node_did: None,
+            spki_sha256: "hash-a".to_string(),
+            cert_fingerprint: "fp-a".to_string(),
+            first_seen: 1,
+            last_seen: 1,
+            policy: TrustPolicy::Tofu,
+            expires_at: None,
+        });
+        let mut bundle = TrustBundle::sign(&db, &keypair).unwrap();
+        bundle.anchors[0].spki_sha256 = "tampered".to_string();
+
+        let mut importer = TrustDb::new();
+        assert!(importer.import_bundle(&bundle).is_err());
+        assert!(importer.anchors.is_empty());
+    }
+
+    #[test]
+    fn test_import_bundle_reports_spki_conflicts() {
+        let keypair = lib_crypto::KeyPair::generate().unwrap();
+        let mut db = TrustDb::new();
+        db.set(TrustAnchor {
+            node_addr: "127.0.0.1:9334".to_string(),
+            node_did: None,
+            spki_sha256: "hash-new".to_string(),
+            cert_fingerprint: "fp-new".to_string(),
+            first_seen: 1,
+            last_seen: 1,
+            policy: TrustPolicy::Tofu,
+            expires_at: None,
+        });
+        let bundle = TrustBundle::sign(&db, &keypair).unwrap();
+
+        let mut importer = TrustDb::new();
+        importer.set(TrustAnchor {
+            node_addr: "127.0.0.1:9334".to_string(),
+            node_did: None,
+            spki_sha256: "hash-existing".to_string(),
+            cert_fingerprint: "fp-existing".to_string(),
+            first_seen: 1,
+            last_seen: 1,
+            policy: TrustPolicy::Pinned,
+            expires_at: None,
+        });
+
+        let report = importer.import_bundle(&bundle).unwrap();
+        assert_eq!(report.merged, 0);
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(importer.get("127.0.0.1:9334").unwrap().spki_sha256, "hash-existing");
+    }
+
+    #[test]
+    fn test_parse_lenient_skips_corrupt_anchor_and_keeps_the_rest() {
+        let data = serde_json::json!({
+            "version": 1,
+            "anchors": {
+                "good:1": {
+                    "node_addr": "good:1",
+                    "node_did": null,
+                    "spki_sha256": "hash",
+                    "cert_fingerprint": "fp",
+                    "first_seen": 1,
+                    "last_seen": 1,
+                    "policy": "Tofu",
+                },
+                "bad:1": { "node_addr": "bad:1" },
+            },
+            "blocked": {},
+            "allowed": [],
+        })
+        .to_string();
+
+        let (db, issues) = TrustDb::parse_lenient(&data).unwrap();
+        assert_eq!(db.anchors.len(), 1);
+        assert!(db.anchors.contains_key("good:1"));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location, "anchors.bad:1");
+    }
+
+    #[test]
+    fn test_parse_lenient_rejects_invalid_json() {
+        assert!(TrustDb::parse_lenient("not json").is_err());
+    }
+
+    #[test]
+    fn test_unblock_and_disallow() {
+        let mut db = TrustDb::new();
+
+        db.block("node-a");
+        assert!(db.unblock("node-a").is_some());
+        assert!(!db.is_blocked("node-a"));
+
+        db.allow("node-b");
+        assert!(db.disallow("node-b"));
+        assert!(!db.is_allowed("node-b"));
+    }
+
+    #[test]
+    fn test_grant_round_trips_and_installs_a_delegated_anchor() {
+        let keypair = lib_crypto::KeyPair::generate().unwrap();
+        let token = issue_grant("127.0.0.1:9334", "spki-hash", 3600, &keypair).unwrap();
+
+        let anchor = redeem_grant(&token).unwrap();
+        assert_eq!(anchor.node_addr, "127.0.0.1:9334");
+        assert_eq!(anchor.spki_sha256, "spki-hash");
+        assert_eq!(anchor.policy, TrustPolicy::Delegated);
+        assert!(anchor.expires_at.is_some());
+    }
+
+    #[test]
+    fn test_redeem_grant_rejects_tampered_claims() {
+        let keypair = lib_crypto::KeyPair::generate().unwrap();
+        let token = issue_grant("127.0.0.1:9334", "spki-hash", 3600, &keypair).unwrap();
+        let mut parts: Vec<&str> = token.split('.').collect();
+        let tampered = parts.remove(1).replace('A', "B");
+        parts.insert(1, &tampered);
+        let tampered_token = parts.join(".");
+
+        assert!(redeem_grant(&tampered_token).is_err());
+    }
+
+    #[test]
+    fn test_redeem_grant_rejects_expired_token() {
+        let keypair = lib_crypto::KeyPair::generate().unwrap();
+        let token = issue_grant("127.0.0.1:9334", "spki-hash", 0, &keypair).unwrap();
+
+        assert!(redeem_grant(&token).is_err());
+    }
+
+    #[test]
+    fn test_prune_expired_drops_only_expired_anchors() {
+        let mut db = TrustDb::new();
+        db.set(TrustAnchor {
+            node_addr: "expired".to_string(),
+            node_did: None,
+            spki_sha256: "hash".to_string(),
+            cert_fingerprint: "fp".to_string(),
+            first_seen: 1,
+            last_seen: 1,
+            policy: TrustPolicy::Delegated,
+            expires_at: Some(1),
+        });
+        db.set(TrustAnchor {
+            node_addr: "permanent".to_string(),
+            node_did: None,
+            spki_sha256: "hash".to_string(),
+            cert_fingerprint: "fp".to_string(),
+            first_seen: 1,
+            last_seen: 1,
+            policy: TrustPolicy::Pinned,
+            expires_at: None,
+        });
+
+        db.prune_expired();
+        assert!(db.get("expired").is_none());
+        assert!(db.get("permanent").is_some());
+    }
 }
@@ -5,6 +5,17 @@
 
 use serde::{Serialize, Deserialize};
 use crate::plonky2::Plonky2Proof;
+use crate::plonky2::{generate_batch_recursive_proof, verify_batch_recursive_proof, RecursiveConfig, RecursiveProof};
+
+/// `proof_system` tag used for a [`ZkProof`] produced by [`ZkProof::aggregate`],
+/// so `verify`/`verify_batch` can recognize it without inspecting `plonky2_proof`
+/// (an aggregated proof has no single leaf `Plonky2Proof` of its own).
+const AGGREGATED_PROOF_SYSTEM: &str = "ZHTP-Aggregated-Recursive";
+
+/// Version tag for an aggregated proof's serialized form; bumped from `"v0"`
+/// since the aggregated encoding stores a serialized [`RecursiveProof`] in
+/// `proof_data` instead of a single `Plonky2Proof`.
+const VERSION_V1_AGGREGATED: &str = "v1-aggregated";
 
 /// Zero-knowledge proof (unified approach matching ZHTPDEV-main65)
 #[derive(Debug, Clone)]
@@ -33,8 +44,13 @@ impl Serialize for ZkProof {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
+        let version = if self.proof_system == AGGREGATED_PROOF_SYSTEM {
+            VERSION_V1_AGGREGATED
+        } else {
+            "v0"
+        };
         let mut state = serializer.serialize_struct("ZkProof", 7)?;
-        state.serialize_field("version", &default_version())?;
+        state.serialize_field("version", &version)?;
         state.serialize_field("proof_system", &self.proof_system)?;
         state.serialize_field("proof_data", &self.proof_data)?;
         state.serialize_field("public_inputs", &self.public_inputs)?;
@@ -66,7 +82,7 @@ impl<'de> Deserialize<'de> for ZkProof {
             tracing::warn!("Missing version field in proof; assuming v0");
             default_version()
         });
-        if version != "v0" {
+        if version != "v0" && version != VERSION_V1_AGGREGATED {
             tracing::warn!("ZkProof version mismatch: {}", version);
         }
 
@@ -175,8 +191,103 @@ impl ZkProof {
         self.verification_key.is_empty()
     }
 
+    /// Aggregate several proofs into a single recursive proof via Plonky2
+    /// recursion (see [`crate::plonky2::recursive`]), so a block of
+    /// transactions can be checked with one [`verify_batch`] call instead of
+    /// verifying each proof individually.
+    ///
+    /// Every input must carry a `plonky2_proof` (no fallbacks, matching
+    /// [`verify`]) and all inputs must share the same `proof_system`, since
+    /// mixing proofs from incompatible circuits into one recursive proof
+    /// would make the result unverifiable against any single circuit.
+    ///
+    /// [`verify`]: ZkProof::verify
+    /// [`verify_batch`]: ZkProof::verify_batch
+    pub fn aggregate(proofs: &[ZkProof]) -> anyhow::Result<ZkProof> {
+        if proofs.is_empty() {
+            return Err(anyhow::anyhow!("Cannot aggregate an empty set of proofs"));
+        }
+
+        let leaves: Vec<Plonky2Proof> = proofs
+            .iter()
+            .map(|p| {
+                p.plonky2_proof.clone().ok_or_else(|| {
+                    anyhow::anyhow!("Proof must use Plonky2 - no fallbacks allowed")
+                })
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        let circuit = &leaves[0].proof_system;
+        if leaves.iter().any(|p| &p.proof_system != circuit) {
+            return Err(anyhow::anyhow!(
+                "Cannot aggregate proofs from incompatible circuit configs: all proofs must use the same proof_system (expected '{}')",
+                circuit
+            ));
+        }
+
+        // Each leaf's verification_key_hash is folded into the aggregated
+        // public inputs so a verifier can confirm exactly which leaf proofs
+        // were rolled up without re-verifying them individually.
+        let key_commitment_inputs: Vec<u64> = leaves
+            .iter()
+            .flat_map(|p| {
+                p.verification_key_hash
+                    .chunks(8)
+                    .map(|chunk| {
+                        let mut buf = [0u8; 8];
+                        buf[..chunk.len()].copy_from_slice(chunk);
+                        u64::from_le_bytes(buf)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let config = RecursiveConfig {
+            batch_size: leaves.len() as u32,
+            ..RecursiveConfig::default()
+        };
+        let mut recursive_proof = generate_batch_recursive_proof(leaves, config)?;
+        recursive_proof.aggregated_inputs.extend(key_commitment_inputs);
+
+        let proof_data = serde_json::to_vec(&recursive_proof)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize aggregated proof: {}", e))?;
+        let public_inputs = recursive_proof
+            .aggregated_inputs
+            .iter()
+            .flat_map(|&x| x.to_le_bytes().to_vec())
+            .collect();
+
+        Ok(ZkProof {
+            proof_system: AGGREGATED_PROOF_SYSTEM.to_string(),
+            proof_data: proof_data.clone(),
+            public_inputs,
+            verification_key: recursive_proof.base_proof.verification_key_hash.to_vec(),
+            plonky2_proof: None,
+            proof: proof_data,
+        })
+    }
+
+    /// Verify an aggregated proof produced by [`ZkProof::aggregate`] by
+    /// checking the single recursive proof it wraps, instead of re-verifying
+    /// each inner leaf proof.
+    pub fn verify_batch(&self) -> anyhow::Result<bool> {
+        if self.proof_system != AGGREGATED_PROOF_SYSTEM {
+            return Err(anyhow::anyhow!(
+                "verify_batch called on a non-aggregated proof (proof_system = {})",
+                self.proof_system
+            ));
+        }
+
+        let recursive_proof: RecursiveProof = serde_json::from_slice(&self.proof_data)
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize aggregated proof: {}", e))?;
+        verify_batch_recursive_proof(&recursive_proof)
+    }
+
     /// Verify this proof using unified ZK system
     pub fn verify(&self) -> anyhow::Result<bool> {
+        if self.proof_system == AGGREGATED_PROOF_SYSTEM {
+            return self.verify_batch();
+        }
         if let Some(ref plonky2_proof) = self.plonky2_proof {
             // Use ZkProofSystem for verification (unified approach)
             let zk_system = crate::plonky2::ZkProofSystem::new()?;
@@ -229,6 +229,120 @@ pub struct AccessControlConfig {
     pub enable_abac: bool,
     /// Access control timeout in seconds
     pub access_timeout_seconds: u64,
+    /// Configured OIDC/OAuth2 identity providers, keyed by provider name
+    pub oidc_providers: HashMap<String, OidcProvider>,
+    /// Argon2id cost parameters for password credential hashing
+    pub password_hashing: PasswordHashConfig,
+    /// When set, access is only granted during this time window; reconfigurable
+    /// at runtime via `AccessController::set_time_window`
+    pub required_time_window: Option<crate::zhtp::access_control::TimeWindow>,
+    /// EWMA reputation scoring parameters
+    pub reputation: ReputationConfig,
+    /// How request hostnames are resolved to IPs before a geo/geofencing lookup
+    pub dns_resolution: DnsResolutionConfig,
+}
+
+/// Which DNS resolution strategy `HostnameResolver` uses to turn a request's
+/// hostname into an IP before a GeoIP lookup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DnsResolverMode {
+    /// Use the OS's configured resolver (`/etc/resolv.conf` and friends)
+    System,
+    /// Query `nameservers` directly over raw UDP, bypassing the OS resolver
+    /// so hostname lookups aren't visible to whatever DNS the host is set to
+    Custom,
+}
+
+/// Configuration for `HostnameResolver`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsResolutionConfig {
+    /// Which resolution strategy to use
+    pub mode: DnsResolverMode,
+    /// Upstream nameserver IPs to query directly, in order, when `mode` is
+    /// `Custom`; ignored when `mode` is `System`
+    pub nameservers: Vec<String>,
+    /// Per-nameserver query timeout in milliseconds
+    pub timeout_ms: u64,
+}
+
+impl Default for DnsResolutionConfig {
+    fn default() -> Self {
+        Self {
+            mode: DnsResolverMode::System,
+            nameservers: vec!["1.1.1.1".to_string(), "9.9.9.9".to_string()],
+            timeout_ms: 2_000,
+        }
+    }
+}
+
+/// Parameters for the EWMA-based reputation scoring engine. Scores live on a
+/// 0-100 scale with 50 treated as the neutral baseline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReputationConfig {
+    /// Smoothing factor applied to each new access outcome (0.0-1.0); higher
+    /// values make the score react more quickly to recent behavior
+    pub alpha: f64,
+    /// Fraction of the gap to the neutral baseline (50) recovered per hour of
+    /// elapsed wall-clock time since the last update, so stale bad marks heal
+    pub decay_per_hour: f64,
+    /// Minimum score required to pass `check_reputation_requirements` when a
+    /// request does not specify its own `X-Minimum-Reputation` threshold
+    pub default_minimum_score: u32,
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        Self {
+            alpha: 0.1,
+            decay_per_hour: 0.05,
+            default_minimum_score: 20,
+        }
+    }
+}
+
+/// Argon2id cost parameters for password credential hashing. Stored hashes
+/// are self-describing PHC strings, so changing these only affects newly
+/// hashed or rehashed passwords - existing hashes keep verifying under the
+/// parameters they were created with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordHashConfig {
+    /// Memory cost in KiB
+    pub m_cost: u32,
+    /// Number of iterations
+    pub t_cost: u32,
+    /// Degree of parallelism (lanes)
+    pub p_cost: u32,
+}
+
+impl Default for PasswordHashConfig {
+    fn default() -> Self {
+        Self {
+            m_cost: 64 * 1024,
+            t_cost: 3,
+            p_cost: 1,
+        }
+    }
+}
+
+/// OIDC/OAuth2 federated identity provider configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcProvider {
+    /// Issuer URL, matched against the `iss` claim of ID tokens
+    pub issuer_url: String,
+    /// Client ID registered with the provider
+    pub client_id: String,
+    /// Client secret used for the authorization code exchange
+    pub client_secret: String,
+    /// Token endpoint the authorization code is POSTed to in exchange for
+    /// an ID token
+    pub token_endpoint: String,
+    /// Redirect URI registered with the provider, echoed back in the
+    /// token exchange request
+    pub redirect_uri: String,
+    /// JWKS endpoint used to fetch signing keys for ID token verification
+    pub jwks_uri: String,
+    /// Scopes requested during the authorization code flow
+    pub scopes: Vec<String>,
 }
 
 /// Access policies
@@ -247,7 +361,7 @@ pub enum AccessPolicy {
 }
 
 /// Authentication methods
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AuthMethod {
     /// API key authentication
     ApiKey,
@@ -261,6 +375,10 @@ pub enum AuthMethod {
     WalletSignature,
     /// Multi-factor authentication
     Mfa,
+    /// OIDC authorization-code federated login
+    Oidc,
+    /// Password/secret credential verified against a stored Argon2id hash
+    Password,
 }
 
 /// Networking configuration
@@ -699,6 +817,11 @@ impl Default for AccessControlConfig {
             enable_rbac: true,
             enable_abac: false,
             access_timeout_seconds: 300, // 5 minutes
+            oidc_providers: HashMap::new(),
+            password_hashing: PasswordHashConfig::default(),
+            required_time_window: None,
+            reputation: ReputationConfig::default(),
+            dns_resolution: DnsResolutionConfig::default(),
         }
     }
 }
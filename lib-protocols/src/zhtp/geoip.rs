@@ -0,0 +1,427 @@
+//! Binary GeoLite2/GeoIP2 (.mmdb) reader
+//!
+//! Implements enough of the MaxMind DB file format to resolve a client IP to
+//! a country/coordinate/ASN record without a network call or an external
+//! MaxMind client library. The format is a binary search tree followed by a
+//! data section, separated by a 16-byte all-zero-then-`0xAB` marker, with a
+//! metadata map trailing the file after a second, content-addressed marker.
+
+use std::collections::HashMap;
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+
+use crate::zhtp::ZhtpResult;
+
+/// Marks the boundary between the search tree and the data section
+const DATA_SECTION_SEPARATOR_SIZE: usize = 16;
+
+/// Marks the start of the trailing metadata section
+const METADATA_MARKER: &[u8] = b"\xab\xcd\xefMaxMind.com";
+
+/// Fields decoded out of a matched data-section record
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GeoIpRecord {
+    /// ISO 3166-1 alpha-2 country code
+    pub country_iso_code: Option<String>,
+    /// Latitude, if the database carries a location block
+    pub latitude: Option<f64>,
+    /// Longitude, if the database carries a location block
+    pub longitude: Option<f64>,
+    /// Autonomous system number, if this is an ASN database
+    pub asn: Option<u32>,
+}
+
+/// Metadata read from the file's trailing metadata map
+#[derive(Debug, Clone)]
+struct MmdbMetadata {
+    node_count: u32,
+    record_size: u16,
+    ip_version: u16,
+}
+
+/// A decoded MaxMind DB value, general enough to cover the handful of types
+/// the search tree and data section can contain
+#[derive(Debug, Clone)]
+enum MmdbValue {
+    Map(HashMap<String, MmdbValue>),
+    String(String),
+    Double(f64),
+    Uint32(u32),
+    Uint16(u32),
+    Int32(i32),
+    Array(Vec<MmdbValue>),
+    Boolean(bool),
+}
+
+/// A loaded GeoLite2/GeoIP2 database, ready for repeated lookups
+pub struct MmdbReader {
+    data: Vec<u8>,
+    metadata: MmdbMetadata,
+    search_tree_size: usize,
+}
+
+impl MmdbReader {
+    /// Load and parse an `.mmdb` file from disk
+    pub fn open(path: &Path) -> ZhtpResult<Self> {
+        let data = fs::read(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read MMDB file {}: {}", path.display(), e))?;
+        let metadata = Self::parse_metadata(&data)?;
+        let record_bits = metadata.record_size as usize * 2;
+        let search_tree_size = metadata.node_count as usize * record_bits / 8;
+
+        Ok(Self { data, metadata, search_tree_size })
+    }
+
+    /// Look up `ip` in the database, returning whatever fields were decoded
+    /// from the matched record
+    pub fn lookup(&self, ip: IpAddr) -> ZhtpResult<Option<GeoIpRecord>> {
+        let bits = Self::ip_bits(ip);
+        let node_count = self.metadata.node_count;
+        let mut node = 0u32;
+
+        for bit in bits {
+            if node >= node_count {
+                break;
+            }
+            let (left, right) = self.read_node(node as usize)?;
+            node = if bit { right } else { left };
+        }
+
+        if node <= node_count {
+            // Tree ended on an interior/unmapped node before a pointer into
+            // the data section was reached - no match for this address
+            return Ok(None);
+        }
+
+        let offset = node as usize - node_count as usize - DATA_SECTION_SEPARATOR_SIZE;
+        let (value, _) = self.decode_value(self.search_tree_size + offset)?;
+        Ok(Some(Self::value_to_record(&value)))
+    }
+
+    /// Read the left/right records of tree node `index`
+    fn read_node(&self, index: usize) -> ZhtpResult<(u32, u32)> {
+        let record_bytes = self.metadata.record_size as usize * 2 / 8;
+        let start = index * record_bytes;
+        let bytes = self.data.get(start..start + record_bytes)
+            .ok_or_else(|| anyhow::anyhow!("MMDB node {} out of bounds", index))?;
+
+        match self.metadata.record_size {
+            24 => {
+                let left = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+                let right = u32::from_be_bytes([0, bytes[3], bytes[4], bytes[5]]);
+                Ok((left, right))
+            }
+            28 => {
+                // 7 bytes: byte3's high nibble extends `left`, low nibble extends `right`
+                let left = (((bytes[3] & 0xF0) as u32) << 20)
+                    | ((bytes[0] as u32) << 16)
+                    | ((bytes[1] as u32) << 8)
+                    | bytes[2] as u32;
+                let right = (((bytes[3] & 0x0F) as u32) << 24)
+                    | ((bytes[4] as u32) << 16)
+                    | ((bytes[5] as u32) << 8)
+                    | bytes[6] as u32;
+                Ok((left, right))
+            }
+            32 => {
+                let left = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                let right = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+                Ok((left, right))
+            }
+            other => Err(anyhow::anyhow!("Unsupported MMDB record size: {}", other)),
+        }
+    }
+
+    /// Split an IP address into its bits, MSB first, matching `ip_version`
+    fn ip_bits(ip: IpAddr) -> Vec<bool> {
+        let octets: Vec<u8> = match ip {
+            IpAddr::V4(v4) => v4.octets().to_vec(),
+            IpAddr::V6(v6) => v6.octets().to_vec(),
+        };
+
+        let mut bits = Vec::with_capacity(octets.len() * 8);
+        for byte in octets {
+            for shift in (0..8).rev() {
+                bits.push((byte >> shift) & 1 == 1);
+            }
+        }
+        bits
+    }
+
+    /// Locate and decode the trailing metadata map
+    fn parse_metadata(data: &[u8]) -> ZhtpResult<MmdbMetadata> {
+        let search_window = data.len().min(128 * 1024);
+        let tail = &data[data.len() - search_window..];
+
+        let marker_pos = tail.windows(METADATA_MARKER.len())
+            .rposition(|w| w == METADATA_MARKER)
+            .ok_or_else(|| anyhow::anyhow!("MMDB metadata marker not found"))?;
+
+        let metadata_start = (data.len() - search_window) + marker_pos + METADATA_MARKER.len();
+        let reader = Self { data: data.to_vec(), metadata: MmdbMetadata { node_count: 0, record_size: 24, ip_version: 4 }, search_tree_size: 0 };
+        let (value, _) = reader.decode_value(metadata_start)?;
+
+        let map = match value {
+            MmdbValue::Map(m) => m,
+            _ => return Err(anyhow::anyhow!("MMDB metadata section is not a map")),
+        };
+
+        let node_count = match map.get("node_count") {
+            Some(MmdbValue::Uint32(v)) => *v,
+            Some(MmdbValue::Uint16(v)) => *v,
+            _ => return Err(anyhow::anyhow!("MMDB metadata missing node_count")),
+        };
+        let record_size = match map.get("record_size") {
+            Some(MmdbValue::Uint16(v)) => *v as u16,
+            Some(MmdbValue::Uint32(v)) => *v as u16,
+            _ => return Err(anyhow::anyhow!("MMDB metadata missing record_size")),
+        };
+        let ip_version = match map.get("ip_version") {
+            Some(MmdbValue::Uint16(v)) => *v as u16,
+            Some(MmdbValue::Uint32(v)) => *v as u16,
+            _ => 4,
+        };
+
+        Ok(MmdbMetadata { node_count, record_size, ip_version })
+    }
+
+    /// Decode the value at byte offset `offset`, returning it and the offset
+    /// just past it
+    fn decode_value(&self, offset: usize) -> ZhtpResult<(MmdbValue, usize)> {
+        let control = *self.data.get(offset)
+            .ok_or_else(|| anyhow::anyhow!("MMDB offset {} out of bounds", offset))?;
+        let mut pos = offset + 1;
+
+        let mut type_id = control >> 5;
+        let mut length = (control & 0x1F) as usize;
+
+        // Extended type byte for type_id 0
+        if type_id == 0 {
+            let extended = *self.data.get(pos)
+                .ok_or_else(|| anyhow::anyhow!("MMDB truncated extended type at {}", pos))?;
+            pos += 1;
+            type_id = extended + 7;
+        }
+
+        // Pointers carry their length bits differently and are resolved
+        // before the rest of the type dispatch
+        if type_id == 1 {
+            return self.decode_pointer(control, pos);
+        }
+
+        if length == 29 {
+            length = 29 + *self.data.get(pos).ok_or_else(|| anyhow::anyhow!("MMDB truncated length"))? as usize;
+            pos += 1;
+        } else if length == 30 {
+            let bytes = self.data.get(pos..pos + 2).ok_or_else(|| anyhow::anyhow!("MMDB truncated length"))?;
+            length = 285 + ((bytes[0] as usize) << 8 | bytes[1] as usize);
+            pos += 2;
+        } else if length == 31 {
+            let bytes = self.data.get(pos..pos + 3).ok_or_else(|| anyhow::anyhow!("MMDB truncated length"))?;
+            length = 65_821 + ((bytes[0] as usize) << 16 | (bytes[1] as usize) << 8 | bytes[2] as usize);
+            pos += 3;
+        }
+
+        match type_id {
+            2 => {
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated string"))?;
+                let s = String::from_utf8_lossy(bytes).to_string();
+                Ok((MmdbValue::String(s), pos + length))
+            }
+            7 => {
+                let mut map = HashMap::new();
+                let mut cursor = pos;
+                for _ in 0..length {
+                    let (key, next) = self.decode_value(cursor)?;
+                    let (val, next2) = self.decode_value(next)?;
+                    let key = match key {
+                        MmdbValue::String(s) => s,
+                        other => format!("{:?}", other),
+                    };
+                    map.insert(key, val);
+                    cursor = next2;
+                }
+                Ok((MmdbValue::Map(map), cursor))
+            }
+            8 => {
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated int32"))?;
+                let mut buf = [0u8; 4];
+                buf[4 - bytes.len()..].copy_from_slice(bytes);
+                Ok((MmdbValue::Int32(i32::from_be_bytes(buf)), pos + length))
+            }
+            4 => {
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated bytes"))?;
+                Ok((MmdbValue::String(format!("{:x?}", bytes)), pos + length))
+            }
+            6 => {
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated uint32"))?;
+                let mut buf = [0u8; 4];
+                buf[4 - bytes.len()..].copy_from_slice(bytes);
+                Ok((MmdbValue::Uint32(u32::from_be_bytes(buf)), pos + length))
+            }
+            5 => {
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated uint16"))?;
+                let mut buf = [0u8; 4];
+                buf[4 - bytes.len()..].copy_from_slice(bytes);
+                Ok((MmdbValue::Uint16(u32::from_be_bytes(buf)), pos + length))
+            }
+            3 => {
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated double"))?;
+                let mut buf = [0u8; 8];
+                buf[8 - bytes.len()..].copy_from_slice(bytes);
+                Ok((MmdbValue::Double(f64::from_be_bytes(buf)), pos + length))
+            }
+            9 => {
+                // Uint64 - truncated to our Uint32 variant since the fields
+                // this reader extracts never need the full 64 bits
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated uint64"))?;
+                let mut buf = [0u8; 4];
+                let low = &bytes[bytes.len().saturating_sub(4)..];
+                buf[4 - low.len()..].copy_from_slice(low);
+                Ok((MmdbValue::Uint32(u32::from_be_bytes(buf)), pos + length))
+            }
+            10 => {
+                // Uint128 - not needed by any field this reader extracts
+                Ok((MmdbValue::String(String::new()), pos + length))
+            }
+            11 => {
+                let mut items = Vec::with_capacity(length);
+                let mut cursor = pos;
+                for _ in 0..length {
+                    let (val, next) = self.decode_value(cursor)?;
+                    items.push(val);
+                    cursor = next;
+                }
+                Ok((MmdbValue::Array(items), cursor))
+            }
+            15 => {
+                let bytes = self.data.get(pos..pos + length).ok_or_else(|| anyhow::anyhow!("MMDB truncated float"))?;
+                let mut buf = [0u8; 4];
+                buf[4 - bytes.len()..].copy_from_slice(bytes);
+                Ok((MmdbValue::Double(f32::from_be_bytes(buf) as f64), pos + length))
+            }
+            14 => Ok((MmdbValue::Boolean(length == 1), pos)),
+            other => Err(anyhow::anyhow!("Unsupported MMDB data type: {}", other)),
+        }
+    }
+
+    /// Decode a pointer record and follow it to the pointed-to value
+    fn decode_pointer(&self, control: u8, pos: usize) -> ZhtpResult<(MmdbValue, usize)> {
+        let size = (control >> 3) & 0x03;
+        let mut pos = pos;
+
+        let pointer_value: usize = match size {
+            0 => {
+                let byte = *self.data.get(pos).ok_or_else(|| anyhow::anyhow!("MMDB truncated pointer"))?;
+                pos += 1;
+                (((control & 0x07) as usize) << 8) | byte as usize
+            }
+            1 => {
+                let bytes = self.data.get(pos..pos + 2).ok_or_else(|| anyhow::anyhow!("MMDB truncated pointer"))?;
+                pos += 2;
+                ((((control & 0x07) as usize) << 16) | ((bytes[0] as usize) << 8) | bytes[1] as usize) + 2048
+            }
+            2 => {
+                let bytes = self.data.get(pos..pos + 3).ok_or_else(|| anyhow::anyhow!("MMDB truncated pointer"))?;
+                pos += 3;
+                ((((control & 0x07) as usize) << 24)
+                    | ((bytes[0] as usize) << 16)
+                    | ((bytes[1] as usize) << 8)
+                    | bytes[2] as usize) + 526_336
+            }
+            _ => {
+                let bytes = self.data.get(pos..pos + 4).ok_or_else(|| anyhow::anyhow!("MMDB truncated pointer"))?;
+                pos += 4;
+                ((bytes[0] as usize) << 24) | ((bytes[1] as usize) << 16) | ((bytes[2] as usize) << 8) | bytes[3] as usize
+            }
+        };
+
+        let (value, _) = self.decode_value(pointer_value)?;
+        Ok((value, pos))
+    }
+
+    /// Flatten a decoded map into the subset of fields `GeoIpRecord` cares
+    /// about, matching GeoLite2's `country`/`location`/`autonomous_system_number` layout
+    fn value_to_record(value: &MmdbValue) -> GeoIpRecord {
+        let mut record = GeoIpRecord::default();
+        let MmdbValue::Map(map) = value else { return record };
+
+        if let Some(MmdbValue::Map(country)) = map.get("country") {
+            if let Some(MmdbValue::String(code)) = country.get("iso_code") {
+                record.country_iso_code = Some(code.clone());
+            }
+        }
+
+        if let Some(MmdbValue::Map(location)) = map.get("location") {
+            if let Some(MmdbValue::Double(lat)) = location.get("latitude") {
+                record.latitude = Some(*lat);
+            }
+            if let Some(MmdbValue::Double(lon)) = location.get("longitude") {
+                record.longitude = Some(*lon);
+            }
+        }
+
+        if let Some(asn) = map.get("autonomous_system_number") {
+            record.asn = match asn {
+                MmdbValue::Uint32(v) => Some(*v),
+                MmdbValue::Uint16(v) => Some(*v),
+                _ => None,
+            };
+        }
+
+        record
+    }
+}
+
+/// Derive the /24 prefix cache key for an IPv4 address (IPv6 addresses are
+/// cached verbatim since prefix aggregation doesn't apply the same way)
+pub fn cache_key_for(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(v4) => {
+            let o = v4.octets();
+            format!("{}.{}.{}.0/24", o[0], o[1], o[2])
+        }
+        IpAddr::V6(v6) => v6.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_cache_key_for_groups_ipv4_by_slash_24() {
+        let a = IpAddr::V4(Ipv4Addr::new(93, 184, 216, 10));
+        let b = IpAddr::V4(Ipv4Addr::new(93, 184, 216, 200));
+        assert_eq!(cache_key_for(a), cache_key_for(b));
+        assert_eq!(cache_key_for(a), "93.184.216.0/24");
+    }
+
+    #[test]
+    fn test_ip_bits_msb_first() {
+        let ip = IpAddr::V4(Ipv4Addr::new(0b1000_0001, 0, 0, 0));
+        let bits = MmdbReader::ip_bits(ip);
+        assert_eq!(bits.len(), 32);
+        assert_eq!(&bits[0..8], &[true, false, false, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn test_decode_value_reads_a_plain_string() {
+        // Control byte: type 2 (String, 0b010) with length 2 -> 0b010_00010 = 0x42
+        let data = vec![0x42, b'U', b'S'];
+        let reader = MmdbReader {
+            data,
+            metadata: MmdbMetadata { node_count: 0, record_size: 24, ip_version: 4 },
+            search_tree_size: 0,
+        };
+
+        let (value, next) = reader.decode_value(0).unwrap();
+        assert_eq!(next, 3);
+        match value {
+            MmdbValue::String(s) => assert_eq!(s, "US"),
+            other => panic!("expected a string, got {:?}", other),
+        }
+    }
+}
@@ -7,6 +7,7 @@
 pub mod server;
 pub mod config;
 pub mod access_control;
+pub mod geoip;
 pub mod routing;
 pub mod content;
 pub mod middleware;
@@ -16,6 +17,7 @@ pub mod session;
 pub use server::{ZhtpServer, ServerState};
 pub use config::ServerConfig;
 pub use access_control::AccessController;
+pub use geoip::{MmdbReader, GeoIpRecord};
 pub use routing::{Router, Route, RouteHandler};
 pub use content::{ZhtpContentManager, ContentConfig, StorageBackend, CompressionType, EncryptionType};
 pub use session::{ZhtpSessionManager, SessionConfig, SessionInfo, AuthMethod, SecurityLevel};
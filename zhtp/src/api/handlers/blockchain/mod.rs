@@ -25,6 +25,13 @@ use lib_blockchain::Blockchain;
 /// added via mesh protocol).
 pub struct BlockchainHandler {
     contract_states: Arc<RwLock<HashMap<String, serde_json::Value>>>,
+    /// Content-addressed snapshot chunks served by `handle_get_snapshot_chunk`
+    /// (supplier side of warp sync), populated the last time
+    /// `handle_get_snapshot_manifest` ran.
+    snapshot_chunks: RwLock<HashMap<String, Vec<u8>>>,
+    /// In-progress snapshot restores driven by `handle_snapshot_restore` and
+    /// friends (requester side of warp sync).
+    snapshot_restores: RwLock<HashMap<String, SnapshotRestoreState>>,
 }
 
 impl BlockchainHandler {
@@ -32,6 +39,8 @@ impl BlockchainHandler {
         // We ignore the passed blockchain reference and always fetch from global provider
         Self {
             contract_states: Arc::new(RwLock::new(HashMap::new())),
+            snapshot_chunks: RwLock::new(HashMap::new()),
+            snapshot_restores: RwLock::new(HashMap::new()),
         }
     }
 
@@ -97,6 +106,28 @@ impl ZhtpRequestHandler for BlockchainHandler {
             (ZhtpMethod::Get, "/api/v1/blockchain/edge-stats") => {
                 self.handle_edge_stats(request).await
             }
+            // Warp/snapshot fast-sync endpoints
+            (ZhtpMethod::Get, "/api/v1/blockchain/snapshot/manifest") => {
+                self.handle_get_snapshot_manifest(request).await
+            }
+            (ZhtpMethod::Get, path) if path.starts_with("/api/v1/blockchain/snapshot/chunk/") => {
+                self.handle_get_snapshot_chunk(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/blockchain/snapshot/restore") => {
+                self.handle_snapshot_restore(request).await
+            }
+            (ZhtpMethod::Get, path)
+                if path.starts_with("/api/v1/blockchain/snapshot/restore/")
+                    && path.ends_with("/status") =>
+            {
+                self.handle_get_snapshot_restore_status(request).await
+            }
+            (ZhtpMethod::Post, path)
+                if path.starts_with("/api/v1/blockchain/snapshot/restore/")
+                    && path.contains("/chunk/") =>
+            {
+                self.handle_snapshot_restore_chunk_ack(request).await
+            }
             (ZhtpMethod::Post, "/api/v1/blockchain/transaction/estimate-fee") => {
                 self.handle_estimate_transaction_fee(request).await
             }
@@ -1752,4 +1783,394 @@ impl BlockchainHandler {
             None,
         ))
     }
+
+    // --- Warp/snapshot fast-sync ----------------------------------------
+    //
+    // The only sync primitives this chain had were `export`/`import` (whole
+    // chain) and `blocks/{start}/{end}` (block-range replay) - a fresh node
+    // always had to either download a full export or replay every block.
+    // This adds a snapshot (warp) sync subsystem split into the same
+    // supplier/requester roles as ethcore-sync: `handle_get_snapshot_manifest`
+    // and `handle_get_snapshot_chunk` are the supplier side (a synced node
+    // answers them from its own state), `handle_snapshot_restore` and friends
+    // are the requester side (a bootstrapping node drives them).
+    //
+    // This chain has no separate Merkle-Patricia state trie distinct from its
+    // UTXO set and on-chain registries, so `state_root` in the manifest is a
+    // blake3 hash over the serialized UTXO set plus identity/wallet/validator
+    // registries - the closest analog this chain has to a state trie root,
+    // not a literal trie digest.
+    //
+    // These routes live under `/api/v1/blockchain/snapshot/*` rather than
+    // `/api/v1/blockchain/sync/snapshot/*`: the latter prefix is already
+    // claimed by `NetworkHandler` (see `unified_server::register_api_handlers`),
+    // and a second, duplicate manifest/chunk/restore surface there would
+    // fork this state machine across two handlers. Completing a restore now
+    // hands off to `runtime::sync_scheduler` (see `handle_snapshot_restore`
+    // and `handle_snapshot_restore_chunk_ack`) so ordinary range sync resumes
+    // from `warp_barrier` forward, and `WarpRestore` is a distinct scheduler
+    // state so metrics history can tell warp-restore activity apart from
+    // ordinary block reception.
+
+    /// Supplier side: (re)build the snapshot manifest from the current chain
+    /// tip and repopulate the content-addressed chunk cache that
+    /// `handle_get_snapshot_chunk` serves from.
+    async fn handle_get_snapshot_manifest(&self, _request: ZhtpRequest) -> Result<ZhtpResponse> {
+        let blockchain_arc = self.get_blockchain().await?;
+        let blockchain = blockchain_arc.read().await;
+
+        let block_number = blockchain.height;
+        let block_hash = blockchain
+            .blocks
+            .last()
+            .map(|b| hex::encode(b.header.block_hash.as_bytes()))
+            .unwrap_or_else(|| "none".to_string());
+
+        let registries_bytes = bincode::serialize(&(
+            &blockchain.identity_registry,
+            &blockchain.wallet_registry,
+            &blockchain.validator_registry,
+        ))?;
+        let state_root = hex::encode(blake3::hash(&registries_bytes).as_bytes());
+
+        let mut chunks: HashMap<String, Vec<u8>> = HashMap::new();
+        let mut state_chunk_hashes = Vec::new();
+
+        // The registries chunk is content-addressed by the same hash as
+        // `state_root` since it's the only thing `state_root` summarizes.
+        chunks.insert(state_root.clone(), registries_bytes);
+        state_chunk_hashes.push(state_root.clone());
+
+        let utxo_entries: Vec<_> = blockchain.utxo_set.iter().collect();
+        for utxo_chunk in utxo_entries.chunks(SNAPSHOT_STATE_CHUNK_SIZE) {
+            let bytes = bincode::serialize(utxo_chunk)?;
+            let hash = hex::encode(blake3::hash(&bytes).as_bytes());
+            chunks.insert(hash.clone(), bytes);
+            state_chunk_hashes.push(hash);
+        }
+
+        let mut block_chunk_hashes = Vec::new();
+        for block_chunk in blockchain.blocks.chunks(SNAPSHOT_BLOCK_CHUNK_SIZE) {
+            let bytes = bincode::serialize(block_chunk)?;
+            let hash = hex::encode(blake3::hash(&bytes).as_bytes());
+            chunks.insert(hash.clone(), bytes);
+            block_chunk_hashes.push(hash);
+        }
+
+        drop(blockchain);
+
+        let manifest = SnapshotManifestResponse {
+            block_number,
+            block_hash,
+            state_root,
+            state_chunk_hashes,
+            block_chunk_hashes,
+            chunk_size: SNAPSHOT_BLOCK_CHUNK_SIZE,
+        };
+
+        *self.snapshot_chunks.write().await = chunks;
+
+        tracing::info!(
+            " Built snapshot manifest: block={}, state_chunks={}, block_chunks={}",
+            manifest.block_number,
+            manifest.state_chunk_hashes.len(),
+            manifest.block_chunk_hashes.len()
+        );
+
+        let json_response = serde_json::to_vec(&manifest)?;
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            "application/json".to_string(),
+            None,
+        ))
+    }
+
+    /// Supplier side: serve a single content-addressed chunk, re-verifying it
+    /// against its own hash before sending so a corrupted cache entry can't
+    /// silently poison a requester's reconstruction.
+    async fn handle_get_snapshot_chunk(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        let chunk_hash = request
+            .uri
+            .rsplit('/')
+            .next()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string());
+        let chunk_hash = match chunk_hash {
+            Some(hash) => hash,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Missing chunk hash".to_string(),
+                ));
+            }
+        };
+
+        let bytes = {
+            let chunks = self.snapshot_chunks.read().await;
+            chunks.get(&chunk_hash).cloned()
+        };
+        let bytes = match bytes {
+            Some(bytes) => bytes,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::NotFound,
+                    format!(
+                        "Unknown snapshot chunk {}; fetch /snapshot/manifest first",
+                        chunk_hash
+                    ),
+                ));
+            }
+        };
+
+        let actual_hash = hex::encode(blake3::hash(&bytes).as_bytes());
+        if actual_hash != chunk_hash {
+            return Ok(ZhtpResponse::error(
+                ZhtpStatus::InternalServerError,
+                "Snapshot chunk failed its own content-address check".to_string(),
+            ));
+        }
+
+        Ok(ZhtpResponse::success_with_content_type(
+            bytes,
+            "application/octet-stream".to_string(),
+            None,
+        ))
+    }
+
+    /// Requester side: accept a manifest (typically fetched from another
+    /// node's `handle_get_snapshot_manifest`), register a restore session that
+    /// tracks which chunks are still missing, and report initial progress.
+    async fn handle_snapshot_restore(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        if request.body.is_empty() {
+            return Ok(ZhtpResponse::error(
+                ZhtpStatus::BadRequest,
+                "Restore requires a manifest from /api/v1/blockchain/snapshot/manifest"
+                    .to_string(),
+            ));
+        }
+
+        let manifest: SnapshotManifestResponse = match serde_json::from_slice(&request.body) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    format!("Invalid snapshot manifest: {}", e),
+                ));
+            }
+        };
+
+        let total = manifest.state_chunk_hashes.len() + manifest.block_chunk_hashes.len();
+        let restore_id = uuid::Uuid::new_v4().to_string();
+        let state = SnapshotRestoreState {
+            missing_state_chunks: manifest.state_chunk_hashes.iter().cloned().collect(),
+            missing_block_chunks: manifest.block_chunk_hashes.iter().cloned().collect(),
+            phase: SnapshotRestorePhase::State,
+            warp_barrier: manifest.block_number,
+            manifest,
+        };
+
+        let response = SnapshotStatusResponse {
+            status: "success".to_string(),
+            restore_id: restore_id.clone(),
+            phase: state.phase.as_str().to_string(),
+            chunks_done: 0,
+            total,
+            warp_barrier: state.warp_barrier,
+        };
+
+        self.snapshot_restores
+            .write()
+            .await
+            .insert(restore_id, state);
+
+        crate::runtime::sync_scheduler::begin_warp_restore().await;
+
+        let json_response = serde_json::to_vec(&response)?;
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            "application/json".to_string(),
+            None,
+        ))
+    }
+
+    /// Requester side: report a fetched chunk as received, advancing the
+    /// restore's phase (`state` -> `blocks` -> `complete`) as each group of
+    /// chunks empties out.
+    async fn handle_snapshot_restore_chunk_ack(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        // Path: /api/v1/blockchain/snapshot/restore/{id}/chunk/{hash}
+        let parts: Vec<&str> = request.uri.split('/').collect();
+        if parts.len() < 9 {
+            return Ok(ZhtpResponse::error(
+                ZhtpStatus::BadRequest,
+                "Invalid path. Use: /api/v1/blockchain/snapshot/restore/{id}/chunk/{hash}"
+                    .to_string(),
+            ));
+        }
+        let restore_id = parts[6];
+        let chunk_hash = parts[8];
+
+        let mut restores = self.snapshot_restores.write().await;
+        let state = match restores.get_mut(restore_id) {
+            Some(state) => state,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::NotFound,
+                    format!("Unknown snapshot restore {}", restore_id),
+                ));
+            }
+        };
+
+        state.missing_state_chunks.remove(chunk_hash);
+        state.missing_block_chunks.remove(chunk_hash);
+
+        let was_already_complete = state.phase == SnapshotRestorePhase::Complete;
+        if state.phase == SnapshotRestorePhase::State && state.missing_state_chunks.is_empty() {
+            state.phase = SnapshotRestorePhase::Blocks;
+        }
+        if state.phase == SnapshotRestorePhase::Blocks && state.missing_block_chunks.is_empty() {
+            state.phase = SnapshotRestorePhase::Complete;
+        }
+
+        let total = state.manifest.state_chunk_hashes.len() + state.manifest.block_chunk_hashes.len();
+        let chunks_done = total - state.missing_state_chunks.len() - state.missing_block_chunks.len();
+        let just_completed = !was_already_complete && state.phase == SnapshotRestorePhase::Complete;
+        let warp_barrier = state.warp_barrier;
+        let response = SnapshotStatusResponse {
+            status: "success".to_string(),
+            restore_id: restore_id.to_string(),
+            phase: state.phase.as_str().to_string(),
+            chunks_done,
+            total,
+            warp_barrier,
+        };
+        drop(restores);
+
+        if just_completed {
+            // Resume ordinary range sync from the snapshot height forward,
+            // toward the highest height any peer has announced (or the
+            // snapshot height itself if none have, i.e. nothing left to do).
+            let schedule = crate::runtime::sync_scheduler::status().await;
+            let target_height = schedule
+                .peer_announcements
+                .iter()
+                .map(|a| a.best_height)
+                .max()
+                .unwrap_or(warp_barrier)
+                .max(warp_barrier);
+            crate::runtime::sync_scheduler::complete_warp_restore(warp_barrier, target_height).await;
+        }
+
+        let json_response = serde_json::to_vec(&response)?;
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            "application/json".to_string(),
+            None,
+        ))
+    }
+
+    /// Requester side: report current restore progress without mutating it.
+    async fn handle_get_snapshot_restore_status(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        // Path: /api/v1/blockchain/snapshot/restore/{id}/status
+        let parts: Vec<&str> = request.uri.split('/').collect();
+        let restore_id = match parts.get(parts.len().saturating_sub(2)) {
+            Some(id) => *id,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Invalid path. Use: /api/v1/blockchain/snapshot/restore/{id}/status"
+                        .to_string(),
+                ));
+            }
+        };
+
+        let restores = self.snapshot_restores.read().await;
+        let state = match restores.get(restore_id) {
+            Some(state) => state,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::NotFound,
+                    format!("Unknown snapshot restore {}", restore_id),
+                ));
+            }
+        };
+
+        let total = state.manifest.state_chunk_hashes.len() + state.manifest.block_chunk_hashes.len();
+        let chunks_done = total - state.missing_state_chunks.len() - state.missing_block_chunks.len();
+        let response = SnapshotStatusResponse {
+            status: "success".to_string(),
+            restore_id: restore_id.to_string(),
+            phase: state.phase.as_str().to_string(),
+            chunks_done,
+            total,
+            warp_barrier: state.warp_barrier,
+        };
+
+        let json_response = serde_json::to_vec(&response)?;
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            "application/json".to_string(),
+            None,
+        ))
+    }
+}
+
+/// Number of blocks bundled into one content-addressed snapshot chunk.
+const SNAPSHOT_BLOCK_CHUNK_SIZE: usize = 50;
+/// Number of UTXO entries bundled into one content-addressed snapshot chunk.
+const SNAPSHOT_STATE_CHUNK_SIZE: usize = 500;
+
+/// Snapshot (warp) sync manifest: points a fresh node at the content-addressed
+/// state and block chunks it needs to bootstrap without replaying history.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct SnapshotManifestResponse {
+    block_number: u64,
+    block_hash: String,
+    /// Closest analog this chain has to a state trie root - see the
+    /// warp/snapshot fast-sync comment above `handle_get_snapshot_manifest`.
+    state_root: String,
+    state_chunk_hashes: Vec<String>,
+    block_chunk_hashes: Vec<String>,
+    chunk_size: usize,
+}
+
+/// Phase of an in-progress snapshot restore (requester side).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SnapshotRestorePhase {
+    Manifest,
+    State,
+    Blocks,
+    Complete,
+}
+
+impl SnapshotRestorePhase {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SnapshotRestorePhase::Manifest => "manifest",
+            SnapshotRestorePhase::State => "state",
+            SnapshotRestorePhase::Blocks => "blocks",
+            SnapshotRestorePhase::Complete => "complete",
+        }
+    }
+}
+
+/// Server-side tracking for one in-progress snapshot restore.
+struct SnapshotRestoreState {
+    manifest: SnapshotManifestResponse,
+    missing_state_chunks: std::collections::HashSet<String>,
+    missing_block_chunks: std::collections::HashSet<String>,
+    phase: SnapshotRestorePhase,
+    /// Block number below which ordinary block sync is skipped once this
+    /// snapshot is applied, so the node avoids re-downloading pre-snapshot
+    /// history.
+    warp_barrier: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotStatusResponse {
+    status: String,
+    restore_id: String,
+    phase: String,
+    chunks_done: usize,
+    total: usize,
+    warp_barrier: u64,
 }
@@ -1,6 +1,6 @@
 //! Guardian Social Recovery HTTP Handler
 //!
-//! Implements 9 endpoints for guardian management and social recovery.
+//! Implements 26 endpoints for guardian management and social recovery.
 //! Security-focused with rate limiting, signature verification, and proper session management.
 
 use std::sync::Arc;
@@ -12,8 +12,8 @@ use tracing::{info, warn, error};
 use lib_protocols::types::{ZhtpRequest, ZhtpResponse, ZhtpStatus, ZhtpMethod};
 use lib_protocols::zhtp::ZhtpRequestHandler;
 use lib_identity::{
-    IdentityManager, GuardianConfig, GuardianStatus,
-    SocialRecoveryManager,
+    IdentityManager, GuardianConfig, GuardianStatus, GuardianType,
+    RecoveryStatus, SocialRecoveryManager, SiweMessage,
 };
 use lib_crypto::{PublicKey, PostQuantumSignature, SignatureAlgorithm};
 
@@ -55,6 +55,57 @@ impl GuardianHandler {
         .await
     }
 
+    /// Handle: POST /api/v1/identity/guardians/add-wallet
+    async fn handle_add_wallet_guardian(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_add_wallet_guardian(
+            &request.body,
+            self.identity_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/guardians/invite
+    async fn handle_invite_guardian(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_invite_guardian(
+            &request.body,
+            self.identity_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/guardians/accept
+    async fn handle_accept_guardian_invitation(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_accept_guardian_invitation(
+            &request.body,
+            self.identity_manager.clone(),
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/guardians/decline
+    async fn handle_decline_guardian_invitation(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_decline_guardian_invitation(
+            &request.body,
+            self.identity_manager.clone(),
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/guardians/search
+    async fn handle_search_identity(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_search_identity(
+            &request.body,
+            self.identity_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
     /// Handle: DELETE /api/v1/identity/guardians/{guardian_id}
     async fn handle_remove_guardian(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
         handle_remove_guardian(
@@ -76,6 +127,17 @@ impl GuardianHandler {
         .await
     }
 
+    /// Handle: POST /api/v1/identity/guardians/notification-endpoint
+    async fn handle_set_notification_endpoint(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_set_notification_endpoint(
+            &request.body,
+            self.identity_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
     /// Handle: POST /api/v1/identity/recovery/initiate
     async fn handle_initiate_recovery(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
         handle_initiate_recovery(
@@ -145,6 +207,127 @@ impl GuardianHandler {
         )
         .await
     }
+
+    /// Handle: POST /api/v1/identity/guardians/distribute-shares
+    async fn handle_distribute_key_shares(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_distribute_key_shares(
+            &request.body,
+            self.identity_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/recovery/{recovery_id}/reconstruct
+    async fn handle_reconstruct_recovery(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_reconstruct_recovery(
+            &request.uri,
+            &request.body,
+            self.identity_manager.clone(),
+            self.recovery_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/session/refresh
+    async fn handle_refresh_session(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_refresh_session(
+            &request.body,
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: GET /api/v1/identity/audit
+    async fn handle_audit_log(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_audit_log(
+            &request.uri,
+            self.identity_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: GET /api/v1/identity/guardians/export
+    async fn handle_export_guardians(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_export_guardians(
+            self.identity_manager.clone(),
+            self.recovery_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/guardians/import
+    async fn handle_import_guardians(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_import_guardians(
+            &request.body,
+            self.identity_manager.clone(),
+            self.recovery_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/recovery/batch-approve
+    async fn handle_batch_approve_recovery(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_batch_approve_recovery(
+            &request.body,
+            self.identity_manager.clone(),
+            self.recovery_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/guardians/emergency-access/grant
+    async fn handle_grant_emergency_access(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_grant_emergency_access(
+            &request.body,
+            self.identity_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: GET /api/v1/identity/guardians/emergency-access/nonce
+    async fn handle_emergency_initiation_nonce(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_emergency_initiation_nonce(&request.uri, self.identity_manager.clone()).await
+    }
+
+    /// Handle: POST /api/v1/identity/recovery/emergency/initiate
+    async fn handle_initiate_emergency_access(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_initiate_emergency_access(
+            &request.body,
+            self.identity_manager.clone(),
+            self.recovery_manager.clone(),
+            self.rate_limiter.clone(),
+            &request,
+        )
+        .await
+    }
+
+    /// Handle: POST /api/v1/identity/recovery/{recovery_id}/emergency/reject
+    async fn handle_reject_emergency_access(&self, request: ZhtpRequest) -> Result<ZhtpResponse> {
+        handle_reject_emergency_access(
+            &request.uri,
+            &request.body,
+            self.identity_manager.clone(),
+            self.recovery_manager.clone(),
+            self.session_manager.clone(),
+            &request,
+        )
+        .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -154,15 +337,48 @@ impl ZhtpRequestHandler for GuardianHandler {
             (ZhtpMethod::Post, "/api/v1/identity/guardians/add") => {
                 self.handle_add_guardian(request).await
             }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/add-wallet") => {
+                self.handle_add_wallet_guardian(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/invite") => {
+                self.handle_invite_guardian(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/accept") => {
+                self.handle_accept_guardian_invitation(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/decline") => {
+                self.handle_decline_guardian_invitation(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/search") => {
+                self.handle_search_identity(request).await
+            }
             (ZhtpMethod::Delete, uri) if uri.starts_with("/api/v1/identity/guardians/") => {
                 self.handle_remove_guardian(request).await
             }
             (ZhtpMethod::Get, "/api/v1/identity/guardians") => {
                 self.handle_list_guardians(request).await
             }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/notification-endpoint") => {
+                self.handle_set_notification_endpoint(request).await
+            }
             (ZhtpMethod::Post, "/api/v1/identity/recovery/initiate") => {
                 self.handle_initiate_recovery(request).await
             }
+            (ZhtpMethod::Post, "/api/v1/identity/recovery/batch-approve") => {
+                self.handle_batch_approve_recovery(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/emergency-access/grant") => {
+                self.handle_grant_emergency_access(request).await
+            }
+            (ZhtpMethod::Get, "/api/v1/identity/guardians/emergency-access/nonce") => {
+                self.handle_emergency_initiation_nonce(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/recovery/emergency/initiate") => {
+                self.handle_initiate_emergency_access(request).await
+            }
+            (ZhtpMethod::Post, uri) if uri.contains("/recovery/") && uri.ends_with("/emergency/reject") => {
+                self.handle_reject_emergency_access(request).await
+            }
             (ZhtpMethod::Post, uri) if uri.contains("/recovery/") && uri.ends_with("/approve") => {
                 self.handle_approve_recovery(request).await
             }
@@ -178,6 +394,24 @@ impl ZhtpRequestHandler for GuardianHandler {
             (ZhtpMethod::Get, "/api/v1/identity/recovery/pending") => {
                 self.handle_pending_recoveries(request).await
             }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/distribute-shares") => {
+                self.handle_distribute_key_shares(request).await
+            }
+            (ZhtpMethod::Post, uri) if uri.contains("/recovery/") && uri.ends_with("/reconstruct") => {
+                self.handle_reconstruct_recovery(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/session/refresh") => {
+                self.handle_refresh_session(request).await
+            }
+            (ZhtpMethod::Get, uri) if uri.starts_with("/api/v1/identity/audit") => {
+                self.handle_audit_log(request).await
+            }
+            (ZhtpMethod::Get, "/api/v1/identity/guardians/export") => {
+                self.handle_export_guardians(request).await
+            }
+            (ZhtpMethod::Post, "/api/v1/identity/guardians/import") => {
+                self.handle_import_guardians(request).await
+            }
             _ => Ok(ZhtpResponse::error(
                 ZhtpStatus::NotFound,
                 format!("Guardian endpoint not found: {}", request.uri),
@@ -188,6 +422,8 @@ impl ZhtpRequestHandler for GuardianHandler {
     fn can_handle(&self, request: &ZhtpRequest) -> bool {
         request.uri.starts_with("/api/v1/identity/guardians")
             || request.uri.starts_with("/api/v1/identity/recovery")
+            || request.uri == "/api/v1/identity/session/refresh"
+            || request.uri.starts_with("/api/v1/identity/audit")
     }
 
     fn priority(&self) -> u32 {
@@ -213,6 +449,104 @@ struct AddGuardianResponse {
     total_guardians: usize,
 }
 
+#[derive(Debug, Deserialize)]
+struct AddWalletGuardianRequest {
+    identity_id: String,
+    session_token: String,
+    /// `0x`-prefixed Ethereum wallet address (EIP-55 checksum not
+    /// required on input; it's normalized on storage)
+    guardian_address: String,
+    guardian_name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct AddWalletGuardianResponse {
+    status: String,
+    guardian_id: String,
+    total_guardians: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct SetNotificationEndpointRequest {
+    identity_id: String,
+    session_token: String,
+    guardian_id: String,
+    /// Where to push recovery notifications for this guardian. `None`
+    /// clears it, falling back to polling `/recovery/pending`.
+    notification_endpoint: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SetNotificationEndpointResponse {
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct InviteGuardianRequest {
+    identity_id: String,
+    session_token: String,
+    guardian_did: String,
+    guardian_public_key: Vec<u8>,
+    guardian_name: String,
+    /// Hours until the invitation expires (default 72h if omitted).
+    expiration_hours: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct InviteGuardianResponse {
+    status: String,
+    guardian_id: String,
+    invitation_token: String,
+    expires_at: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct AcceptGuardianInvitationRequest {
+    identity_id: String,
+    invitation_token: String,
+    /// Signature over `invitation_token`'s UTF-8 bytes, proving control of
+    /// the post-quantum key submitted at invite time.
+    signature: Vec<u8>,
+}
+
+#[derive(Debug, Serialize)]
+struct AcceptGuardianInvitationResponse {
+    status: String,
+    guardian_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeclineGuardianInvitationRequest {
+    identity_id: String,
+    invitation_token: String,
+    /// Signature over `invitation_token`'s UTF-8 bytes, proving control of
+    /// the post-quantum key submitted at invite time - same proof
+    /// `handle_accept_guardian_invitation` requires, so a guardian can't be
+    /// declined on the invitee's behalf by someone who merely knows the
+    /// token.
+    signature: Vec<u8>,
+}
+
+#[derive(Debug, Serialize)]
+struct DeclineGuardianInvitationResponse {
+    status: String,
+    guardian_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchIdentityRequest {
+    session_token: String,
+    /// A `username` metadata value or a partial `did:zhtp:` to resolve to
+    /// a canonical DID, for finding a guardian to invite.
+    query: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SearchIdentityResponse {
+    /// Canonical DIDs matching the query, capped at 10 results.
+    matches: Vec<String>,
+}
+
 #[derive(Debug, Serialize)]
 struct ListGuardiansResponse {
     guardians: Vec<GuardianInfo>,
@@ -247,7 +581,62 @@ struct InitiateRecoveryResponse {
 struct ApproveRecoveryRequest {
     guardian_did: String,
     session_token: String,
-    signature: Vec<u8>,
+    /// Single-use challenge nonce issued to this guardian for this
+    /// recovery request (see `GET .../recovery/{id}/status`), embedded in
+    /// the signed replay-resistant tuple
+    nonce: String,
+    /// Unix timestamp the signature was produced at, checked against a
+    /// freshness window to reject stale signed tuples
+    timestamp: i64,
+    /// ZHTP post-quantum signature over `(recovery_id, guardian_did,
+    /// nonce, action, timestamp)`, required for `GuardianType::ZhtpIdentity`
+    /// guardians
+    signature: Option<Vec<u8>>,
+    /// SIWE (EIP-4361) message text signed by an Ethereum wallet
+    /// guardian, required (together with `signature`) for
+    /// `GuardianType::EthereumWallet` guardians. Its `Nonce:` field must
+    /// match `nonce` above.
+    siwe_message: Option<String>,
+    /// This guardian's decrypted Shamir share of the master seed (the
+    /// guardian decrypts their `key_shares` entry client-side with their
+    /// own private key). Optional - a guardian can approve without
+    /// contributing a share, but the recovery can't be reconstructed
+    /// without at least `threshold` shares submitted.
+    key_share: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DistributeKeySharesRequest {
+    identity_id: String,
+    session_token: String,
+}
+
+#[derive(Debug, Serialize)]
+struct DistributeKeySharesResponse {
+    status: String,
+    guardians_count: usize,
+    threshold: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReconstructRecoveryRequest {
+    /// Session token for the identity being recovered, proving this call
+    /// comes from the recovering owner/device and not from any one of the
+    /// (individually only partially trusted) approving guardians.
+    session_token: String,
+
+    /// If provided, each returned share is re-encrypted to this public key
+    /// (the recovering device's new key) instead of being returned as-is.
+    new_device_public_key: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReconstructRecoveryResponse {
+    status: String,
+    /// Shares making up the reconstructed master seed, one per approving
+    /// guardian who submitted one (re-encrypted to `new_device_public_key`
+    /// if one was given).
+    shares: Vec<Vec<u8>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -265,6 +654,10 @@ struct RecoveryStatusResponse {
     required: usize,
     expires_at: i64,
     identity_did: String,
+    /// Single-use challenge nonce issued to the querying guardian (via the
+    /// `guardian_did` query parameter), to embed in its signed
+    /// approve/reject tuple. `None` if no `guardian_did` was supplied.
+    guardian_nonce: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -272,26 +665,188 @@ struct PendingRecoveriesResponse {
     pending_requests: Vec<PendingRecoveryInfo>,
 }
 
-#[derive(Debug, Serialize)]
-struct PendingRecoveryInfo {
-    recovery_id: String,
-    identity_did: String,
-    initiated_at: i64,
-    expires_at: i64,
+#[derive(Debug, Deserialize)]
+struct RefreshSessionRequest {
+    refresh_token: String,
 }
 
-// Endpoint implementations
+#[derive(Debug, Serialize)]
+struct RefreshSessionResponse {
+    status: String,
+    session_token: String,
+    refresh_token: String,
+}
 
-async fn handle_add_guardian(
-    body: &[u8],
-    identity_manager: Arc<RwLock<IdentityManager>>,
-    session_manager: Arc<SessionManager>,
-    request: &ZhtpRequest,
-) -> Result<ZhtpResponse> {
-    // Parse request
-    let req: AddGuardianRequest = serde_json::from_slice(body).map_err(|e| {
-        anyhow::anyhow!("Invalid request body: {}", e)
-    })?;
+#[derive(Debug, Serialize)]
+struct AuditEventInfo {
+    actor_did: String,
+    kind: String,
+    target_identity_did: String,
+    client_ip: String,
+    user_agent: String,
+    timestamp: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct AuditLogResponse {
+    events: Vec<AuditEventInfo>,
+}
+
+/// Everything needed to restore an identity's guardian set and in-flight
+/// recovery requests on another node. Serialized, then authenticated-
+/// encrypted under a key derived from the identity's own seed before ever
+/// leaving the server.
+#[derive(Debug, Serialize, Deserialize)]
+struct GuardianBackup {
+    guardian_config: GuardianConfig,
+    recovery_requests: Vec<lib_identity::RecoveryRequest>,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportGuardiansResponse {
+    status: String,
+    /// Authenticated-encrypted, compacted `GuardianBackup` blob
+    blob: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImportGuardiansRequest {
+    identity_id: String,
+    session_token: String,
+    blob: Vec<u8>,
+    /// Required to be true if the import would drop any currently `Active`
+    /// guardian that isn't present in the imported backup.
+    #[serde(default)]
+    overwrite: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportGuardiansResponse {
+    status: String,
+    guardians_restored: usize,
+    recovery_requests_restored: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchApprovalItem {
+    recovery_id: String,
+    /// Single-use challenge nonce issued to this guardian for this
+    /// recovery request, embedded in the signed replay-resistant tuple
+    nonce: String,
+    /// Unix timestamp the signature was produced at
+    timestamp: i64,
+    /// ZHTP post-quantum signature, required for `GuardianType::ZhtpIdentity`
+    signature: Option<Vec<u8>>,
+    /// SIWE message text, required (together with `signature`) for
+    /// `GuardianType::EthereumWallet`
+    siwe_message: Option<String>,
+    key_share: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchApproveRecoveryRequest {
+    guardian_did: String,
+    session_token: String,
+    approvals: Vec<BatchApprovalItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchApprovalResult {
+    recovery_id: String,
+    status: String,
+    approvals: Option<usize>,
+    required: Option<usize>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchApproveRecoveryResponse {
+    results: Vec<BatchApprovalResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GrantEmergencyAccessRequest {
+    identity_id: String,
+    session_token: String,
+    guardian_id: String,
+    /// Hours the owner has to reject an initiated emergency access request
+    /// before it matures and can be completed
+    waiting_period_hours: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct GrantEmergencyAccessResponse {
+    status: String,
+    guardian_id: String,
+    waiting_period_hours: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct InitiateEmergencyAccessRequest {
+    identity_did: String,
+    guardian_did: String,
+    requester_device: String,
+
+    /// Single-use challenge nonce issued to this guardian via `GET
+    /// /api/v1/identity/guardians/emergency-access/nonce`, embedded in the
+    /// signed tuple below to prove control of the guardian's registered
+    /// key before an emergency countdown is trusted to start.
+    nonce: String,
+
+    /// Timestamp embedded in the signed tuple, checked against a
+    /// freshness window to reject held/replayed signatures.
+    timestamp: i64,
+
+    /// PQ signature over `(identity_did, guardian_did, nonce,
+    /// "initiate-emergency", timestamp)`, required for `GuardianType::ZhtpIdentity`
+    /// guardians.
+    signature: Option<Vec<u8>>,
+
+    /// SIWE message binding the same tuple, required for
+    /// `GuardianType::EthereumWallet` guardians instead of `signature`.
+    siwe_message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct EmergencyInitiationNonceResponse {
+    nonce: String,
+}
+
+#[derive(Debug, Serialize)]
+struct InitiateEmergencyAccessResponse {
+    status: String,
+    recovery_id: String,
+    guardian_did: String,
+    takeover_available_at: i64,
+    expires_at: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RejectEmergencyAccessRequest {
+    identity_id: String,
+    session_token: String,
+}
+
+#[derive(Debug, Serialize)]
+struct PendingRecoveryInfo {
+    recovery_id: String,
+    identity_did: String,
+    initiated_at: i64,
+    expires_at: i64,
+}
+
+// Endpoint implementations
+
+async fn handle_add_guardian(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Parse request
+    let req: AddGuardianRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
 
     // Security: Validate inputs
     validate_did(&req.guardian_did)?;
@@ -354,6 +909,21 @@ async fn handle_add_guardian(
     // Persist guardian config to identity private data
     manager_write.set_guardian_config(&identity_id, guardian_config)
         .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+
+    // Audit: record the guardian addition against the owning identity
+    let owner_did = manager_write
+        .get_did_by_identity_id(&identity_id)
+        .unwrap_or_default();
+    manager_write.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            owner_did.clone(),
+            lib_identity::AuditEventKind::GuardianAdded,
+            owner_did,
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
     drop(manager_write);
 
     // Security: Log guardian addition
@@ -377,112 +947,103 @@ async fn handle_add_guardian(
     ))
 }
 
-async fn handle_remove_guardian(
-    uri: &str,
+async fn handle_add_wallet_guardian(
+    body: &[u8],
     identity_manager: Arc<RwLock<IdentityManager>>,
     session_manager: Arc<SessionManager>,
     request: &ZhtpRequest,
 ) -> Result<ZhtpResponse> {
-    // Extract guardian_id from URI: /api/v1/identity/guardians/{guardian_id}
-    let parts: Vec<&str> = uri.split('/').collect();
-    let guardian_id = parts.get(5).ok_or_else(|| anyhow::anyhow!("Missing guardian_id"))?;
+    // Parse request
+    let req: AddWalletGuardianRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
 
-    // Security: Extract and validate session token from Authorization header
-    let session_token = request
-        .headers
-        .get("Authorization")
-        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
-        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
+    // Security: Validate inputs
+    validate_guardian_name(&req.guardian_name)?;
 
-    // Security: Validate session and get identity_id
+    // Security: Extract real client IP
     let client_ip = extract_client_ip(request);
     let user_agent = extract_user_agent(request);
 
+    // Security: Validate session
     let session_token_obj = session_manager
-        .validate_session(&session_token, &client_ip, &user_agent)
+        .validate_session(&req.session_token, &client_ip, &user_agent)
         .await
-        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
+        .map_err(|e| {
+            warn!(
+                client_ip = %client_ip,
+                error = %e,
+                "Session validation failed in add_wallet_guardian"
+            );
+            anyhow::anyhow!("Session validation failed: {}", e)
+        })?;
 
-    let identity_id = session_token_obj.identity_id;
+    // Convert identity_id string to IdentityId (Hash)
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
 
-    // Load guardian config
-    let manager_read = identity_manager.read().await;
-    let mut guardian_config = manager_read
+    // Security: Verify session belongs to this identity
+    if session_token_obj.identity_id != identity_id {
+        error!(
+            session_identity = %hex::encode(session_token_obj.identity_id.as_bytes()),
+            requested_identity = %hex::encode(identity_id.as_bytes()),
+            client_ip = %client_ip,
+            "Authorization denied: session identity mismatch"
+        );
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
+
+    // Get or create guardian config and persist (use single write lock to prevent race conditions)
+    let mut manager_write = identity_manager.write().await;
+    let mut guardian_config = manager_write
         .get_guardian_config(&identity_id)
-        .ok_or_else(|| anyhow::anyhow!("No guardian config found"))?;
-    drop(manager_read);
+        .unwrap_or_default();
 
-    // Remove guardian from config
-    guardian_config
-        .remove_guardian(guardian_id)
-        .map_err(|e| anyhow::anyhow!("Failed to remove guardian: {}", e))?;
+    let guardian_id = guardian_config
+        .add_wallet_guardian(&req.guardian_address, req.guardian_name)
+        .map_err(|e| anyhow::anyhow!("Failed to add wallet guardian: {}", e))?;
 
-    // Persist changes to identity private data
-    let mut manager_write = identity_manager.write().await;
-    manager_write
-        .set_guardian_config(&identity_id, guardian_config)
+    let total_guardians = guardian_config.guardians.len();
+
+    // Persist guardian config to identity private data
+    manager_write.set_guardian_config(&identity_id, guardian_config)
         .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
 
-    // Security: Log guardian removal
+    // Audit: record the guardian addition against the owning identity
+    let owner_did = manager_write
+        .get_did_by_identity_id(&identity_id)
+        .unwrap_or_default();
+    manager_write.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            owner_did.clone(),
+            lib_identity::AuditEventKind::GuardianAdded,
+            owner_did,
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
+    drop(manager_write);
+
+    // Security: Log guardian addition
     info!(
         identity_id = %hex::encode(identity_id.as_bytes()),
+        guardian_address = %req.guardian_address,
         guardian_id = %guardian_id,
         client_ip = %client_ip,
-        "Guardian removed successfully"
+        "Ethereum wallet guardian added successfully"
     );
 
-    Ok(ZhtpResponse::success(
-        serde_json::to_vec(&serde_json::json!({"status": "success"}))?,
-        None,
-    ))
-}
-
-async fn handle_list_guardians(
-    identity_manager: Arc<RwLock<IdentityManager>>,
-    session_manager: Arc<SessionManager>,
-    request: &ZhtpRequest,
-) -> Result<ZhtpResponse> {
-    // Security: Extract session token from Authorization header
-    let session_token = request
-        .headers
-        .get("Authorization")
-        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
-        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
-
-    // Security: Validate session and get identity_id
-    let client_ip = extract_client_ip(request);
-    let user_agent = extract_user_agent(request);
-
-    let session_token_obj = session_manager
-        .validate_session(&session_token, &client_ip, &user_agent)
-        .await
-        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
-
-    let identity_id = session_token_obj.identity_id;
-
-    // Load guardian config from identity storage
-    let manager_read = identity_manager.read().await;
-    let guardian_config = manager_read
-        .get_guardian_config(&identity_id)
-        .unwrap_or_default();
-    drop(manager_read);
-
-    // Convert guardians to response format
-    let guardians: Vec<GuardianInfo> = guardian_config
-        .guardians
-        .values()
-        .map(|g| GuardianInfo {
-            guardian_id: g.guardian_id.clone(),
-            guardian_did: g.guardian_did.clone(),
-            name: g.name.clone(),
-            added_at: g.added_at.timestamp(),
-            status: format!("{:?}", g.status),
-        })
-        .collect();
-
-    let response = ListGuardiansResponse {
-        guardians,
-        threshold: guardian_config.threshold,
+    let response = AddWalletGuardianResponse {
+        status: "success".to_string(),
+        guardian_id,
+        total_guardians,
     };
 
     Ok(ZhtpResponse::success(
@@ -491,79 +1052,1632 @@ async fn handle_list_guardians(
     ))
 }
 
-async fn handle_initiate_recovery(
+async fn handle_invite_guardian(
     body: &[u8],
     identity_manager: Arc<RwLock<IdentityManager>>,
-    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
-    rate_limiter: Arc<RateLimiter>,
+    session_manager: Arc<SessionManager>,
     request: &ZhtpRequest,
 ) -> Result<ZhtpResponse> {
     // Parse request
-    let req: InitiateRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
+    let req: InviteGuardianRequest = serde_json::from_slice(body).map_err(|e| {
         anyhow::anyhow!("Invalid request body: {}", e)
     })?;
 
     // Security: Validate inputs
-    validate_did(&req.identity_did)?;
-    validate_device_name(&req.requester_device)?;
+    validate_did(&req.guardian_did)?;
+    validate_guardian_name(&req.guardian_name)?;
+    validate_public_key_length(&req.guardian_public_key)?;
+    let expiration_hours = req.expiration_hours.unwrap_or(72);
+    if !(1..=168).contains(&expiration_hours) {
+        return Err(anyhow::anyhow!("expiration_hours must be between 1 and 168"));
+    }
 
     // Security: Extract real client IP
     let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
 
-    // Security: Rate limit recovery initiation (3 attempts per 24 hours)
-    if let Err(response) = rate_limiter.check_rate_limit_aggressive(&client_ip, 3, 86400).await {
-        return Ok(response);
-    }
-
-    // Get identity ID from DID
-    let identity_manager_read = identity_manager.read().await;
-    let identity_id = identity_manager_read
-        .get_identity_id_by_did(&req.identity_did)
-        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", req.identity_did))?;
-
-    // Load guardian config from identity storage
-    let guardian_config = identity_manager_read
-        .get_guardian_config(&identity_id)
+    // Security: Validate session
+    let session_token_obj = session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| {
+            warn!(
+                client_ip = %client_ip,
+                error = %e,
+                "Session validation failed in invite_guardian"
+            );
+            anyhow::anyhow!("Session validation failed: {}", e)
+        })?;
+
+    // Convert identity_id string to IdentityId (Hash)
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
+
+    // Security: Verify session belongs to this identity
+    if session_token_obj.identity_id != identity_id {
+        error!(
+            session_identity = %hex::encode(session_token_obj.identity_id.as_bytes()),
+            requested_identity = %hex::encode(identity_id.as_bytes()),
+            client_ip = %client_ip,
+            "Authorization denied: session identity mismatch"
+        );
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
+
+    // Get or create guardian config and persist (use single write lock to prevent race conditions)
+    let mut manager_write = identity_manager.write().await;
+    let mut guardian_config = manager_write
+        .get_guardian_config(&identity_id)
+        .unwrap_or_default();
+
+    // Invite guardian (pending until they accept with a proof-of-control signature)
+    let guardian_public_key = PublicKey::new(req.guardian_public_key);
+    let guardian_did_clone = req.guardian_did.clone();
+    let (guardian_id, invitation_token) = guardian_config
+        .invite_guardian(req.guardian_did, guardian_public_key, req.guardian_name, expiration_hours)
+        .map_err(|e| anyhow::anyhow!("Failed to invite guardian: {}", e))?;
+
+    let expires_at = guardian_config
+        .invitations
+        .get(&invitation_token)
+        .map(|inv| inv.expires_at.timestamp())
+        .unwrap_or(0);
+
+    // Persist guardian config to identity private data
+    manager_write.set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+    drop(manager_write);
+
+    // Security: Log guardian invitation
+    info!(
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardian_did = %guardian_did_clone,
+        guardian_id = %guardian_id,
+        client_ip = %client_ip,
+        "Guardian invited, pending acceptance"
+    );
+
+    let response = InviteGuardianResponse {
+        status: "invited".to_string(),
+        guardian_id,
+        invitation_token,
+        expires_at,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_accept_guardian_invitation(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+) -> Result<ZhtpResponse> {
+    // Parse request
+    let req: AcceptGuardianInvitationRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    validate_signature_length(&req.signature)?;
+
+    // Convert identity_id string to IdentityId (Hash)
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
+
+    // Load guardian config (use single write lock to prevent race conditions)
+    let mut manager_write = identity_manager.write().await;
+    let mut guardian_config = manager_write
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found"))?;
+
+    let invitation = guardian_config
+        .invitations
+        .get(&req.invitation_token)
+        .ok_or_else(|| anyhow::anyhow!("Invitation not found"))?;
+    let guardian = guardian_config
+        .guardians
+        .get(&invitation.guardian_id)
+        .ok_or_else(|| anyhow::anyhow!("Invited guardian not found"))?
+        .clone();
+
+    // Security: Verify the invited party controls the submitted key by
+    // signing the invitation token, the same way `handle_approve_recovery`
+    // verifies a guardian's `PostQuantumSignature`.
+    let public_key_bytes = guardian.public_key.as_bytes();
+    let is_valid = lib_crypto::verify_signature(
+        req.invitation_token.as_bytes(),
+        &req.signature,
+        &public_key_bytes,
+    )
+    .map_err(|e| anyhow::anyhow!("Signature verification failed: {}", e))?;
+
+    if !is_valid {
+        warn!(
+            guardian_did = %guardian.guardian_did,
+            "Rejected guardian invitation acceptance: invalid signature"
+        );
+        return Err(anyhow::anyhow!("Invalid invitation signature"));
+    }
+
+    let guardian_id = guardian_config
+        .accept_invitation(&req.invitation_token)
+        .map_err(|e| anyhow::anyhow!("Failed to accept invitation: {}", e))?;
+
+    manager_write.set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+    drop(manager_write);
+
+    info!(
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardian_did = %guardian.guardian_did,
+        guardian_id = %guardian_id,
+        "Guardian invitation accepted, guardian is now active"
+    );
+
+    let response = AcceptGuardianInvitationResponse {
+        status: "active".to_string(),
+        guardian_id,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_decline_guardian_invitation(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+) -> Result<ZhtpResponse> {
+    let req: DeclineGuardianInvitationRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    validate_signature_length(&req.signature)?;
+
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
+
+    let mut manager_write = identity_manager.write().await;
+    let mut guardian_config = manager_write
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found"))?;
+
+    let invitation = guardian_config
+        .invitations
+        .get(&req.invitation_token)
+        .ok_or_else(|| anyhow::anyhow!("Invitation not found"))?;
+    let guardian = guardian_config
+        .guardians
+        .get(&invitation.guardian_id)
+        .ok_or_else(|| anyhow::anyhow!("Invited guardian not found"))?
+        .clone();
+
+    // Security: Verify the declining party controls the submitted key, the
+    // same proof required to accept, so only the invitee can decline
+    let public_key_bytes = guardian.public_key.as_bytes();
+    let is_valid = lib_crypto::verify_signature(
+        req.invitation_token.as_bytes(),
+        &req.signature,
+        &public_key_bytes,
+    )
+    .map_err(|e| anyhow::anyhow!("Signature verification failed: {}", e))?;
+
+    if !is_valid {
+        warn!(
+            guardian_did = %guardian.guardian_did,
+            "Rejected guardian invitation decline: invalid signature"
+        );
+        return Err(anyhow::anyhow!("Invalid invitation signature"));
+    }
+
+    let guardian_id = guardian_config
+        .decline_invitation(&req.invitation_token)
+        .map_err(|e| anyhow::anyhow!("Failed to decline invitation: {}", e))?;
+
+    manager_write.set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+    drop(manager_write);
+
+    info!(
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardian_did = %guardian.guardian_did,
+        guardian_id = %guardian_id,
+        "Guardian invitation declined"
+    );
+
+    let response = DeclineGuardianInvitationResponse {
+        status: "declined".to_string(),
+        guardian_id,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+/// Resolve a username or partial DID to candidate guardians to invite.
+/// Requires a valid session (any authenticated identity) so the lookup
+/// can't be used for unauthenticated enumeration of registered DIDs.
+async fn handle_search_identity(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    let req: SearchIdentityRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    if req.query.trim().is_empty() {
+        return Err(anyhow::anyhow!("Search query cannot be empty"));
+    }
+
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
+
+    let mut matches = identity_manager.read().await.search_identities(&req.query);
+    matches.truncate(10);
+
+    let response = SearchIdentityResponse { matches };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_distribute_key_shares(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Parse request
+    let req: DistributeKeySharesRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    // Security: Extract real client IP
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    // Security: Validate session
+    let session_token_obj = session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| {
+            warn!(
+                client_ip = %client_ip,
+                error = %e,
+                "Session validation failed in distribute_key_shares"
+            );
+            anyhow::anyhow!("Session validation failed: {}", e)
+        })?;
+
+    // Convert identity_id string to IdentityId (Hash)
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
+
+    // Security: Verify session belongs to this identity
+    if session_token_obj.identity_id != identity_id {
+        error!(
+            session_identity = %hex::encode(session_token_obj.identity_id.as_bytes()),
+            requested_identity = %hex::encode(identity_id.as_bytes()),
+            client_ip = %client_ip,
+            "Authorization denied: session identity mismatch"
+        );
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
+
+    // Split the master seed and encrypt shares under a single write lock
+    let mut manager_write = identity_manager.write().await;
+    let mut guardian_config = manager_write
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardians configured for this identity"))?;
+    let master_seed = manager_write
+        .get_identity_seed(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found"))?;
+
+    guardian_config
+        .distribute_key_shares(&master_seed)
+        .map_err(|e| anyhow::anyhow!("Failed to distribute key shares: {}", e))?;
+
+    let guardians_count = guardian_config.get_active_guardians().len();
+    let threshold = guardian_config.threshold;
+
+    manager_write.set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+    drop(manager_write);
+
+    // Security: Log key-share distribution
+    info!(
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardians_count = guardians_count,
+        threshold = threshold,
+        client_ip = %client_ip,
+        "Master seed split into guardian key shares"
+    );
+
+    let response = DistributeKeySharesResponse {
+        status: "distributed".to_string(),
+        guardians_count,
+        threshold,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_remove_guardian(
+    uri: &str,
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Extract guardian_id from URI: /api/v1/identity/guardians/{guardian_id}
+    let parts: Vec<&str> = uri.split('/').collect();
+    let guardian_id = parts.get(5).ok_or_else(|| anyhow::anyhow!("Missing guardian_id"))?;
+
+    // Security: Extract and validate session token from Authorization header
+    let session_token = request
+        .headers
+        .get("Authorization")
+        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
+        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
+
+    // Security: Validate session and get identity_id
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
+
+    let identity_id = session_token_obj.identity_id;
+
+    // Load guardian config
+    let manager_read = identity_manager.read().await;
+    let mut guardian_config = manager_read
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found"))?;
+    drop(manager_read);
+
+    // Remove guardian from config
+    guardian_config
+        .remove_guardian(guardian_id)
+        .map_err(|e| anyhow::anyhow!("Failed to remove guardian: {}", e))?;
+
+    // Persist changes to identity private data
+    let mut manager_write = identity_manager.write().await;
+    manager_write
+        .set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+
+    // Audit: record the guardian removal against the owning identity
+    let owner_did = manager_write
+        .get_did_by_identity_id(&identity_id)
+        .unwrap_or_default();
+    manager_write.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            owner_did.clone(),
+            lib_identity::AuditEventKind::GuardianRemoved,
+            owner_did,
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
+    drop(manager_write);
+
+    // Security: Log guardian removal
+    info!(
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardian_id = %guardian_id,
+        client_ip = %client_ip,
+        "Guardian removed successfully"
+    );
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&serde_json::json!({"status": "success"}))?,
+        None,
+    ))
+}
+
+async fn handle_list_guardians(
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Security: Extract session token from Authorization header
+    let session_token = request
+        .headers
+        .get("Authorization")
+        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
+        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
+
+    // Security: Validate session and get identity_id
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
+
+    let identity_id = session_token_obj.identity_id;
+
+    // Load guardian config from identity storage
+    let manager_read = identity_manager.read().await;
+    let guardian_config = manager_read
+        .get_guardian_config(&identity_id)
+        .unwrap_or_default();
+    drop(manager_read);
+
+    // Convert guardians to response format
+    let guardians: Vec<GuardianInfo> = guardian_config
+        .guardians
+        .values()
+        .map(|g| GuardianInfo {
+            guardian_id: g.guardian_id.clone(),
+            guardian_did: g.guardian_did.clone(),
+            name: g.name.clone(),
+            added_at: g.added_at.timestamp(),
+            status: format!("{:?}", g.status),
+        })
+        .collect();
+
+    let response = ListGuardiansResponse {
+        guardians,
+        threshold: guardian_config.threshold,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_set_notification_endpoint(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    let req: SetNotificationEndpointRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    if let Some(endpoint) = &req.notification_endpoint {
+        validate_notification_endpoint(endpoint)?;
+    }
+
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
+
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
+
+    // Security: Verify session belongs to this identity - only the owning
+    // identity can manage its own guardians' notification endpoints
+    if session_token_obj.identity_id != identity_id {
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
+
+    let mut manager_write = identity_manager.write().await;
+    let mut guardian_config = manager_write
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found for this identity"))?;
+
+    guardian_config
+        .set_notification_endpoint(&req.guardian_id, req.notification_endpoint)
+        .map_err(|e| anyhow::anyhow!("Failed to set notification endpoint: {}", e))?;
+
+    manager_write.set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+
+    let owner_did = manager_write
+        .get_did_by_identity_id(&identity_id)
+        .unwrap_or_default();
+    manager_write.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            owner_did.clone(),
+            lib_identity::AuditEventKind::GuardianUpdated,
+            owner_did,
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
+    drop(manager_write);
+
+    info!(
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardian_id = %req.guardian_id,
+        client_ip = %client_ip,
+        "Guardian notification endpoint updated"
+    );
+
+    let response = SetNotificationEndpointResponse {
+        status: "success".to_string(),
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_initiate_recovery(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    rate_limiter: Arc<RateLimiter>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Parse request
+    let req: InitiateRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    // Security: Validate inputs
+    validate_did(&req.identity_did)?;
+    validate_device_name(&req.requester_device)?;
+
+    // Security: Extract real client IP
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    // Security: Rate limit recovery initiation (3 attempts per 24 hours)
+    if let Err(response) = rate_limiter.check_rate_limit_aggressive(&client_ip, 3, 86400).await {
+        return Ok(response);
+    }
+
+    // Get identity ID from DID
+    let identity_manager_read = identity_manager.read().await;
+    let identity_id = identity_manager_read
+        .get_identity_id_by_did(&req.identity_did)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", req.identity_did))?;
+
+    // Load guardian config from identity storage
+    let guardian_config = identity_manager_read
+        .get_guardian_config(&identity_id)
         .ok_or_else(|| anyhow::anyhow!("No guardians configured for this identity. Please add guardians first."))?;
     drop(identity_manager_read);
 
-    // Verify that guardians are configured
-    if guardian_config.guardians.is_empty() {
-        return Err(anyhow::anyhow!("No guardians configured for this identity"));
+    // Verify that guardians are configured
+    if guardian_config.guardians.is_empty() {
+        return Err(anyhow::anyhow!("No guardians configured for this identity"));
+    }
+
+    // Initiate recovery
+    let mut manager = recovery_manager.write().await;
+    let client_ip_clone = client_ip.clone();
+    let recovery_id = manager
+        .initiate_recovery(
+            req.identity_did.clone(),
+            &guardian_config,
+            req.requester_device,
+            client_ip,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to initiate recovery: {}", e))?;
+
+    let recovery_request = manager
+        .get_request(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+
+    // Audit: record the recovery initiation against the identity being recovered
+    identity_manager.write().await.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            req.identity_did.clone(),
+            lib_identity::AuditEventKind::RecoveryInitiated,
+            req.identity_did.clone(),
+            client_ip_clone.clone(),
+            user_agent.clone(),
+        ),
+    );
+
+    // Security: Log recovery initiation
+    info!(
+        identity_did = %req.identity_did,
+        recovery_id = %recovery_id,
+        guardians_required = recovery_request.threshold,
+        client_ip = %client_ip_clone,
+        requester_device = %recovery_request.requester_device,
+        "Recovery initiated"
+    );
+
+    // Push-notify active guardians so they don't have to poll
+    // `/recovery/pending` to learn a new request needs their approval
+    notify_guardians(
+        &guardian_config,
+        GuardianNotificationEvent::RecoveryInitiated,
+        recovery_id.clone(),
+        recovery_request.requester_device.clone(),
+        recovery_request.expires_at.timestamp(),
+    );
+
+    let response = InitiateRecoveryResponse {
+        status: "initiated".to_string(),
+        recovery_id,
+        guardians_required: recovery_request.threshold,
+        guardians_approved: 0,
+        expires_at: recovery_request.expires_at.timestamp(),
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_approve_recovery(
+    uri: &str,
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Extract recovery_id from URI
+    let recovery_id = extract_recovery_id(uri)?;
+
+    // Parse request
+    let req: ApproveRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    // Security: Validate inputs (either a ZHTP DID or an Ethereum
+    // wallet address - the guardian lookup below rejects anything that
+    // doesn't match a configured guardian either way)
+    validate_guardian_identifier(&req.guardian_did)?;
+
+    // Security: Extract real client IP
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    // Security: Validate guardian's session
+    session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
+
+    // Get the recovery request to find the identity being recovered
+    let manager = recovery_manager.read().await;
+    let recovery_request = manager
+        .get_request(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+    let identity_did = recovery_request.identity_did.clone();
+    drop(manager);
+
+    // Get the identity ID from DID
+    let identity_manager_read = identity_manager.read().await;
+    let identity_id = identity_manager_read
+        .get_identity_id_by_did(&identity_did)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", identity_did))?;
+
+    // Load guardian config and verify guardian exists
+    let guardian_config = identity_manager_read
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found for this identity"))?;
+    drop(identity_manager_read);
+
+    // Verify the approver is actually an authorized guardian with Active status
+    let guardian = guardian_config
+        .guardians
+        .values()
+        .find(|g| g.guardian_did == req.guardian_did && g.status == GuardianStatus::Active)
+        .ok_or_else(|| anyhow::anyhow!("Not an authorized guardian or guardian is not active"))?;
+
+    let key_share = req.key_share;
+
+    let mut manager = recovery_manager.write().await;
+    let recovery_request = manager
+        .get_request_mut(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+
+    // Add approval with signature verification - the verification method
+    // depends on whether this guardian is a ZHTP identity or an Ethereum
+    // wallet
+    let approval_result = match guardian.guardian_type {
+        GuardianType::ZhtpIdentity => {
+            let signature_bytes = req
+                .signature
+                .ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            validate_signature_length(&signature_bytes)?;
+            let signature = PostQuantumSignature {
+                signature: signature_bytes,
+                public_key: guardian.public_key.clone(),
+                algorithm: SignatureAlgorithm::Dilithium2,
+                timestamp: chrono::Utc::now().timestamp() as u64,
+            };
+            recovery_request.add_approval(guardian, &req.nonce, req.timestamp, signature)
+        }
+        GuardianType::EthereumWallet => {
+            let siwe_message = req
+                .siwe_message
+                .ok_or_else(|| anyhow::anyhow!("Missing siwe_message"))?;
+            let signature_bytes = req
+                .signature
+                .ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            recovery_request.add_wallet_approval(guardian, &siwe_message, &signature_bytes)
+        }
+    };
+
+    approval_result.map_err(|e| {
+        // Security: Log failed approval attempt
+        warn!(
+            recovery_id = %recovery_id,
+            guardian_did = %req.guardian_did,
+            client_ip = %client_ip,
+            error = %e,
+            "Failed guardian approval attempt"
+        );
+        anyhow::anyhow!("Failed to add approval: {}", e)
+    })?;
+
+    if let Some(share) = key_share {
+        recovery_request
+            .submit_key_share(&req.guardian_did, share)
+            .map_err(|e| anyhow::anyhow!("Failed to submit key share: {}", e))?;
+    }
+
+    // Audit: record the approval against the identity being recovered
+    identity_manager.write().await.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            req.guardian_did.clone(),
+            lib_identity::AuditEventKind::RecoveryApproved,
+            identity_did.clone(),
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
+
+    // Security: Log successful approval
+    info!(
+        recovery_id = %recovery_id,
+        guardian_did = %req.guardian_did,
+        approvals = recovery_request.approval_count(),
+        required = recovery_request.threshold,
+        client_ip = %client_ip,
+        "Guardian approved recovery"
+    );
+
+    if recovery_request.status == RecoveryStatus::Approved {
+        notify_guardians(
+            &guardian_config,
+            GuardianNotificationEvent::ThresholdMet,
+            recovery_id.clone(),
+            recovery_request.requester_device.clone(),
+            recovery_request.expires_at.timestamp(),
+        );
+    }
+
+    let response = ApproveRecoveryResponse {
+        status: "approved".to_string(),
+        approvals: recovery_request.approval_count(),
+        required: recovery_request.threshold,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_reject_recovery(
+    uri: &str,
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Extract recovery_id from URI
+    let recovery_id = extract_recovery_id(uri)?;
+
+    // Parse request to get guardian_did
+    let req: ApproveRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    validate_guardian_identifier(&req.guardian_did)?;
+
+    // Security: Extract real client IP
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    // Security: Validate guardian's session
+    session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
+
+    // Get the recovery request to find the identity being recovered
+    let manager = recovery_manager.read().await;
+    let recovery_request = manager
+        .get_request(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+    let identity_did = recovery_request.identity_did.clone();
+    drop(manager);
+
+    // Get the identity ID from DID
+    let identity_manager_read = identity_manager.read().await;
+    let identity_id = identity_manager_read
+        .get_identity_id_by_did(&identity_did)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", identity_did))?;
+
+    // Load guardian config and verify guardian exists
+    let guardian_config = identity_manager_read
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found for this identity"))?;
+    drop(identity_manager_read);
+
+    // Verify the rejecter is actually an authorized guardian with Active status
+    let guardian = guardian_config
+        .guardians
+        .values()
+        .find(|g| g.guardian_did == req.guardian_did && g.status == GuardianStatus::Active)
+        .ok_or_else(|| anyhow::anyhow!("Not an authorized guardian or guardian is not active"))?;
+
+    // Reject the recovery - verification method depends on whether this
+    // guardian is a ZHTP identity or an Ethereum wallet, same as approval
+    let mut manager = recovery_manager.write().await;
+    let recovery_request_mut = manager
+        .get_request_mut(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+
+    let rejection_result = match guardian.guardian_type {
+        GuardianType::ZhtpIdentity => {
+            let signature_bytes = req
+                .signature
+                .ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            validate_signature_length(&signature_bytes)?;
+            let signature = PostQuantumSignature {
+                signature: signature_bytes,
+                public_key: guardian.public_key.clone(),
+                algorithm: SignatureAlgorithm::Dilithium2,
+                timestamp: chrono::Utc::now().timestamp() as u64,
+            };
+            recovery_request_mut.reject_approval(guardian, &req.nonce, req.timestamp, &signature)
+        }
+        GuardianType::EthereumWallet => {
+            let siwe_message = req
+                .siwe_message
+                .ok_or_else(|| anyhow::anyhow!("Missing siwe_message"))?;
+            let signature_bytes = req
+                .signature
+                .ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            recovery_request_mut.reject_wallet_approval(guardian, &siwe_message, &signature_bytes)
+        }
+    };
+
+    rejection_result.map_err(|e| anyhow::anyhow!("Failed to reject recovery: {}", e))?;
+
+    // Audit: record the rejection against the identity being recovered
+    identity_manager.write().await.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            req.guardian_did.clone(),
+            lib_identity::AuditEventKind::RecoveryRejected,
+            identity_did.clone(),
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
+
+    // Security: Log recovery rejection
+    warn!(
+        recovery_id = %recovery_id,
+        guardian_did = %req.guardian_did,
+        client_ip = %client_ip,
+        "Guardian rejected recovery"
+    );
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&serde_json::json!({"status": "rejected"}))?,
+        None,
+    ))
+}
+
+async fn handle_complete_recovery(
+    uri: &str,
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Extract recovery_id from URI
+    let recovery_id = extract_recovery_id(uri)?;
+
+    // Security: Extract real client IP
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    // FIX P0-4 TOCTOU: Get identity DID first, then validate + complete atomically
+    let identity_did = {
+        let manager = recovery_manager.read().await;
+        let recovery_request = manager
+            .get_request(&recovery_id)
+            .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+        recovery_request.identity_did.clone()
+    };
+
+    // Get the identity ID from DID
+    let identity_manager_read = identity_manager.read().await;
+    let identity_id = identity_manager_read
+        .get_identity_id_by_did(&identity_did)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", identity_did))?;
+
+    let guardian_config = identity_manager_read
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found"))?
+        .clone();
+    drop(identity_manager_read);
+
+    // Complete recovery atomically (validate + complete under single write lock to prevent TOCTOU)
+    {
+        let mut manager = recovery_manager.write().await;
+        let recovery_request = manager
+            .get_request_mut(&recovery_id)
+            .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+
+        // Security: Re-verify all guardian approvals are from currently active guardians
+        // Do this WHILE holding the write lock to prevent race conditions
+        for (guardian_did, _) in &recovery_request.approvals {
+            let is_still_active = guardian_config
+                .guardians
+                .values()
+                .any(|g| &g.guardian_did == guardian_did && g.status == GuardianStatus::Active);
+
+            if !is_still_active {
+                return Err(anyhow::anyhow!(
+                    "Guardian {} is no longer active - recovery invalid",
+                    guardian_did
+                ));
+            }
+        }
+
+        // Security: If this identity's master seed was Shamir-split across
+        // guardians, reconstruct it from the shares guardians submitted
+        // alongside their approvals and verify it against the commitment
+        // recorded at distribution time before trusting the recovery -
+        // this catches corrupt/tampered shares that still happen to
+        // reconstruct to *some* 32-byte value
+        if guardian_config.master_seed_commitment.is_some() {
+            let submitted_shares: Vec<Vec<u8>> =
+                recovery_request.key_shares.values().cloned().collect();
+
+            let reconstructed_seed = guardian_config
+                .reconstruct_seed(&submitted_shares)
+                .map_err(|e| anyhow::anyhow!("Failed to reconstruct master seed: {}", e))?;
+
+            if !guardian_config.verify_seed_commitment(&reconstructed_seed) {
+                return Err(anyhow::anyhow!(
+                    "Reconstructed master seed does not match stored commitment - corrupt or invalid key shares"
+                ));
+            }
+        }
+
+        // Validation passed, complete the recovery
+        recovery_request
+            .complete()
+            .map_err(|e| anyhow::anyhow!("Failed to complete recovery: {}", e))?;
+    } // Lock dropped here automatically
+
+    // Create session token for recovered identity
+    let identity_id_clone = identity_id.clone();
+    let session_token = session_manager
+        .create_session(identity_id, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to create session: {}", e))?;
+
+    // Audit: record the completed recovery against the recovered identity
+    identity_manager.write().await.record_audit_event(
+        &identity_id_clone,
+        lib_identity::AuditEvent::new(
+            identity_did.clone(),
+            lib_identity::AuditEventKind::RecoveryCompleted,
+            identity_did.clone(),
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
+
+    // Security: Log successful recovery completion
+    info!(
+        recovery_id = %recovery_id,
+        identity_did = %identity_did,
+        identity_id = %hex::encode(identity_id_clone.as_bytes()),
+        client_ip = %client_ip,
+        "Recovery completed successfully"
+    );
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&serde_json::json!({
+            "status": "success",
+            "session_token": session_token,
+            "identity_did": identity_did,
+        }))?,
+        None,
+    ))
+}
+
+async fn handle_reconstruct_recovery(
+    uri: &str,
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Extract recovery_id from URI
+    let recovery_id = extract_recovery_id(uri)?;
+
+    // Parse request
+    let req: ReconstructRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+    if let Some(ref device_key) = req.new_device_public_key {
+        validate_public_key_length(device_key)?;
+    }
+
+    // Security: Extract real client IP
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    // Security: Validate session
+    let session_token_obj = session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| {
+            warn!(
+                client_ip = %client_ip,
+                error = %e,
+                "Session validation failed in reconstruct_recovery"
+            );
+            anyhow::anyhow!("Session validation failed: {}", e)
+        })?;
+
+    let manager = recovery_manager.read().await;
+    let recovery_request = manager
+        .get_request(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+
+    // Security: Only the identity being recovered (not any one of its
+    // individually-partially-trusted guardians) may harvest the collected
+    // shares
+    let identity_manager_read = identity_manager.read().await;
+    let recovery_identity_id = identity_manager_read
+        .get_identity_id_by_did(&recovery_request.identity_did)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", recovery_request.identity_did))?;
+    drop(identity_manager_read);
+
+    if session_token_obj.identity_id != recovery_identity_id {
+        error!(
+            session_identity = %hex::encode(session_token_obj.identity_id.as_bytes()),
+            recovery_identity = %hex::encode(recovery_identity_id.as_bytes()),
+            client_ip = %client_ip,
+            "Authorization denied: session identity mismatch"
+        );
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
+
+    // Security: Refuse to hand out shares once the request is no longer
+    // in a state that's supposed to be reconstructable
+    if recovery_request.is_expired() {
+        return Err(anyhow::anyhow!("Recovery request has expired"));
+    }
+    if recovery_request.status != RecoveryStatus::Approved {
+        return Err(anyhow::anyhow!(
+            "Recovery is not approved (status: {:?})",
+            recovery_request.status
+        ));
+    }
+
+    // Security: Refuse to hand out shares until threshold approvals and
+    // threshold submitted shares are both in place
+    if recovery_request.approval_count() < recovery_request.threshold {
+        return Err(anyhow::anyhow!(
+            "Insufficient approvals: {} of {} required",
+            recovery_request.approval_count(),
+            recovery_request.threshold
+        ));
+    }
+    if recovery_request.key_shares.len() < recovery_request.threshold {
+        return Err(anyhow::anyhow!(
+            "Insufficient key shares submitted: {} of {} required",
+            recovery_request.key_shares.len(),
+            recovery_request.threshold
+        ));
+    }
+
+    let identity_did = recovery_request.identity_did.clone();
+    let shares: Vec<Vec<u8>> = recovery_request.key_shares.values().cloned().collect();
+    drop(manager);
+
+    // Security: Verify the collected shares actually reconstruct the
+    // master seed (malformed or mismatched shares fail here) before
+    // handing anything back to the client
+    let identity_manager_read = identity_manager.read().await;
+    let identity_id = identity_manager_read
+        .get_identity_id_by_did(&identity_did)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", identity_did))?;
+    let guardian_config = identity_manager_read
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardian config found for this identity"))?;
+    drop(identity_manager_read);
+
+    guardian_config
+        .reconstruct_seed(&shares)
+        .map_err(|e| anyhow::anyhow!("Failed to reconstruct master seed: {}", e))?;
+
+    // Hand back the shares themselves (optionally re-encrypted to the new
+    // device's key) rather than the reconstructed seed, so the seed is
+    // never assembled anywhere but the client.
+    let output_shares = match &req.new_device_public_key {
+        Some(device_key) => {
+            let device_public_key = PublicKey::new(device_key.clone());
+            shares
+                .iter()
+                .map(|share| {
+                    lib_crypto::hybrid_encrypt(share, &device_public_key)
+                        .map_err(|e| anyhow::anyhow!("Failed to re-encrypt share: {}", e))
+                })
+                .collect::<Result<Vec<_>>>()?
+        }
+        None => shares,
+    };
+
+    info!(
+        recovery_id = %recovery_id,
+        identity_did = %identity_did,
+        client_ip = %client_ip,
+        share_count = output_shares.len(),
+        "Recovery key shares released for reconstruction"
+    );
+
+    let response = ReconstructRecoveryResponse {
+        status: "reconstructed".to_string(),
+        shares: output_shares,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_recovery_status(
+    uri: &str,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+) -> Result<ZhtpResponse> {
+    // Extract recovery_id from URI
+    let recovery_id = extract_recovery_id(uri)?;
+
+    // A querying guardian supplies its own DID to be issued (or handed
+    // back, if already issued and still valid) the single-use challenge
+    // nonce it must embed in its signed approve/reject tuple
+    let query = parse_query_string(uri);
+    let guardian_did = query.get("guardian_did").cloned();
+
+    let mut manager = recovery_manager.write().await;
+    let recovery_request = manager
+        .get_request_mut(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+
+    let guardian_nonce = guardian_did.map(|did| recovery_request.issue_guardian_nonce(&did));
+
+    let response = RecoveryStatusResponse {
+        recovery_id: recovery_request.recovery_id.clone(),
+        status: format!("{:?}", recovery_request.status),
+        approvals: recovery_request.approval_count(),
+        required: recovery_request.threshold,
+        expires_at: recovery_request.expires_at.timestamp(),
+        identity_did: recovery_request.identity_did.clone(),
+        guardian_nonce,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_pending_recoveries(
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Security: Extract session token from Authorization header
+    let session_token = request
+        .headers
+        .get("Authorization")
+        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
+        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
+
+    // Security: Validate guardian's session
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
+
+    let guardian_identity_id = session_token_obj.identity_id;
+
+    // Get the guardian's DID
+    let identity_manager_read = identity_manager.read().await;
+    let guardian_did = identity_manager_read
+        .get_did_by_identity_id(&guardian_identity_id)
+        .ok_or_else(|| anyhow::anyhow!("Guardian identity not found"))?;
+    drop(identity_manager_read);
+
+    // Get all pending recovery requests from recovery manager
+    let all_requests = {
+        let manager = recovery_manager.read().await;
+        manager.get_all_pending_requests().iter().map(|r| (*r).clone()).collect::<Vec<_>>()
+    }; // Lock dropped here automatically
+
+    // Acquire identity manager lock once for all lookups
+    let identity_manager_read = identity_manager.read().await;
+
+    // Filter requests where this guardian is authorized
+    let pending_requests: Vec<PendingRecoveryInfo> = all_requests
+        .into_iter()
+        .filter_map(|recovery_request| {
+            // Get the identity being recovered
+            let identity_id = identity_manager_read.get_identity_id_by_did(&recovery_request.identity_did)?;
+
+            // Check if this guardian is authorized for this identity
+            let guardian_config = identity_manager_read.get_guardian_config(&identity_id)?;
+
+            // Check if guardian_did is in the authorized guardians list with Active status
+            let is_authorized = guardian_config
+                .guardians
+                .values()
+                .any(|g| g.guardian_did == guardian_did && g.status == GuardianStatus::Active);
+
+            if is_authorized {
+                Some(PendingRecoveryInfo {
+                    recovery_id: recovery_request.recovery_id.clone(),
+                    identity_did: recovery_request.identity_did.clone(),
+                    initiated_at: recovery_request.initiated_at.timestamp(),
+                    expires_at: recovery_request.expires_at.timestamp(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    drop(identity_manager_read);
+
+    let response = PendingRecoveriesResponse {
+        pending_requests,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_refresh_session(
+    body: &[u8],
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    let req: RefreshSessionRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    // Security: Extract real client IP and User-Agent for binding validation
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let (session_token, refresh_token) = session_manager
+        .refresh_token_pair(&req.refresh_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| {
+            warn!(client_ip = %client_ip, error = %e, "Refresh token rotation failed");
+            anyhow::anyhow!("Failed to refresh session: {}", e)
+        })?;
+
+    info!(client_ip = %client_ip, "Session token pair rotated");
+
+    let response = RefreshSessionResponse {
+        status: "refreshed".to_string(),
+        session_token,
+        refresh_token,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_audit_log(
+    uri: &str,
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Security: Extract and validate session token from Authorization header
+    let session_token = request
+        .headers
+        .get("Authorization")
+        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
+        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
+
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
+
+    // Security: Only ever the caller's own identity - never accepts a
+    // target identity from the query string
+    let identity_id = session_token_obj.identity_id;
+
+    let query = parse_query_string(uri);
+    let kind_filter = query.get("kind").cloned();
+    let since = query.get("since").and_then(|v| v.parse::<i64>().ok());
+    let until = query.get("until").and_then(|v| v.parse::<i64>().ok());
+
+    let manager_read = identity_manager.read().await;
+    let mut events = manager_read.get_audit_events(&identity_id);
+    drop(manager_read);
+
+    events.sort_by_key(|e| e.timestamp);
+
+    let events: Vec<AuditEventInfo> = events
+        .into_iter()
+        .filter(|e| kind_filter.as_deref().map_or(true, |k| format!("{:?}", e.kind) == k))
+        .filter(|e| since.map_or(true, |s| e.timestamp.timestamp() >= s))
+        .filter(|e| until.map_or(true, |u| e.timestamp.timestamp() <= u))
+        .map(|e| AuditEventInfo {
+            actor_did: e.actor_did,
+            kind: format!("{:?}", e.kind),
+            target_identity_did: e.target_identity_did,
+            client_ip: e.client_ip,
+            user_agent: e.user_agent,
+            timestamp: e.timestamp.timestamp(),
+        })
+        .collect();
+
+    let response = AuditLogResponse { events };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_export_guardians(
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    // Security: Extract and validate session token from Authorization header
+    let session_token = request
+        .headers
+        .get("Authorization")
+        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
+        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
+
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
+
+    let identity_id = session_token_obj.identity_id;
+
+    let manager_read = identity_manager.read().await;
+    let guardian_config = manager_read
+        .get_guardian_config(&identity_id)
+        .unwrap_or_default();
+    let identity_did = manager_read
+        .get_did_by_identity_id(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found"))?;
+    let master_seed = manager_read
+        .get_identity_seed(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found"))?;
+    drop(manager_read);
+
+    let recovery_requests: Vec<lib_identity::RecoveryRequest> = recovery_manager
+        .read()
+        .await
+        .get_requests_for_identity(&identity_did)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    let backup = GuardianBackup {
+        guardian_config,
+        recovery_requests,
+    };
+    let serialized = serde_json::to_vec(&backup)?;
+
+    let backup_key = lib_crypto::hash_blake3(&[&master_seed[..], b"ZHTP_guardian_backup_v1"].concat());
+    let blob = lib_crypto::symmetric::encrypt_data_with_ad(
+        &serialized,
+        &backup_key,
+        identity_id.as_bytes(),
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to encrypt guardian backup: {}", e))?;
+
+    info!(
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        client_ip = %client_ip,
+        "Guardian configuration and recovery state exported"
+    );
+
+    let response = ExportGuardiansResponse {
+        status: "exported".to_string(),
+        blob,
+    };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+async fn handle_import_guardians(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    let req: ImportGuardiansRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
+        .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
+
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
     }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
 
-    // Initiate recovery
-    let mut manager = recovery_manager.write().await;
-    let client_ip_clone = client_ip.clone();
-    let recovery_id = manager
-        .initiate_recovery(
-            req.identity_did.clone(),
-            &guardian_config,
-            req.requester_device,
-            client_ip,
-        )
-        .map_err(|e| anyhow::anyhow!("Failed to initiate recovery: {}", e))?;
+    // Security: Verify session belongs to this identity
+    if session_token_obj.identity_id != identity_id {
+        error!(
+            session_identity = %hex::encode(session_token_obj.identity_id.as_bytes()),
+            requested_identity = %hex::encode(identity_id.as_bytes()),
+            client_ip = %client_ip,
+            "Authorization denied: session identity mismatch"
+        );
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
 
-    let recovery_request = manager
-        .get_request(&recovery_id)
-        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+    // Run the whole check-then-restore under a single write lock to avoid races
+    let mut manager_write = identity_manager.write().await;
+    let master_seed = manager_write
+        .get_identity_seed(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found"))?;
+
+    let backup_key = lib_crypto::hash_blake3(&[&master_seed[..], b"ZHTP_guardian_backup_v1"].concat());
+    let serialized = lib_crypto::symmetric::decrypt_data_with_ad(
+        &req.blob,
+        &backup_key,
+        identity_id.as_bytes(),
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to decrypt guardian backup: {}", e))?;
+
+    let backup: GuardianBackup = serde_json::from_slice(&serialized)
+        .map_err(|e| anyhow::anyhow!("Invalid guardian backup contents: {}", e))?;
+
+    // Security: Refuse to silently drop Active guardians the imported
+    // backup doesn't know about, unless the caller explicitly opts in
+    if !req.overwrite {
+        if let Some(existing_config) = manager_write.get_guardian_config(&identity_id) {
+            let would_be_dropped = existing_config
+                .get_active_guardians()
+                .iter()
+                .any(|g| !backup.guardian_config.guardians.contains_key(&g.guardian_id));
+
+            if would_be_dropped {
+                return Err(anyhow::anyhow!(
+                    "Import would drop active guardians not present in the backup; pass overwrite=true to proceed"
+                ));
+            }
+        }
+    }
+
+    let guardians_restored = backup.guardian_config.guardians.len();
+    manager_write
+        .set_guardian_config(&identity_id, backup.guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist imported guardian config: {}", e))?;
+    drop(manager_write);
+
+    let mut recovery_manager_write = recovery_manager.write().await;
+    for recovery_request in &backup.recovery_requests {
+        recovery_manager_write.restore_request(recovery_request.clone());
+    }
+    let recovery_requests_restored = backup.recovery_requests.len();
+    drop(recovery_manager_write);
 
-    // Security: Log recovery initiation
     info!(
-        identity_did = %req.identity_did,
-        recovery_id = %recovery_id,
-        guardians_required = recovery_request.threshold,
-        client_ip = %client_ip_clone,
-        requester_device = %recovery_request.requester_device,
-        "Recovery initiated"
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardians_restored = guardians_restored,
+        recovery_requests_restored = recovery_requests_restored,
+        client_ip = %client_ip,
+        "Guardian configuration and recovery state imported"
     );
 
-    let response = InitiateRecoveryResponse {
-        status: "initiated".to_string(),
-        recovery_id,
-        guardians_required: recovery_request.threshold,
-        guardians_approved: 0,
-        expires_at: recovery_request.expires_at.timestamp(),
+    let response = ImportGuardiansResponse {
+        status: "imported".to_string(),
+        guardians_restored,
+        recovery_requests_restored,
     };
 
     Ok(ZhtpResponse::success(
@@ -572,104 +2686,267 @@ async fn handle_initiate_recovery(
     ))
 }
 
-async fn handle_approve_recovery(
-    uri: &str,
+async fn handle_batch_approve_recovery(
     body: &[u8],
     identity_manager: Arc<RwLock<IdentityManager>>,
     recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
     session_manager: Arc<SessionManager>,
     request: &ZhtpRequest,
 ) -> Result<ZhtpResponse> {
-    // Extract recovery_id from URI
-    let recovery_id = extract_recovery_id(uri)?;
-
-    // Parse request
-    let req: ApproveRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
+    let req: BatchApproveRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
         anyhow::anyhow!("Invalid request body: {}", e)
     })?;
 
-    // Security: Validate inputs
-    validate_did(&req.guardian_did)?;
-    validate_signature_length(&req.signature)?;
+    validate_guardian_identifier(&req.guardian_did)?;
 
-    // Security: Extract real client IP
     let client_ip = extract_client_ip(request);
     let user_agent = extract_user_agent(request);
 
-    // Security: Validate guardian's session
+    // Security: Validate the guardian's session once for the whole batch
     session_manager
         .validate_session(&req.session_token, &client_ip, &user_agent)
         .await
         .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
 
-    // Get the recovery request to find the identity being recovered
-    let manager = recovery_manager.read().await;
-    let recovery_request = manager
-        .get_request(&recovery_id)
-        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
-    let identity_did = recovery_request.identity_did.clone();
+    // Security: Keep the whole batch under a single recovery_manager write
+    // lock so one bad signature can't leave the batch interleaved with a
+    // concurrent reject/complete on one of the same recovery requests
+    let mut manager = recovery_manager.write().await;
+
+    let mut results = Vec::with_capacity(req.approvals.len());
+    for item in req.approvals {
+        let outcome = approve_one_recovery(
+            &mut manager,
+            &identity_manager,
+            &item.recovery_id,
+            &req.guardian_did,
+            &item.nonce,
+            item.timestamp,
+            item.signature,
+            item.siwe_message,
+            item.key_share,
+            &client_ip,
+            &user_agent,
+        )
+        .await;
+
+        results.push(match outcome {
+            Ok((approvals, required)) => BatchApprovalResult {
+                recovery_id: item.recovery_id,
+                status: "approved".to_string(),
+                approvals: Some(approvals),
+                required: Some(required),
+                error: None,
+            },
+            Err(e) => {
+                warn!(
+                    recovery_id = %item.recovery_id,
+                    guardian_did = %req.guardian_did,
+                    client_ip = %client_ip,
+                    error = %e,
+                    "Failed guardian approval attempt in batch"
+                );
+                BatchApprovalResult {
+                    recovery_id: item.recovery_id,
+                    status: "error".to_string(),
+                    approvals: None,
+                    required: None,
+                    error: Some(e.to_string()),
+                }
+            }
+        });
+    }
     drop(manager);
 
-    // Get the identity ID from DID
+    info!(
+        guardian_did = %req.guardian_did,
+        client_ip = %client_ip,
+        batch_size = results.len(),
+        "Batch guardian approval processed"
+    );
+
+    let response = BatchApproveRecoveryResponse { results };
+
+    Ok(ZhtpResponse::success(
+        serde_json::to_vec(&response)?,
+        None,
+    ))
+}
+
+/// Apply one guardian approval within an already-held `recovery_manager`
+/// write lock. Mirrors the per-item verification `handle_approve_recovery`
+/// does under its own locks, but reused here so a bad item in a batch can
+/// fail independently of the rest.
+async fn approve_one_recovery(
+    manager: &mut SocialRecoveryManager,
+    identity_manager: &Arc<RwLock<IdentityManager>>,
+    recovery_id: &str,
+    guardian_did: &str,
+    nonce: &str,
+    timestamp: i64,
+    signature: Option<Vec<u8>>,
+    siwe_message: Option<String>,
+    key_share: Option<Vec<u8>>,
+    client_ip: &str,
+    user_agent: &str,
+) -> Result<(usize, usize)> {
+    let identity_did = manager
+        .get_request(recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?
+        .identity_did
+        .clone();
+
     let identity_manager_read = identity_manager.read().await;
     let identity_id = identity_manager_read
         .get_identity_id_by_did(&identity_did)
         .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", identity_did))?;
-
-    // Load guardian config and verify guardian exists
     let guardian_config = identity_manager_read
         .get_guardian_config(&identity_id)
         .ok_or_else(|| anyhow::anyhow!("No guardian config found for this identity"))?;
     drop(identity_manager_read);
 
-    // Verify the approver is actually an authorized guardian with Active status
     let guardian = guardian_config
         .guardians
         .values()
-        .find(|g| g.guardian_did == req.guardian_did && g.status == GuardianStatus::Active)
+        .find(|g| g.guardian_did == guardian_did && g.status == GuardianStatus::Active)
         .ok_or_else(|| anyhow::anyhow!("Not an authorized guardian or guardian is not active"))?;
 
-    // Add approval with signature verification
-    let signature = PostQuantumSignature {
-        signature: req.signature,
-        public_key: guardian.public_key.clone(),
-        algorithm: SignatureAlgorithm::Dilithium2,
-        timestamp: chrono::Utc::now().timestamp() as u64,
-    };
-
-    let mut manager = recovery_manager.write().await;
     let recovery_request = manager
-        .get_request_mut(&recovery_id)
+        .get_request_mut(recovery_id)
         .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
 
-    recovery_request
-        .add_approval(guardian, signature)
+    match guardian.guardian_type {
+        GuardianType::ZhtpIdentity => {
+            let signature_bytes = signature.ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            validate_signature_length(&signature_bytes)?;
+            let signature = PostQuantumSignature {
+                signature: signature_bytes,
+                public_key: guardian.public_key.clone(),
+                algorithm: SignatureAlgorithm::Dilithium2,
+                timestamp: chrono::Utc::now().timestamp() as u64,
+            };
+            recovery_request
+                .add_approval(guardian, nonce, timestamp, signature)
+                .map_err(|e| anyhow::anyhow!("Failed to add approval: {}", e))?;
+        }
+        GuardianType::EthereumWallet => {
+            let siwe_message = siwe_message.ok_or_else(|| anyhow::anyhow!("Missing siwe_message"))?;
+            let signature_bytes = signature.ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            recovery_request
+                .add_wallet_approval(guardian, &siwe_message, &signature_bytes)
+                .map_err(|e| anyhow::anyhow!("Failed to add approval: {}", e))?;
+        }
+    }
+
+    if let Some(share) = key_share {
+        recovery_request
+            .submit_key_share(guardian_did, share)
+            .map_err(|e| anyhow::anyhow!("Failed to submit key share: {}", e))?;
+    }
+
+    let approvals = recovery_request.approval_count();
+    let required = recovery_request.threshold;
+
+    if recovery_request.status == RecoveryStatus::Approved {
+        notify_guardians(
+            &guardian_config,
+            GuardianNotificationEvent::ThresholdMet,
+            recovery_id.to_string(),
+            recovery_request.requester_device.clone(),
+            recovery_request.expires_at.timestamp(),
+        );
+    }
+
+    // Audit: record the approval against the identity being recovered
+    identity_manager.write().await.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            guardian_did.to_string(),
+            lib_identity::AuditEventKind::RecoveryApproved,
+            identity_did.clone(),
+            client_ip.to_string(),
+            user_agent.to_string(),
+        ),
+    );
+
+    Ok((approvals, required))
+}
+
+async fn handle_grant_emergency_access(
+    body: &[u8],
+    identity_manager: Arc<RwLock<IdentityManager>>,
+    session_manager: Arc<SessionManager>,
+    request: &ZhtpRequest,
+) -> Result<ZhtpResponse> {
+    let req: GrantEmergencyAccessRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    if !(1..=8760).contains(&req.waiting_period_hours) {
+        return Err(anyhow::anyhow!("waiting_period_hours must be between 1 and 8760 (1 year)"));
+    }
+
+    let client_ip = extract_client_ip(request);
+    let user_agent = extract_user_agent(request);
+
+    let session_token_obj = session_manager
+        .validate_session(&req.session_token, &client_ip, &user_agent)
+        .await
         .map_err(|e| {
-            // Security: Log failed approval attempt
             warn!(
-                recovery_id = %recovery_id,
-                guardian_did = %req.guardian_did,
                 client_ip = %client_ip,
                 error = %e,
-                "Failed guardian approval attempt"
+                "Session validation failed in grant_emergency_access"
             );
-            anyhow::anyhow!("Failed to add approval: {}", e)
+            anyhow::anyhow!("Session validation failed: {}", e)
         })?;
 
-    // Security: Log successful approval
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
+
+    // Security: Verify session belongs to this identity
+    if session_token_obj.identity_id != identity_id {
+        error!(
+            session_identity = %hex::encode(session_token_obj.identity_id.as_bytes()),
+            requested_identity = %hex::encode(identity_id.as_bytes()),
+            client_ip = %client_ip,
+            "Authorization denied: session identity mismatch"
+        );
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
+
+    // Grant and persist under a single write lock to prevent race conditions
+    let mut manager_write = identity_manager.write().await;
+    let mut guardian_config = manager_write
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardians configured for this identity"))?;
+
+    guardian_config
+        .grant_emergency_access(&req.guardian_id, req.waiting_period_hours)
+        .map_err(|e| anyhow::anyhow!("Failed to grant emergency access: {}", e))?;
+
+    manager_write.set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+    drop(manager_write);
+
     info!(
-        recovery_id = %recovery_id,
-        guardian_did = %req.guardian_did,
-        approvals = recovery_request.approval_count(),
-        required = recovery_request.threshold,
+        identity_id = %hex::encode(identity_id.as_bytes()),
+        guardian_id = %req.guardian_id,
+        waiting_period_hours = req.waiting_period_hours,
         client_ip = %client_ip,
-        "Guardian approved recovery"
+        "Guardian granted standing emergency access"
     );
 
-    let response = ApproveRecoveryResponse {
-        status: "approved".to_string(),
-        approvals: recovery_request.approval_count(),
-        required: recovery_request.threshold,
+    let response = GrantEmergencyAccessResponse {
+        status: "granted".to_string(),
+        guardian_id: req.guardian_id,
+        waiting_period_hours: req.waiting_period_hours,
     };
 
     Ok(ZhtpResponse::success(
@@ -678,192 +2955,225 @@ async fn handle_approve_recovery(
     ))
 }
 
-async fn handle_reject_recovery(
+async fn handle_emergency_initiation_nonce(
     uri: &str,
-    body: &[u8],
     identity_manager: Arc<RwLock<IdentityManager>>,
-    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
-    session_manager: Arc<SessionManager>,
-    request: &ZhtpRequest,
 ) -> Result<ZhtpResponse> {
-    // Extract recovery_id from URI
-    let recovery_id = extract_recovery_id(uri)?;
-
-    // Parse request to get guardian_did
-    let req: ApproveRecoveryRequest = serde_json::from_slice(body).map_err(|e| {
-        anyhow::anyhow!("Invalid request body: {}", e)
-    })?;
-
-    // Security: Extract real client IP
-    let client_ip = extract_client_ip(request);
-    let user_agent = extract_user_agent(request);
-
-    // Security: Validate guardian's session
-    session_manager
-        .validate_session(&req.session_token, &client_ip, &user_agent)
-        .await
-        .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
-
-    // Get the recovery request to find the identity being recovered
-    let manager = recovery_manager.read().await;
-    let recovery_request = manager
-        .get_request(&recovery_id)
-        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
-    let identity_did = recovery_request.identity_did.clone();
-    drop(manager);
+    let query = parse_query_string(uri);
+    let identity_did = query
+        .get("identity_did")
+        .ok_or_else(|| anyhow::anyhow!("Missing identity_did query parameter"))?;
+    let guardian_did = query
+        .get("guardian_did")
+        .ok_or_else(|| anyhow::anyhow!("Missing guardian_did query parameter"))?;
 
-    // Get the identity ID from DID
-    let identity_manager_read = identity_manager.read().await;
-    let identity_id = identity_manager_read
-        .get_identity_id_by_did(&identity_did)
+    let mut manager_write = identity_manager.write().await;
+    let identity_id = manager_write
+        .get_identity_id_by_did(identity_did)
         .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", identity_did))?;
 
-    // Load guardian config and verify guardian exists
-    let guardian_config = identity_manager_read
+    let mut guardian_config = manager_write
         .get_guardian_config(&identity_id)
-        .ok_or_else(|| anyhow::anyhow!("No guardian config found for this identity"))?;
-    drop(identity_manager_read);
+        .ok_or_else(|| anyhow::anyhow!("No guardians configured for this identity"))?;
 
-    // Verify the rejecter is actually an authorized guardian with Active status
-    let _guardian = guardian_config
+    let guardian = guardian_config
         .guardians
         .values()
-        .find(|g| g.guardian_did == req.guardian_did && g.status == GuardianStatus::Active)
-        .ok_or_else(|| anyhow::anyhow!("Not an authorized guardian or guardian is not active"))?;
+        .find(|g| &g.guardian_did == guardian_did && g.status == GuardianStatus::Active)
+        .ok_or_else(|| anyhow::anyhow!("Not an authorized guardian or guardian is not active"))?
+        .clone();
 
-    // Reject the recovery
-    let mut manager = recovery_manager.write().await;
-    let recovery_request_mut = manager
-        .get_request_mut(&recovery_id)
-        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+    let nonce = guardian_config.issue_emergency_nonce(&guardian.guardian_id);
 
-    recovery_request_mut
-        .reject_approval(&req.guardian_did)
-        .map_err(|e| anyhow::anyhow!("Failed to reject recovery: {}", e))?;
+    manager_write
+        .set_guardian_config(&identity_id, guardian_config)
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
 
-    // Security: Log recovery rejection
-    warn!(
-        recovery_id = %recovery_id,
-        guardian_did = %req.guardian_did,
-        client_ip = %client_ip,
-        "Guardian rejected recovery"
-    );
+    let response = EmergencyInitiationNonceResponse { nonce };
 
     Ok(ZhtpResponse::success(
-        serde_json::to_vec(&serde_json::json!({"status": "rejected"}))?,
+        serde_json::to_vec(&response)?,
         None,
     ))
 }
 
-async fn handle_complete_recovery(
-    uri: &str,
+async fn handle_initiate_emergency_access(
+    body: &[u8],
     identity_manager: Arc<RwLock<IdentityManager>>,
     recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
-    session_manager: Arc<SessionManager>,
+    rate_limiter: Arc<RateLimiter>,
     request: &ZhtpRequest,
 ) -> Result<ZhtpResponse> {
-    // Extract recovery_id from URI
-    let recovery_id = extract_recovery_id(uri)?;
+    let req: InitiateEmergencyAccessRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
+
+    // Security: Validate inputs
+    validate_did(&req.identity_did)?;
+    validate_guardian_identifier(&req.guardian_did)?;
+    validate_device_name(&req.requester_device)?;
 
-    // Security: Extract real client IP
     let client_ip = extract_client_ip(request);
     let user_agent = extract_user_agent(request);
 
-    // FIX P0-4 TOCTOU: Get identity DID first, then validate + complete atomically
-    let identity_did = {
-        let manager = recovery_manager.read().await;
-        let recovery_request = manager
-            .get_request(&recovery_id)
-            .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
-        recovery_request.identity_did.clone()
-    };
-
-    // Get the identity ID from DID
-    let identity_manager_read = identity_manager.read().await;
-    let identity_id = identity_manager_read
-        .get_identity_id_by_did(&identity_did)
-        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", identity_did))?;
-
-    let guardian_config = identity_manager_read
-        .get_guardian_config(&identity_id)
-        .ok_or_else(|| anyhow::anyhow!("No guardian config found"))?
-        .clone();
-    drop(identity_manager_read);
-
-    // Complete recovery atomically (validate + complete under single write lock to prevent TOCTOU)
-    {
-        let mut manager = recovery_manager.write().await;
-        let recovery_request = manager
-            .get_request_mut(&recovery_id)
-            .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
-
-        // Security: Re-verify all guardian approvals are from currently active guardians
-        // Do this WHILE holding the write lock to prevent race conditions
-        for (guardian_did, _) in &recovery_request.approvals {
-            let is_still_active = guardian_config
-                .guardians
-                .values()
-                .any(|g| &g.guardian_did == guardian_did && g.status == GuardianStatus::Active);
-
-            if !is_still_active {
-                return Err(anyhow::anyhow!(
-                    "Guardian {} is no longer active - recovery invalid",
-                    guardian_did
-                ));
-            }
-        }
+    // Security: Rate limit emergency access initiation (3 attempts per 24 hours)
+    if let Err(response) = rate_limiter.check_rate_limit_aggressive(&client_ip, 3, 86400).await {
+        return Ok(response);
+    }
 
-        // Validation passed, complete the recovery
-        recovery_request
-            .complete()
-            .map_err(|e| anyhow::anyhow!("Failed to complete recovery: {}", e))?;
-    } // Lock dropped here automatically
+    // Load with a write lock up front - a valid signature consumes a
+    // single-use nonce recorded on the guardian config, so this handler
+    // mutates identity state even before any recovery request exists
+    let mut manager_write = identity_manager.write().await;
+    let identity_id = manager_write
+        .get_identity_id_by_did(&req.identity_did)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found for DID: {}", req.identity_did))?;
 
-    // Create session token for recovered identity
-    let identity_id_clone = identity_id.clone();
-    let session_token = session_manager
-        .create_session(identity_id, &client_ip, &user_agent)
-        .await
-        .map_err(|e| anyhow::anyhow!("Failed to create session: {}", e))?;
+    let mut guardian_config = manager_write
+        .get_guardian_config(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("No guardians configured for this identity"))?;
 
-    // Security: Log successful recovery completion
-    info!(
-        recovery_id = %recovery_id,
-        identity_did = %identity_did,
-        identity_id = %hex::encode(identity_id_clone.as_bytes()),
-        client_ip = %client_ip,
-        "Recovery completed successfully"
+    // Security: Confirm the initiating guardian is active and holds a
+    // standing emergency access grant - this is what distinguishes
+    // emergency access from any guardian being able to bypass the threshold
+    let guardian = guardian_config
+        .guardians
+        .values()
+        .find(|g| g.guardian_did == req.guardian_did && g.status == GuardianStatus::Active)
+        .ok_or_else(|| anyhow::anyhow!("Not an authorized guardian or guardian is not active"))?
+        .clone();
+
+    let waiting_period_hours = guardian_config
+        .get_emergency_grant(&guardian.guardian_id)
+        .ok_or_else(|| anyhow::anyhow!("Guardian does not have a standing emergency access grant"))?
+        .waiting_period_hours;
+
+    // Security: A guardian's DID is not a secret - it's returned by both
+    // `handle_list_guardians` and `handle_search_identity` - so starting an
+    // emergency takeover countdown must require proof of key control over
+    // the nonce-bound tuple, exactly like `add_approval`/`add_wallet_approval`
+    // require for an in-flight recovery
+    let message = format!(
+        "{}:{}:{}:initiate-emergency:{}",
+        req.identity_did, req.guardian_did, req.nonce, req.timestamp
     );
 
-    Ok(ZhtpResponse::success(
-        serde_json::to_vec(&serde_json::json!({
-            "status": "success",
-            "session_token": session_token,
-            "identity_did": identity_did,
-        }))?,
-        None,
-    ))
-}
+    let verification_result = match guardian.guardian_type {
+        GuardianType::ZhtpIdentity => {
+            let signature_bytes = req
+                .signature
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            validate_signature_length(&signature_bytes)?;
+            let public_key_bytes = guardian.public_key.as_bytes();
+            lib_crypto::verify_signature(message.as_bytes(), &signature_bytes, &public_key_bytes)
+                .map_err(|e| anyhow::anyhow!("Signature verification failed: {}", e))
+        }
+        GuardianType::EthereumWallet => {
+            let siwe_message_text = req
+                .siwe_message
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("Missing siwe_message"))?;
+            let signature_bytes = req
+                .signature
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("Missing signature"))?;
+            (|| -> std::result::Result<bool, String> {
+                let siwe = SiweMessage::parse(&siwe_message_text)?;
+                if !siwe.binds_identity(&req.identity_did) {
+                    return Err("SIWE message does not reference this identity".to_string());
+                }
+                if !siwe.binds_action("initiate-emergency") {
+                    return Err("SIWE message does not authorize initiating emergency access".to_string());
+                }
+                if !siwe.is_fresh(300) {
+                    return Err("SIWE message issued-at timestamp is outside the freshness window".to_string());
+                }
+                let message_hash = lib_crypto::classical::secp256k1::eip191_hash(siwe_message_text.as_bytes());
+                lib_crypto::classical::secp256k1::verify_eth_signature(
+                    &message_hash,
+                    &signature_bytes,
+                    &guardian.guardian_did,
+                )
+                .map_err(|e| e.to_string())
+            })()
+            .map_err(|e| anyhow::anyhow!("Signature verification failed: {}", e))
+        }
+    };
 
-async fn handle_recovery_status(
-    uri: &str,
-    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
-) -> Result<ZhtpResponse> {
-    // Extract recovery_id from URI
-    let recovery_id = extract_recovery_id(uri)?;
+    let is_valid = verification_result?;
+    if !is_valid {
+        warn!(
+            identity_did = %req.identity_did,
+            guardian_did = %req.guardian_did,
+            client_ip = %client_ip,
+            "Rejected emergency access initiation: invalid guardian signature"
+        );
+        return Err(anyhow::anyhow!("Invalid guardian signature"));
+    }
+
+    // Security: Consume the nonce so this signature can't be replayed to
+    // start a second countdown
+    guardian_config
+        .verify_emergency_initiation_nonce(&guardian.guardian_id, &req.nonce, req.timestamp)
+        .map_err(|e| anyhow::anyhow!("Nonce verification failed: {}", e))?;
+
+    manager_write
+        .set_guardian_config(&identity_id, guardian_config.clone())
+        .map_err(|e| anyhow::anyhow!("Failed to persist guardian config: {}", e))?;
+    drop(manager_write);
+
+    let mut manager = recovery_manager.write().await;
+    let recovery_id = manager
+        .initiate_emergency_access(
+            req.identity_did.clone(),
+            &guardian_config,
+            &req.guardian_did,
+            waiting_period_hours,
+            req.requester_device,
+            client_ip.clone(),
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to initiate emergency access: {}", e))?;
 
-    let manager = recovery_manager.read().await;
     let recovery_request = manager
         .get_request(&recovery_id)
         .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?;
+    let takeover_available_at = recovery_request
+        .takeover_available_at
+        .ok_or_else(|| anyhow::anyhow!("Emergency request missing takeover time"))?
+        .timestamp();
+    let expires_at = recovery_request.expires_at.timestamp();
+    drop(manager);
 
-    let response = RecoveryStatusResponse {
-        recovery_id: recovery_request.recovery_id.clone(),
-        status: format!("{:?}", recovery_request.status),
-        approvals: recovery_request.approval_count(),
-        required: recovery_request.threshold,
-        expires_at: recovery_request.expires_at.timestamp(),
-        identity_did: recovery_request.identity_did.clone(),
+    // Audit: record the emergency access countdown against the identity being recovered
+    identity_manager.write().await.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            req.guardian_did.clone(),
+            lib_identity::AuditEventKind::RecoveryInitiated,
+            req.identity_did.clone(),
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
+
+    // Security: Log the countdown start so the owner's activity feed and
+    // operators can spot an emergency takeover in progress
+    warn!(
+        identity_did = %req.identity_did,
+        recovery_id = %recovery_id,
+        guardian_did = %req.guardian_did,
+        takeover_available_at = takeover_available_at,
+        client_ip = %client_ip,
+        "Emergency access countdown started"
+    );
+
+    let response = InitiateEmergencyAccessResponse {
+        status: "emergency_pending".to_string(),
+        recovery_id,
+        guardian_did: req.guardian_did,
+        takeover_available_at,
+        expires_at,
     };
 
     Ok(ZhtpResponse::success(
@@ -872,89 +3182,342 @@ async fn handle_recovery_status(
     ))
 }
 
-async fn handle_pending_recoveries(
+async fn handle_reject_emergency_access(
+    uri: &str,
+    body: &[u8],
     identity_manager: Arc<RwLock<IdentityManager>>,
     recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
     session_manager: Arc<SessionManager>,
     request: &ZhtpRequest,
 ) -> Result<ZhtpResponse> {
-    // Security: Extract session token from Authorization header
-    let session_token = request
-        .headers
-        .get("Authorization")
-        .and_then(|auth| auth.strip_prefix("Bearer ").map(|s| s.to_string()))
-        .ok_or_else(|| anyhow::anyhow!("Missing or invalid Authorization header"))?;
+    let recovery_id = extract_recovery_id(uri)?;
+
+    let req: RejectEmergencyAccessRequest = serde_json::from_slice(body).map_err(|e| {
+        anyhow::anyhow!("Invalid request body: {}", e)
+    })?;
 
-    // Security: Validate guardian's session
     let client_ip = extract_client_ip(request);
     let user_agent = extract_user_agent(request);
 
+    // Security: Only the identity owner (validated by session) can cancel
+    // an emergency access window, never the initiating guardian
     let session_token_obj = session_manager
-        .validate_session(&session_token, &client_ip, &user_agent)
+        .validate_session(&req.session_token, &client_ip, &user_agent)
         .await
-        .map_err(|e| anyhow::anyhow!("Invalid or expired session: {}", e))?;
-
-    let guardian_identity_id = session_token_obj.identity_id;
+        .map_err(|e| anyhow::anyhow!("Session validation failed: {}", e))?;
 
-    // Get the guardian's DID
-    let identity_manager_read = identity_manager.read().await;
-    let guardian_did = identity_manager_read
-        .get_did_by_identity_id(&guardian_identity_id)
-        .ok_or_else(|| anyhow::anyhow!("Guardian identity not found"))?;
-    drop(identity_manager_read);
+    let identity_id_bytes = hex::decode(&req.identity_id)
+        .map_err(|e| anyhow::anyhow!("Invalid identity_id format: {}", e))?;
+    if identity_id_bytes.len() != 32 {
+        return Err(anyhow::anyhow!("Invalid identity_id length"));
+    }
+    let mut id_array = [0u8; 32];
+    id_array.copy_from_slice(&identity_id_bytes);
+    let identity_id = lib_crypto::Hash::from_bytes(&id_array);
 
-    // Get all pending recovery requests from recovery manager
-    let all_requests = {
-        let manager = recovery_manager.read().await;
-        manager.get_all_pending_requests().iter().map(|r| (*r).clone()).collect::<Vec<_>>()
-    }; // Lock dropped here automatically
+    if session_token_obj.identity_id != identity_id {
+        error!(
+            session_identity = %hex::encode(session_token_obj.identity_id.as_bytes()),
+            requested_identity = %hex::encode(identity_id.as_bytes()),
+            client_ip = %client_ip,
+            "Authorization denied: session identity mismatch"
+        );
+        return Err(anyhow::anyhow!("Session identity mismatch - authorization denied"));
+    }
 
-    // Acquire identity manager lock once for all lookups
     let identity_manager_read = identity_manager.read().await;
+    let owner_did = identity_manager_read
+        .get_did_by_identity_id(&identity_id)
+        .ok_or_else(|| anyhow::anyhow!("Identity not found"))?;
+    drop(identity_manager_read);
 
-    // Filter requests where this guardian is authorized
-    let pending_requests: Vec<PendingRecoveryInfo> = all_requests
-        .into_iter()
-        .filter_map(|recovery_request| {
-            // Get the identity being recovered
-            let identity_id = identity_manager_read.get_identity_id_by_did(&recovery_request.identity_did)?;
-
-            // Check if this guardian is authorized for this identity
-            let guardian_config = identity_manager_read.get_guardian_config(&identity_id)?;
+    let mut manager = recovery_manager.write().await;
+    let target_identity_did = manager
+        .get_request(&recovery_id)
+        .ok_or_else(|| anyhow::anyhow!("Recovery request not found"))?
+        .identity_did
+        .clone();
 
-            // Check if guardian_did is in the authorized guardians list with Active status
-            let is_authorized = guardian_config
-                .guardians
-                .values()
-                .any(|g| g.guardian_did == guardian_did && g.status == GuardianStatus::Active);
+    // Security: The caller must own the identity this emergency request targets
+    if target_identity_did != owner_did {
+        return Err(anyhow::anyhow!("Session identity does not own this recovery request"));
+    }
 
-            if is_authorized {
-                Some(PendingRecoveryInfo {
-                    recovery_id: recovery_request.recovery_id.clone(),
-                    identity_did: recovery_request.identity_did.clone(),
-                    initiated_at: recovery_request.initiated_at.timestamp(),
-                    expires_at: recovery_request.expires_at.timestamp(),
-                })
-            } else {
-                None
-            }
-        })
-        .collect();
+    manager
+        .reject_emergency_access(&recovery_id)
+        .map_err(|e| anyhow::anyhow!("Failed to reject emergency access: {}", e))?;
+    drop(manager);
 
-    drop(identity_manager_read);
+    // Audit: record the owner's cancellation against their own identity
+    identity_manager.write().await.record_audit_event(
+        &identity_id,
+        lib_identity::AuditEvent::new(
+            owner_did.clone(),
+            lib_identity::AuditEventKind::RecoveryRejected,
+            owner_did.clone(),
+            client_ip.clone(),
+            user_agent.clone(),
+        ),
+    );
 
-    let response = PendingRecoveriesResponse {
-        pending_requests,
-    };
+    // Security: Log the cancellation - this is the owner reclaiming control
+    // before a guardian's emergency takeover would otherwise mature
+    warn!(
+        identity_did = %owner_did,
+        recovery_id = %recovery_id,
+        client_ip = %client_ip,
+        "Owner cancelled emergency access request"
+    );
 
     Ok(ZhtpResponse::success(
-        serde_json::to_vec(&response)?,
+        serde_json::to_vec(&serde_json::json!({"status": "cancelled"}))?,
         None,
     ))
 }
 
+/// Sweep all in-flight recovery requests and mature any `EmergencyPending`
+/// ones whose waiting period has elapsed to `Approved`, so
+/// `handle_complete_recovery` can complete them without a guardian
+/// threshold. Intended to be run periodically by a background task - see
+/// `spawn_emergency_access_sweep`.
+async fn sweep_emergency_access(
+    recovery_manager: &Arc<RwLock<SocialRecoveryManager>>,
+    identity_manager: &Arc<RwLock<IdentityManager>>,
+) {
+    let matured = recovery_manager.write().await.sweep_emergency_access();
+    for recovery_id in matured {
+        info!(recovery_id = %recovery_id, "Emergency access window elapsed, takeover now available");
+
+        let Some((identity_did, requester_device, expires_at)) = recovery_manager
+            .read()
+            .await
+            .get_request(&recovery_id)
+            .map(|r| (r.identity_did.clone(), r.requester_device.clone(), r.expires_at.timestamp()))
+        else {
+            continue;
+        };
+
+        let identity_manager_read = identity_manager.read().await;
+        let Some(identity_id) = identity_manager_read.get_identity_id_by_did(&identity_did) else {
+            continue;
+        };
+        let Some(guardian_config) = identity_manager_read.get_guardian_config(&identity_id) else {
+            continue;
+        };
+        drop(identity_manager_read);
+
+        notify_guardians(
+            &guardian_config,
+            GuardianNotificationEvent::EmergencyMatured,
+            recovery_id,
+            requester_device,
+            expires_at,
+        );
+    }
+}
+
+/// Start a background task that periodically matures elapsed emergency
+/// access windows. Mirrors `RateLimiter::start_cleanup_task`.
+pub fn spawn_emergency_access_sweep(
+    recovery_manager: Arc<RwLock<SocialRecoveryManager>>,
+    identity_manager: Arc<RwLock<IdentityManager>>,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(300)); // 5 minutes
+        loop {
+            interval.tick().await;
+            sweep_emergency_access(&recovery_manager, &identity_manager).await;
+        }
+    });
+}
+
+/// A guardian recovery lifecycle event worth push-notifying guardians about
+#[derive(Debug, Clone, Serialize)]
+enum GuardianNotificationEvent {
+    /// A new recovery request was initiated and needs guardian approval
+    RecoveryInitiated,
+
+    /// The approval threshold was just met
+    ThresholdMet,
+
+    /// A time-delayed emergency access window has matured
+    EmergencyMatured,
+}
+
+/// Payload pushed to a guardian's registered notification endpoint
+#[derive(Debug, Clone, Serialize)]
+struct GuardianNotificationPayload {
+    event: GuardianNotificationEvent,
+    recovery_id: String,
+    requester_device: String,
+    expires_at: i64,
+}
+
+/// Pluggable transport for pushing guardian notifications, so the HTTP
+/// transport below can be swapped (e.g. for a push-notification service)
+/// without touching dispatch logic. Mirrors `NotificationChannel` in
+/// `zhtp::monitoring::alerting`.
+#[async_trait::async_trait]
+trait GuardianNotifier: Send + Sync {
+    /// Push `payload` to `endpoint`. Implementations should not retry
+    /// internally - retry/backoff is handled by `dispatch_with_retry`.
+    async fn notify(&self, endpoint: &str, payload: &GuardianNotificationPayload) -> Result<()>;
+
+    /// Transport name, for logging
+    fn name(&self) -> &str;
+}
+
+/// Delivers a guardian notification as a JSON POST to the guardian's
+/// registered webhook-style endpoint
+struct HttpGuardianNotifier;
+
+impl HttpGuardianNotifier {
+    fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl GuardianNotifier for HttpGuardianNotifier {
+    async fn notify(&self, endpoint: &str, payload: &GuardianNotificationPayload) -> Result<()> {
+        // Security: Re-resolve and re-check the host on every dispatch, not
+        // just when the guardian registered the endpoint - a hostname valid
+        // at registration time could otherwise be repointed at internal
+        // infrastructure later (DNS rebinding).
+        let validated_addr = guard_against_ssrf(endpoint).await?;
+
+        let url = reqwest::Url::parse(endpoint)
+            .map_err(|e| anyhow::anyhow!("Notification endpoint is not a valid URL: {}", e))?;
+        let host = url
+            .host_str()
+            .ok_or_else(|| anyhow::anyhow!("Notification endpoint must include a host"))?
+            .to_string();
+        let port = url.port_or_known_default().unwrap_or(443);
+
+        // Security: Pin this client to connect to exactly the address that
+        // was just validated, and never follow redirects - otherwise a
+        // server that passes the initial check could still 3xx the
+        // request to a private address, or the client's own independent
+        // DNS resolution could land on an address that was never checked.
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .resolve(&host, std::net::SocketAddr::new(validated_addr.ip(), port))
+            .build()
+            .map_err(|e| anyhow::anyhow!("Failed to build notification client: {}", e))?;
+
+        let response = client.post(endpoint).json(payload).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "Notification endpoint returned status {}",
+                response.status()
+            ));
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "http"
+    }
+}
+
+/// Push `payload` to `endpoint` via `notifier`, retrying transient failures
+/// with exponential backoff up to 3 attempts total
+async fn dispatch_with_retry(
+    notifier: &dyn GuardianNotifier,
+    endpoint: &str,
+    payload: &GuardianNotificationPayload,
+) {
+    const MAX_ATTEMPTS: u32 = 3;
+    let mut delay = tokio::time::Duration::from_millis(500);
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        match notifier.notify(endpoint, payload).await {
+            Ok(()) => {
+                info!(
+                    endpoint = %endpoint,
+                    recovery_id = %payload.recovery_id,
+                    event = ?payload.event,
+                    transport = notifier.name(),
+                    attempt,
+                    "Guardian notification delivered"
+                );
+                return;
+            }
+            Err(e) if attempt < MAX_ATTEMPTS => {
+                warn!(
+                    endpoint = %endpoint,
+                    recovery_id = %payload.recovery_id,
+                    transport = notifier.name(),
+                    attempt,
+                    error = %e,
+                    "Guardian notification attempt failed, retrying"
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err(e) => {
+                error!(
+                    endpoint = %endpoint,
+                    recovery_id = %payload.recovery_id,
+                    transport = notifier.name(),
+                    attempts = MAX_ATTEMPTS,
+                    error = %e,
+                    "Guardian notification permanently failed"
+                );
+            }
+        }
+    }
+}
+
+/// Push-notify every active guardian with a registered notification
+/// endpoint. Non-blocking: spawns one background task per guardian so the
+/// calling HTTP handler returns promptly instead of waiting on delivery.
+fn notify_guardians(
+    guardian_config: &GuardianConfig,
+    event: GuardianNotificationEvent,
+    recovery_id: String,
+    requester_device: String,
+    expires_at: i64,
+) {
+    let notifier: Arc<dyn GuardianNotifier> = Arc::new(HttpGuardianNotifier::new());
+
+    for guardian in guardian_config.guardians.values() {
+        if guardian.status != GuardianStatus::Active {
+            continue;
+        }
+        let Some(endpoint) = guardian.notification_endpoint.clone() else {
+            continue;
+        };
+
+        let notifier = notifier.clone();
+        let payload = GuardianNotificationPayload {
+            event: event.clone(),
+            recovery_id: recovery_id.clone(),
+            requester_device: requester_device.clone(),
+            expires_at,
+        };
+
+        tokio::spawn(async move {
+            dispatch_with_retry(notifier.as_ref(), &endpoint, &payload).await;
+        });
+    }
+}
+
 // Helper functions
 
+/// Parse a `key=value&key2=value2` query string off the end of a URI
+fn parse_query_string(uri: &str) -> std::collections::HashMap<String, String> {
+    uri.split_once('?')
+        .map(|(_, query)| {
+            query
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn extract_client_ip(request: &ZhtpRequest) -> String {
     request
         .headers
@@ -1003,6 +3566,25 @@ fn validate_did(did: &str) -> Result<()> {
     Ok(())
 }
 
+/// Validate a guardian identifier that may be either a ZHTP DID or an
+/// Ethereum wallet address, since `guardian_did` now holds either
+/// depending on the guardian's `GuardianType`. Format-specific checks
+/// (e.g. `0x` + 40 hex chars) are left to the guardian lookup, which
+/// simply won't match a malformed identifier against any configured
+/// guardian.
+fn validate_guardian_identifier(identifier: &str) -> Result<()> {
+    if identifier.is_empty() {
+        return Err(anyhow::anyhow!("Guardian identifier cannot be empty"));
+    }
+    if identifier.len() > 200 {
+        return Err(anyhow::anyhow!("Guardian identifier too long (max 200 characters)"));
+    }
+    if !identifier.chars().all(|c| c.is_alphanumeric() || c == ':' || c == '-' || c == '_') {
+        return Err(anyhow::anyhow!("Guardian identifier contains invalid characters"));
+    }
+    Ok(())
+}
+
 /// Validate guardian name (length and safe characters)
 fn validate_guardian_name(name: &str) -> Result<()> {
     if name.is_empty() {
@@ -1033,6 +3615,107 @@ fn validate_device_name(device: &str) -> Result<()> {
     Ok(())
 }
 
+/// Validate a guardian notification endpoint URL
+fn validate_notification_endpoint(endpoint: &str) -> Result<()> {
+    if endpoint.is_empty() {
+        return Err(anyhow::anyhow!("Notification endpoint cannot be empty"));
+    }
+    if endpoint.len() > 500 {
+        return Err(anyhow::anyhow!("Notification endpoint too long (max 500 characters)"));
+    }
+    if !endpoint.starts_with("https://") && !endpoint.starts_with("http://") {
+        return Err(anyhow::anyhow!("Notification endpoint must be an http(s) URL"));
+    }
+
+    // Security: Reject endpoints that are obviously internal infrastructure
+    // up front. A guardian's notification endpoint is untrusted,
+    // network-touching input the same way a DNS/GeoIP hostname is
+    // elsewhere in this file - but a hostname can still resolve to an
+    // internal address later (DNS rebinding), so `dispatch_with_retry`
+    // re-checks the resolved IP on every send as well.
+    let url = reqwest::Url::parse(endpoint)
+        .map_err(|e| anyhow::anyhow!("Notification endpoint is not a valid URL: {}", e))?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow::anyhow!("Notification endpoint must include a host"))?;
+    if let Ok(ip) = host.parse::<std::net::IpAddr>() {
+        if is_disallowed_notification_target(ip) {
+            return Err(anyhow::anyhow!(
+                "Notification endpoint may not target a loopback, link-local, private, or multicast address"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Security: Loopback, link-local, private, and multicast ranges a
+/// guardian's notification endpoint must not be allowed to reach, whether
+/// given directly as an IP literal or reached by resolving a hostname
+fn is_disallowed_notification_target(ip: std::net::IpAddr) -> bool {
+    match ip {
+        std::net::IpAddr::V4(v4) => {
+            v4.is_loopback()
+                || v4.is_private()
+                || v4.is_link_local()
+                || v4.is_multicast()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+        }
+        std::net::IpAddr::V6(v6) => {
+            if v6.is_loopback() || v6.is_multicast() || v6.is_unspecified() {
+                return true;
+            }
+            // fc00::/7 (unique local) and fe80::/10 (link-local) - not yet
+            // exposed as stable `Ipv6Addr` predicates, so checked directly
+            let first_segment = v6.segments()[0];
+            (first_segment & 0xfe00) == 0xfc00 || (first_segment & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/// Resolve `endpoint`'s host, reject it if it (now) points at internal
+/// infrastructure, and return the exact address that passed the check.
+/// Called on every dispatch, not just at registration time, so a hostname
+/// that starts out resolving to a public address can't be repointed at an
+/// internal one later (DNS rebinding). The caller must connect to the
+/// returned address directly (e.g. via `reqwest::ClientBuilder::resolve`)
+/// rather than re-resolving the host itself - otherwise the independent
+/// resolution the HTTP client performs could still land on a different,
+/// unvalidated address (TOCTOU).
+async fn guard_against_ssrf(endpoint: &str) -> Result<std::net::SocketAddr> {
+    let url = reqwest::Url::parse(endpoint)
+        .map_err(|e| anyhow::anyhow!("Notification endpoint is not a valid URL: {}", e))?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow::anyhow!("Notification endpoint must include a host"))?;
+    let port = url.port_or_known_default().unwrap_or(443);
+
+    if let Ok(ip) = host.parse::<std::net::IpAddr>() {
+        if is_disallowed_notification_target(ip) {
+            return Err(anyhow::anyhow!("Notification endpoint resolves to a disallowed address"));
+        }
+        return Ok(std::net::SocketAddr::new(ip, port));
+    }
+
+    let resolved = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to resolve notification endpoint host: {}", e))?;
+
+    for addr in resolved {
+        if is_disallowed_notification_target(addr.ip()) {
+            return Err(anyhow::anyhow!("Notification endpoint resolves to a disallowed address"));
+        }
+        // Security: Pin to the first validated address rather than
+        // validating every candidate and letting the HTTP client resolve
+        // again later - a second, independent resolution could return a
+        // different (unvalidated) address.
+        return Ok(addr);
+    }
+
+    Err(anyhow::anyhow!("Notification endpoint host did not resolve to any address"))
+}
+
 /// Validate signature length (post-quantum signatures are typically 2-4KB)
 fn validate_signature_length(signature: &[u8]) -> Result<()> {
     if signature.is_empty() {
@@ -1074,6 +3757,15 @@ mod tests {
         assert!(validate_did("not-a-did").is_err()); // Invalid format
     }
 
+    #[test]
+    fn test_validate_guardian_identifier() {
+        assert!(validate_guardian_identifier("did:zhtp:alice123").is_ok());
+        assert!(validate_guardian_identifier("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_ok());
+        assert!(validate_guardian_identifier("").is_err()); // Empty
+        assert!(validate_guardian_identifier(&"x".repeat(201)).is_err()); // Too long
+        assert!(validate_guardian_identifier("alice<script>").is_err()); // Invalid chars
+    }
+
     #[test]
     fn test_validate_guardian_name() {
         assert!(validate_guardian_name("Alice Smith").is_ok());
@@ -1090,4 +3782,37 @@ mod tests {
         assert!(validate_device_name("").is_err()); // Empty
         assert!(validate_device_name(&"x".repeat(101)).is_err()); // Too long
     }
+
+    #[test]
+    fn test_validate_notification_endpoint() {
+        assert!(validate_notification_endpoint("https://example.com/hooks/guardian").is_ok());
+        assert!(validate_notification_endpoint("http://example.com/hooks/guardian").is_ok());
+        assert!(validate_notification_endpoint("").is_err()); // Empty
+        assert!(validate_notification_endpoint(&format!("https://{}", "x".repeat(500))).is_err()); // Too long
+        assert!(validate_notification_endpoint("ftp://example.com").is_err()); // Wrong scheme
+    }
+
+    #[test]
+    fn test_validate_notification_endpoint_rejects_ssrf_targets() {
+        assert!(validate_notification_endpoint("http://127.0.0.1:8080/hook").is_err()); // Loopback
+        assert!(validate_notification_endpoint("http://169.254.169.254/latest/meta-data").is_err()); // Link-local
+        assert!(validate_notification_endpoint("http://10.0.0.5/hook").is_err()); // Private (RFC1918)
+        assert!(validate_notification_endpoint("http://172.16.0.1/hook").is_err()); // Private (RFC1918)
+        assert!(validate_notification_endpoint("http://192.168.1.1/hook").is_err()); // Private (RFC1918)
+        assert!(validate_notification_endpoint("http://224.0.0.1/hook").is_err()); // Multicast
+        assert!(validate_notification_endpoint("http://[::1]/hook").is_err()); // IPv6 loopback
+        assert!(validate_notification_endpoint("http://[fe80::1]/hook").is_err()); // IPv6 link-local
+        assert!(validate_notification_endpoint("http://203.0.113.5/hook").is_ok()); // Public IP literal
+    }
+
+    #[test]
+    fn test_is_disallowed_notification_target() {
+        assert!(is_disallowed_notification_target("127.0.0.1".parse().unwrap()));
+        assert!(is_disallowed_notification_target("169.254.169.254".parse().unwrap()));
+        assert!(is_disallowed_notification_target("10.1.2.3".parse().unwrap()));
+        assert!(is_disallowed_notification_target("::1".parse().unwrap()));
+        assert!(is_disallowed_notification_target("fc00::1".parse().unwrap()));
+        assert!(!is_disallowed_notification_target("8.8.8.8".parse().unwrap()));
+        assert!(!is_disallowed_notification_target("2001:4860:4860::8888".parse().unwrap()));
+    }
 }
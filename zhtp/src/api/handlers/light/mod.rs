@@ -0,0 +1,211 @@
+//! Light-client API handlers for ZHTP
+//!
+//! Exposes the node's [`LightProvider`](crate::runtime::light_provider::LightProvider)
+//! implementation so resource-constrained clients can fetch headers and
+//! proof-bearing account/storage data without running a full node,
+//! following the LES "Provider answers requests" pattern.
+
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+// ZHTP protocol imports
+use lib_protocols::zhtp::{ZhtpRequestHandler, ZhtpResult};
+use lib_protocols::types::{ZhtpRequest, ZhtpResponse, ZhtpStatus, ZhtpMethod};
+
+use crate::runtime::light_provider::LightProvider;
+use crate::runtime::RuntimeOrchestrator;
+
+const CONTENT_TYPE_JSON: &str = "application/json";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LightHeaderData {
+    pub height: u64,
+    pub block_hash: String,
+    pub previous_block_hash: String,
+    pub merkle_root: String,
+    pub timestamp: u64,
+}
+
+impl From<crate::runtime::light_provider::LightHeader> for LightHeaderData {
+    fn from(h: crate::runtime::light_provider::LightHeader) -> Self {
+        Self {
+            height: h.height,
+            block_hash: h.block_hash,
+            previous_block_hash: h.previous_block_hash,
+            merkle_root: h.merkle_root,
+            timestamp: h.timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HeadersResponse {
+    pub status: String,
+    pub headers: Vec<LightHeaderData>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountProofResponse {
+    pub status: String,
+    pub header: LightHeaderData,
+    pub balance: u64,
+    pub nonce: u64,
+    pub code_hash: String,
+    pub storage_root: String,
+    /// Merkle branch nodes proving the account's data against `storage_root`.
+    pub proof_nodes: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StorageProofResponse {
+    pub status: String,
+    pub header: LightHeaderData,
+    pub key: u32,
+    pub value: String,
+    pub storage_root: String,
+    pub proof_nodes: Vec<Vec<u8>>,
+}
+
+/// Light/SPV client request handler
+pub struct LightHandler {
+    runtime: Arc<RuntimeOrchestrator>,
+}
+
+impl LightHandler {
+    pub fn new(runtime: Arc<RuntimeOrchestrator>) -> Self {
+        Self { runtime }
+    }
+
+    fn query_param<'a>(request: &'a ZhtpRequest, name: &str) -> Option<&'a str> {
+        request.uri.split('?').nth(1)?.split('&').find_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            (key == name).then_some(value)
+        })
+    }
+
+    /// GET /api/v1/light/headers?start=&count=&reverse=
+    async fn handle_get_headers(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let start: u64 = Self::query_param(&request, "start").and_then(|v| v.parse().ok()).unwrap_or(0);
+        let count: usize = Self::query_param(&request, "count").and_then(|v| v.parse().ok()).unwrap_or(1);
+        let reverse: bool = Self::query_param(&request, "reverse").and_then(|v| v.parse().ok()).unwrap_or(false);
+
+        info!("API: Light client requesting {} header(s) from {} (reverse={})", count, start, reverse);
+
+        let headers = self.runtime.get_headers(start, count, reverse).await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch headers: {}", e))?;
+
+        let response = HeadersResponse {
+            status: "success".to_string(),
+            headers: headers.into_iter().map(LightHeaderData::from).collect(),
+        };
+
+        Ok(ZhtpResponse::success_with_content_type(
+            serde_json::to_vec(&response)?,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// GET /api/v1/light/proof/account?address=&block=
+    async fn handle_get_account_proof(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let address = match Self::query_param(&request, "address") {
+            Some(a) => a.to_string(),
+            None => {
+                return Ok(ZhtpResponse::error(ZhtpStatus::BadRequest, "Missing 'address' query parameter".to_string()));
+            }
+        };
+        let block: Option<u64> = Self::query_param(&request, "block").and_then(|v| v.parse().ok());
+
+        info!("API: Light client requesting account proof for {} at block {:?}", address, block);
+
+        let proof = match self.runtime.get_account_proof(&address, block).await {
+            Ok(p) => p,
+            Err(e) => {
+                return Ok(ZhtpResponse::error(ZhtpStatus::NotFound, format!("Could not build account proof: {}", e)));
+            }
+        };
+
+        let response = AccountProofResponse {
+            status: "success".to_string(),
+            header: LightHeaderData::from(proof.header),
+            balance: proof.balance,
+            nonce: proof.nonce,
+            code_hash: proof.code_hash,
+            storage_root: proof.storage_root,
+            proof_nodes: proof.proof_nodes,
+        };
+
+        Ok(ZhtpResponse::success_with_content_type(
+            serde_json::to_vec(&response)?,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// GET /api/v1/light/proof/storage?address=&key=&block=
+    async fn handle_get_storage_proof(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let address = match Self::query_param(&request, "address") {
+            Some(a) => a.to_string(),
+            None => {
+                return Ok(ZhtpResponse::error(ZhtpStatus::BadRequest, "Missing 'address' query parameter".to_string()));
+            }
+        };
+        let key: u32 = match Self::query_param(&request, "key").and_then(|v| v.parse().ok()) {
+            Some(k) => k,
+            None => {
+                return Ok(ZhtpResponse::error(ZhtpStatus::BadRequest, "Missing or invalid 'key' query parameter".to_string()));
+            }
+        };
+        let block: Option<u64> = Self::query_param(&request, "block").and_then(|v| v.parse().ok());
+
+        info!("API: Light client requesting storage proof for {} key {} at block {:?}", address, key, block);
+
+        let proof = match self.runtime.get_storage_proof(&address, key, block).await {
+            Ok(p) => p,
+            Err(e) => {
+                return Ok(ZhtpResponse::error(ZhtpStatus::NotFound, format!("Could not build storage proof: {}", e)));
+            }
+        };
+
+        let response = StorageProofResponse {
+            status: "success".to_string(),
+            header: LightHeaderData::from(proof.header),
+            key: proof.key,
+            value: proof.value,
+            storage_root: proof.storage_root,
+            proof_nodes: proof.proof_nodes,
+        };
+
+        Ok(ZhtpResponse::success_with_content_type(
+            serde_json::to_vec(&response)?,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl ZhtpRequestHandler for LightHandler {
+    async fn handle_request(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        info!("Light handler: {} {}", request.method, request.uri);
+
+        let path = request.uri.split('?').next().unwrap_or(&request.uri);
+        let response = match (request.method, path) {
+            (ZhtpMethod::Get, "/api/v1/light/headers") => self.handle_get_headers(request).await,
+            (ZhtpMethod::Get, "/api/v1/light/proof/account") => self.handle_get_account_proof(request).await,
+            (ZhtpMethod::Get, "/api/v1/light/proof/storage") => self.handle_get_storage_proof(request).await,
+            _ => Ok(ZhtpResponse::error(ZhtpStatus::NotFound, format!("Light endpoint not found: {}", request.uri))),
+        };
+
+        response.or_else(|e| Ok(ZhtpResponse::error(ZhtpStatus::InternalServerError, format!("Light handler error: {}", e))))
+    }
+
+    fn can_handle(&self, request: &ZhtpRequest) -> bool {
+        request.uri.starts_with("/api/v1/light/")
+    }
+
+    fn priority(&self) -> u32 {
+        90
+    }
+}
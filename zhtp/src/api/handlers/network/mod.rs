@@ -3,9 +3,12 @@
 //! Provides endpoints for network management, peer operations, and network statistics.
 //! Built on lib-network functions and runtime orchestrator capabilities.
 
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
-// Removed unused tokio::sync::RwLock, anyhow::Result, serde_json::json
+use tokio::sync::RwLock;
+// Removed unused anyhow::Result, serde_json::json
 use tracing::{info, warn, error};
 use chrono;
 use uuid;
@@ -15,6 +18,7 @@ use lib_protocols::zhtp::{ZhtpRequestHandler, ZhtpResult};
 use lib_protocols::types::{ZhtpRequest, ZhtpResponse, ZhtpStatus, ZhtpMethod};
 
 use crate::runtime::RuntimeOrchestrator;
+use lib_blockchain::Transaction;
 
 // Constants
 const CONTENT_TYPE_JSON: &str = "application/json";
@@ -30,6 +34,77 @@ pub struct ErrorResponse {
 
 // Request/Response structures for network operations
 
+/// How long an idle client's credit bucket is kept before eviction.
+const FLOW_CREDIT_TTL: Duration = Duration::from_secs(600);
+
+/// A client's flow-control credit bucket, modeled on LES buffer-flow
+/// accounting: credits are spent per request and recharge linearly over
+/// time up to `max`.
+#[derive(Debug, Clone)]
+struct Credits {
+    current: u64,
+    max: u64,
+    last_recharge: Instant,
+}
+
+impl Credits {
+    fn new(max: u64) -> Self {
+        Self { current: max, max, last_recharge: Instant::now() }
+    }
+
+    /// Recharge toward `max` at `rate_per_ms` credits/ms based on elapsed time.
+    fn recharge(&mut self, rate_per_ms: f64) {
+        let elapsed_ms = self.last_recharge.elapsed().as_millis() as f64;
+        let gained = (elapsed_ms * rate_per_ms) as u64;
+        self.current = self.current.saturating_add(gained).min(self.max);
+        self.last_recharge = Instant::now();
+    }
+}
+
+/// Per-request-type credit costs and recharge rate shared by every
+/// client's [`Credits`] bucket.
+#[derive(Debug, Clone)]
+pub struct FlowParams {
+    pub costs: HashMap<String, u64>,
+    pub default_cost: u64,
+    pub max_credits: u64,
+    pub recharge_rate_per_ms: f64,
+}
+
+impl FlowParams {
+    fn default_params() -> Self {
+        let mut costs = HashMap::new();
+        costs.insert("stats".to_string(), 10);
+        costs.insert("peers".to_string(), 20);
+        costs.insert("history".to_string(), 100);
+        costs.insert("gas".to_string(), 10);
+        costs.insert("txpool_content".to_string(), 150);
+
+        Self {
+            costs,
+            default_cost: 10,
+            max_credits: 1000,
+            recharge_rate_per_ms: 1.0,
+        }
+    }
+
+    fn cost_for(&self, route: &str) -> u64 {
+        *self.costs.get(route).unwrap_or(&self.default_cost)
+    }
+}
+
+/// The configured flow-control costs and recharge rate, so clients can
+/// self-throttle instead of discovering limits via 429s.
+/// GET /api/v1/network/flow
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FlowControlResponse {
+    pub status: String,
+    pub costs: HashMap<String, u64>,
+    pub default_cost: u64,
+    pub max_credits: u64,
+    pub recharge_rate_per_ms: f64,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GasInfoResponse {
     pub status: String,
@@ -37,6 +112,15 @@ pub struct GasInfoResponse {
     pub estimated_cost: u64,
     pub base_fee: u64,
     pub priority_fee: u64,
+    /// Percentiles `reward[i]` was computed at, e.g. `[10.0, 50.0, 90.0]`.
+    pub reward_percentiles: Vec<f64>,
+    /// Base fee of each recent block plus the projected next block's,
+    /// mirroring `eth_feeHistory`'s `baseFeePerGas`.
+    pub base_fee_per_gas: Vec<u64>,
+    /// Fraction of `gas_limit` used by each recent block.
+    pub gas_used_ratio: Vec<f64>,
+    /// `reward[i][j]` is the `reward_percentiles[j]` priority fee of block `i`.
+    pub reward: Vec<Vec<u64>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -49,9 +133,14 @@ pub struct NetworkPeersResponse {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PeerInfo {
     pub peer_id: String,
+    pub peer_address: String,
     pub peer_type: String,
     pub status: String,
     pub connection_time: Option<u64>,
+    pub last_seen: u64,
+    /// Joined from the sync monitoring performance stats, if this peer has
+    /// any recorded yet.
+    pub reputation_score: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -126,6 +215,50 @@ pub struct SyncMetricsResponse {
     pub relay_ratio: f64,
 }
 
+// Transaction-pool (txpool) introspection response structures.
+//
+// `eth_txpool_*`-style visibility, analogous to `txpool_status` /
+// `txpool_inspect` / `txpool_content`. This chain's mempool is UTXO-based
+// with no account/nonce concept, so there's no nonce-gap notion of a
+// transaction being "queued" behind a lower nonce - every pool entry is
+// already eligible for inclusion, so `queued` is always empty/zero here.
+// Where geth groups by sender nonce, grouping below is by fee rate (highest
+// first) within each sender, since that's what determines this chain's
+// block-inclusion order.
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TxPoolStatusResponse {
+    pub status: String,
+    pub pending: usize,
+    pub queued: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TxPoolInspectResponse {
+    pub status: String,
+    /// sender -> { fee-rank index (stands in for nonce) -> compact summary }
+    pub pending: HashMap<String, HashMap<String, String>>,
+    pub queued: HashMap<String, HashMap<String, String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TxPoolContentResponse {
+    pub status: String,
+    pub pending: HashMap<String, Vec<TxPoolTransaction>>,
+    pub queued: HashMap<String, Vec<TxPoolTransaction>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TxPoolTransaction {
+    pub hash: String,
+    pub from: String,
+    pub to: String,
+    pub fee: u64,
+    pub transaction_type: String,
+    pub timestamp: u64,
+    pub size: usize,
+}
+
 // Phase 4: Advanced monitoring response structures
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -191,6 +324,23 @@ pub struct AlertThresholdsResponse {
     pub min_validation_success_rate: f64,
     pub max_duplicate_ratio: f64,
     pub min_peer_score: i32,
+    pub disconnect_score_threshold: i32,
+    pub ban_score_threshold: i32,
+    pub ban_duration_secs: u64,
+    /// Cap on a score-triggered ban's escalated duration (see
+    /// `PeerReputation::apply_violation`).
+    pub max_ban_duration_secs: u64,
+    pub reputation_decay_per_minute: i32,
+    /// Half-life, in seconds, used to compute `decayed_reputation` (see
+    /// `PeerReputation::decayed_score`).
+    pub reputation_half_life_secs: u64,
+    /// Default per-peer mesh flow-control params (see `PeerFlowParams`).
+    pub peer_flow_max_buffer: f64,
+    pub peer_flow_recharge_per_sec: f64,
+    pub peer_flow_costs: HashMap<String, f64>,
+    /// Per-violation-kind score penalty (see `ViolationKind`), keyed by
+    /// `ViolationKind::as_str()`.
+    pub violation_penalties: HashMap<String, i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -201,6 +351,16 @@ pub struct UpdateThresholdsRequest {
     pub min_validation_success_rate: Option<f64>,
     pub max_duplicate_ratio: Option<f64>,
     pub min_peer_score: Option<i32>,
+    pub disconnect_score_threshold: Option<i32>,
+    pub ban_score_threshold: Option<i32>,
+    pub ban_duration_secs: Option<u64>,
+    pub max_ban_duration_secs: Option<u64>,
+    pub reputation_decay_per_minute: Option<i32>,
+    pub reputation_half_life_secs: Option<u64>,
+    pub peer_flow_max_buffer: Option<f64>,
+    pub peer_flow_recharge_per_sec: Option<f64>,
+    pub peer_flow_costs: Option<HashMap<String, f64>>,
+    pub violation_penalties: Option<HashMap<String, i32>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -221,12 +381,21 @@ pub struct HistorySnapshot {
     pub bandwidth_bps: u64,
     pub active_peers: usize,
     pub banned_peers: usize,
+    pub warp_restore_active: bool,
 }
 
+/// Paginated, filtered view over peer performance stats, returned by
+/// `GET /api/v1/blockchain/sync/peers` when any query parameters are given
+/// (and always shaped the same way, so callers don't need two response
+/// formats).
 #[derive(Debug, Serialize, Deserialize)]
-pub struct PeerPerformanceResponse {
+pub struct PeerPerformanceQueryResponse {
     pub status: String,
-    pub total_peers: usize,
+    /// Count of peers matching the filters, before `limit`/`offset`.
+    pub total: usize,
+    /// Number of peers in this page (`peers.len()`).
+    pub count: usize,
+    pub offset: usize,
     pub peers: Vec<PeerPerformanceInfo>,
 }
 
@@ -243,16 +412,303 @@ pub struct PeerPerformanceInfo {
     pub first_seen: u64,
     pub last_seen: u64,
     pub status: String, // "active", "warning", "banned"
+    /// Unix timestamp the current ban lifts at, if any.
+    pub ban_expires_at: Option<u64>,
+    /// `ViolationKind::as_str()` of the infraction that triggered the
+    /// current ban, if any.
+    pub ban_reason: Option<String>,
+    /// Number of times this peer has been banned; each ban escalates
+    /// exponentially in duration from the last (see `apply_violation`).
+    pub ban_count: u32,
+    /// Signed delta of the most recent score change (decay or violation).
+    pub score_trend: i32,
+    /// Time-windowed reputation weighting recent behavior over old,
+    /// distinct from the lifetime `reputation_score` (see
+    /// `PeerReputation::decayed_score`).
+    pub decayed_reputation: f64,
+    /// Current mesh-protocol flow-control credit balance (distinct from the
+    /// per-API-client `Credits` this handler also enforces).
+    pub credits: f64,
+    pub max_buffer: f64,
+    pub recharge_per_sec: f64,
+}
+
+/// Network-wide peer health dashboard, returned by
+/// `GET /api/v1/blockchain/sync/peers/summary` so operators can answer "is
+/// the network healthy right now" in one call instead of paging through
+/// every peer via `handle_get_peer_performance`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PeerHealthSummaryResponse {
+    pub status: String,
+    pub total_peers: usize,
+    pub active_peers: usize,
+    pub warning_peers: usize,
+    pub banned_peers: usize,
+    pub mean_decayed_reputation: f64,
+    pub median_decayed_reputation: f64,
+    pub aggregate_acceptance_rate: f64,
+    pub best_peer: Option<PeerSummaryEntry>,
+    pub worst_peer: Option<PeerSummaryEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PeerSummaryEntry {
+    pub peer_id: String,
+    pub decayed_reputation: f64,
+}
+
+/// POST /api/v1/blockchain/sync/peers/{id}/punish
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PunishPeerRequest {
+    /// One of "invalid_block", "duplicate_flood", "bad_proof", "timeout",
+    /// "credit_overrun".
+    pub violation: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PunishPeerResponse {
+    pub status: String,
+    pub peer_id: String,
+    pub punishment: String, // "none", "disconnect", "ban"
+    pub ban_expires_at: Option<u64>,
+}
+
+/// DELETE /api/v1/blockchain/sync/peers/{id}/ban
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LiftBanResponse {
+    pub status: String,
+    pub peer_id: String,
+    pub lifted: bool,
+}
+
+/// POST /api/v1/blockchain/sync/peers/{id}/ban - a direct admin-imposed
+/// ban, independent of `PunishPeerRequest`'s violation-scoring ladder.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BanPeerRequest {
+    /// One of "bad_block", "bad_transaction", "protocol_violation",
+    /// "manual_admin", "abusive".
+    pub reason: String,
+    /// Ban duration in seconds; defaults to the configured
+    /// `ban_duration_secs` threshold if omitted.
+    pub duration_secs: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BanPeerResponse {
+    pub status: String,
+    pub peer_id: String,
+    pub reason: String,
+    pub expires_at: u64,
+}
+
+/// POST /api/v1/blockchain/sync/subscribe request body.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscribeRequest {
+    /// e.g. `["alerts", "performance", "peer_status"]`; empty/omitted means
+    /// all topics.
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// Minimum alert level to deliver: "info" | "warning" | "critical".
+    #[serde(default)]
+    pub min_level: Option<String>,
+    #[serde(default)]
+    pub peer_id: Option<String>,
+    /// Minimum spacing, in seconds, between delivered performance frames.
+    #[serde(default)]
+    pub interval_secs: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubscribeResponse {
+    pub status: String,
+    pub subscription_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnsubscribeResponse {
+    pub status: String,
+    pub subscription_id: String,
+    pub removed: bool,
+}
+
+/// GET /api/v1/blockchain/sync/subscribe/{id}/poll response: newline-
+/// delimited JSON frames accumulated since the subscriber's last poll.
+///
+/// ZHTP's `ZhtpResponse` has a single fixed `Vec<u8>` body with no chunked
+/// or server-push transport, so the "one long-lived connection" a pub/sub
+/// stream implies isn't representable as-is. This drains the same
+/// broadcast-fed, filtered queue a persistent stream would have written to,
+/// via a lightweight poll instead of holding the connection open.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PollSubscriptionResponse {
+    pub status: String,
+    pub subscription_id: String,
+    pub frame_count: usize,
+    /// Newline-delimited JSON event frames, oldest first.
+    pub frames: String,
+}
+
+/// How long an idle (never-polled-since) subscription is kept before its
+/// drain task is stopped and it is evicted, mirroring `FLOW_CREDIT_TTL`'s
+/// idle-bucket eviction.
+const SUBSCRIPTION_IDLE_TTL: Duration = Duration::from_secs(600);
+
+/// Maximum buffered frames per subscription; oldest frames are dropped once
+/// a slow poller falls behind this far.
+const SUBSCRIPTION_QUEUE_CAPACITY: usize = 500;
+
+/// Media type `GET .../sync/events` answers with.
+const SSE_CONTENT_TYPE: &str = "text/event-stream";
+
+/// Default time `handle_get_events` waits for at least one matching event
+/// before returning, absent a `wait_ms` query override.
+const EVENTS_DEFAULT_WAIT: Duration = Duration::from_secs(20);
+
+/// Upper bound a caller's `wait_ms` query param is clamped to.
+const EVENTS_MAX_WAIT: Duration = Duration::from_secs(55);
+
+/// Maximum events collected into a single `GET .../sync/events` response.
+const EVENTS_MAX_FRAMES: usize = 50;
+
+/// A registered `POST .../subscribe` client. `task` drains the shared
+/// monitoring broadcast channel into `frames`, filtered by `filter`, so
+/// `handle_poll_subscription` only has to collect already-matched frames.
+struct Subscription {
+    filter: crate::unified_server::SubscriptionFilter,
+    frames: Arc<RwLock<VecDeque<String>>>,
+    last_polled: RwLock<Instant>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
 }
 
 /// Network handler implementation
 pub struct NetworkHandler {
     runtime: Arc<RuntimeOrchestrator>,
+    flow_params: FlowParams,
+    flow_credits: RwLock<HashMap<String, Credits>>,
+    subscriptions: RwLock<HashMap<String, Subscription>>,
 }
 
 impl NetworkHandler {
     pub fn new(runtime: Arc<RuntimeOrchestrator>) -> Self {
-        Self { runtime }
+        Self {
+            runtime,
+            flow_params: FlowParams::default_params(),
+            flow_credits: RwLock::new(HashMap::new()),
+            subscriptions: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Look up a single query-string parameter by name, ZHTP having no
+    /// structured query-parsing of its own.
+    fn query_param<'a>(request: &'a ZhtpRequest, name: &str) -> Option<&'a str> {
+        request.uri.split('?').nth(1)?.split('&').find_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            (key == name).then_some(value)
+        })
+    }
+
+    /// Resolve the client identity a credit bucket is keyed on, from the
+    /// same headers reverse proxies set for real client IPs.
+    fn resolve_client_id(request: &ZhtpRequest) -> String {
+        request.headers.get("X-Real-IP")
+            .or_else(|| request.headers.get("X-Forwarded-For").and_then(|f| f.split(',').next().map(|s| s.trim().to_string())))
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    /// Map a request path to the cost-table category it's charged against.
+    fn route_category(uri: &str) -> &'static str {
+        let path = uri.split('?').next().unwrap_or(uri);
+        if path == "/api/v1/network/gas" {
+            "gas"
+        } else if path == "/api/v1/blockchain/network/peers"
+            || path.starts_with("/api/v1/blockchain/network/peer/")
+        {
+            "peers"
+        } else if path.starts_with("/api/v1/blockchain/sync/history")
+            || path.starts_with("/api/v1/blockchain/sync/peers")
+        {
+            "history"
+        } else if path.starts_with("/api/v1/blockchain/txpool/content") {
+            "txpool_content"
+        } else {
+            "stats"
+        }
+    }
+
+    /// Recharge and charge `client_id`'s credit bucket for `route`,
+    /// evicting idle buckets past [`FLOW_CREDIT_TTL`] along the way. On
+    /// insufficient credits, returns the number of seconds the client
+    /// should wait before retrying.
+    async fn check_flow_credits(&self, client_id: &str, route: &str) -> Result<(), f64> {
+        let cost = self.flow_params.cost_for(route);
+        let mut credits = self.flow_credits.write().await;
+
+        credits.retain(|_, c| c.last_recharge.elapsed() < FLOW_CREDIT_TTL);
+
+        let entry = credits
+            .entry(client_id.to_string())
+            .or_insert_with(|| Credits::new(self.flow_params.max_credits));
+        entry.recharge(self.flow_params.recharge_rate_per_ms);
+
+        if entry.current < cost {
+            let deficit = (cost - entry.current) as f64;
+            let retry_after_secs = deficit / self.flow_params.recharge_rate_per_ms / 1000.0;
+            return Err(retry_after_secs);
+        }
+
+        entry.current -= cost;
+        Ok(())
+    }
+
+    /// Classify a peer's punishment state for display against the
+    /// operator-configured [`crate::unified_server::AlertThresholds`],
+    /// preferring an active ban expiry over the score-based heuristics.
+    fn peer_status(
+        stats: &crate::unified_server::PeerPerformanceStats,
+        thresholds: &crate::unified_server::AlertThresholds,
+    ) -> &'static str {
+        let banned = stats.ban_expires_at
+            .map(|exp| exp > chrono::Utc::now().timestamp() as u64)
+            .unwrap_or(false);
+
+        if banned || stats.reputation_score <= thresholds.ban_score_threshold {
+            "banned"
+        } else if stats.reputation_score <= thresholds.disconnect_score_threshold {
+            "warning"
+        } else {
+            "active"
+        }
+    }
+
+    /// Derive the grouping key txpool endpoints use in place of a sender
+    /// address, mirroring `handle_get_pending_transactions` in the
+    /// blockchain handler: this chain's transactions are UTXO-based, so the
+    /// spent output they reference is the closest stand-in for "sender".
+    fn tx_sender(tx: &Transaction) -> String {
+        tx.inputs
+            .first()
+            .map(|i| i.previous_output.to_string())
+            .unwrap_or_else(|| "genesis".to_string())
+    }
+
+    /// Group pending transactions by [`Self::tx_sender`], ordering each
+    /// group by fee (highest first) in place of the nonce ordering a
+    /// nonce-based chain would use.
+    fn group_txpool_by_sender(txs: &[Transaction]) -> HashMap<String, Vec<&Transaction>> {
+        let mut grouped: HashMap<String, Vec<&Transaction>> = HashMap::new();
+        for tx in txs {
+            grouped.entry(Self::tx_sender(tx)).or_default().push(tx);
+        }
+        for group in grouped.values_mut() {
+            group.sort_by(|a, b| b.fee.cmp(&a.fee));
+        }
+        grouped
     }
 
     /// Create standardized JSON error response (Issue #11)
@@ -292,11 +748,48 @@ impl ZhtpRequestHandler for NetworkHandler {
             "Network API request received"
         );
 
+        // Credit-based flow control: recharge and charge this client's
+        // bucket before dispatching to any route, so back-pressure is
+        // enforced uniformly rather than per-endpoint.
+        let client_id = Self::resolve_client_id(&request);
+        let route = Self::route_category(&request.uri);
+        if let Err(retry_after_secs) = self.check_flow_credits(&client_id, route).await {
+            warn!(
+                request_id = %request_id,
+                client = %client_id,
+                route = route,
+                "Network API request throttled: insufficient flow credits"
+            );
+
+            let result = self.json_error(
+                ZhtpStatus::ServiceUnavailable,
+                format!("Insufficient flow credits for '{}' requests", route),
+            ).map(|mut resp| {
+                resp.headers.set("Retry-After", format!("{:.3}", retry_after_secs.max(0.0)));
+                resp
+            });
+
+            let duration_ms = start_time.elapsed().as_millis();
+            return match result {
+                Ok(resp) => {
+                    info!(request_id = %request_id, status = ?resp.status, duration_ms = duration_ms, "Network API request throttled");
+                    Ok(resp)
+                }
+                Err(e) => {
+                    error!(request_id = %request_id, error = %e, duration_ms = duration_ms, "Network API request failed");
+                    Ok(ZhtpResponse::error(ZhtpStatus::InternalServerError, format!("Network error: {}", e)))
+                }
+            };
+        }
+
         let response = match (request.method, request.uri.as_str()) {
             // Gas pricing endpoint (Issue #10)
             (ZhtpMethod::Get, "/api/v1/network/gas") => {
                 self.handle_get_gas_info(request).await
             }
+            (ZhtpMethod::Get, "/api/v1/network/flow") => {
+                self.handle_get_flow_params(request).await
+            }
             (ZhtpMethod::Get, "/api/v1/blockchain/network/peers") => {
                 self.handle_get_network_peers(request).await
             }
@@ -306,6 +799,12 @@ impl ZhtpRequestHandler for NetworkHandler {
             (ZhtpMethod::Get, "/api/v1/blockchain/sync/metrics") => {
                 self.handle_get_sync_metrics(request).await
             }
+            (ZhtpMethod::Get, "/api/v1/blockchain/sync/schedule") => {
+                self.handle_get_sync_schedule(request).await
+            }
+            (ZhtpMethod::Get, path) if path.starts_with("/api/v1/blockchain/sync/events") => {
+                self.handle_get_events(request).await
+            }
             // Phase 4: Advanced monitoring endpoints
             (ZhtpMethod::Get, "/api/v1/blockchain/sync/performance") => {
                 self.handle_get_performance_metrics(request).await
@@ -331,9 +830,43 @@ impl ZhtpRequestHandler for NetworkHandler {
             (ZhtpMethod::Get, "/api/v1/blockchain/sync/peers") => {
                 self.handle_get_peer_performance(request).await
             }
+            (ZhtpMethod::Get, "/api/v1/blockchain/sync/peers/summary") => {
+                self.handle_get_peer_summary(request).await
+            }
+            (ZhtpMethod::Post, path) if path.starts_with("/api/v1/blockchain/sync/peers/") && path.ends_with("/punish") => {
+                self.handle_punish_peer(request).await
+            }
+            (ZhtpMethod::Post, path) if path.starts_with("/api/v1/blockchain/sync/peers/") && path.ends_with("/ban") => {
+                self.handle_ban_peer(request).await
+            }
+            (ZhtpMethod::Delete, path) if path.starts_with("/api/v1/blockchain/sync/peers/") && path.ends_with("/ban") => {
+                self.handle_lift_ban(request).await
+            }
+            (ZhtpMethod::Post, path) if path.starts_with("/api/v1/blockchain/network/peer/") && path.ends_with("/unban") => {
+                self.handle_unban_peer(request).await
+            }
             (ZhtpMethod::Get, path) if path.starts_with("/api/v1/blockchain/sync/peers/") => {
                 self.handle_get_specific_peer_performance(request).await
             }
+            (ZhtpMethod::Post, "/api/v1/blockchain/sync/subscribe") => {
+                self.handle_subscribe(request).await
+            }
+            (ZhtpMethod::Get, path) if path.starts_with("/api/v1/blockchain/sync/subscribe/") && path.ends_with("/poll") => {
+                self.handle_poll_subscription(request).await
+            }
+            (ZhtpMethod::Delete, path) if path.starts_with("/api/v1/blockchain/sync/subscribe/") => {
+                self.handle_unsubscribe(request).await
+            }
+            // Mempool (txpool) introspection
+            (ZhtpMethod::Get, "/api/v1/blockchain/txpool/status") => {
+                self.handle_get_txpool_status(request).await
+            }
+            (ZhtpMethod::Get, "/api/v1/blockchain/txpool/inspect") => {
+                self.handle_get_txpool_inspect(request).await
+            }
+            (ZhtpMethod::Get, "/api/v1/blockchain/txpool/content") => {
+                self.handle_get_txpool_content(request).await
+            }
             // Existing endpoints
             (ZhtpMethod::Post, "/api/v1/blockchain/network/peer/add") => {
                 self.handle_add_network_peer(request).await
@@ -385,6 +918,7 @@ impl ZhtpRequestHandler for NetworkHandler {
     fn can_handle(&self, request: &ZhtpRequest) -> bool {
         request.uri.starts_with("/api/v1/blockchain/network/") ||
         request.uri.starts_with("/api/v1/blockchain/sync/") ||
+        request.uri.starts_with("/api/v1/blockchain/txpool/") ||
         request.uri.starts_with("/api/v1/network/")
     }
     
@@ -394,25 +928,49 @@ impl ZhtpRequestHandler for NetworkHandler {
 }
 
 impl NetworkHandler {
-    /// Get gas pricing information
-    /// GET /api/v1/network/gas (Issue #10)
+    /// Get gas pricing information, driven by an EIP-1559-style oracle
+    /// over recent blocks' fee history (the `eth_feeHistory` model),
+    /// instead of static pricing.
+    /// GET /api/v1/network/gas?blocks=10&percentiles=10,50,90 (Issue #10)
     async fn handle_get_gas_info(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
         info!("API: Getting gas pricing information");
 
-        // Security: Rate limit gas price queries (100 requests per 15 minutes per IP)
-        let client_ip = request.headers.get("X-Real-IP")
-            .or_else(|| request.headers.get("X-Forwarded-For").and_then(|f| f.split(',').next().map(|s| s.trim().to_string())))
-            .unwrap_or_else(|| "unknown".to_string());
-
-        // Note: Rate limiter would need to be added to NetworkHandler struct
-        // For now, just log the IP for monitoring
+        // Flow-control credits are checked centrally in `handle_request`;
+        // just log the resolved client for monitoring here.
+        let client_ip = Self::resolve_client_id(&request);
         info!("Gas price request from IP: {}", client_ip);
 
-        // Static gas pricing - integrate with economic model when available
-        let base_fee = 100; // Base fee in smallest unit
-        let priority_fee = 50; // Priority fee for faster processing
+        // Parse query parameters for the size of the history window and
+        // which reward percentiles to report, mirroring `?last_n=` on
+        // the sync metrics history endpoint.
+        let query = request.uri.split('?').nth(1);
+        let blocks = query
+            .and_then(|q| {
+                q.split('&')
+                    .find(|param| param.starts_with("blocks="))
+                    .and_then(|param| param.strip_prefix("blocks="))
+                    .and_then(|val| val.parse::<usize>().ok())
+            })
+            .unwrap_or(20);
+        let reward_percentiles: Vec<f64> = query
+            .and_then(|q| {
+                q.split('&')
+                    .find(|param| param.starts_with("percentiles="))
+                    .and_then(|param| param.strip_prefix("percentiles="))
+                    .map(|val| val.split(',').filter_map(|p| p.parse::<f64>().ok()).collect())
+            })
+            .filter(|v: &Vec<f64>| !v.is_empty())
+            .unwrap_or_else(|| vec![10.0, 50.0, 90.0]);
+
+        let history = crate::runtime::mesh_router_provider::get_gas_fee_history()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read gas fee history: {}", e))?;
+
+        let view = history.fee_history(blocks, &reward_percentiles);
+        let base_fee = history.next_base_fee();
+        let priority_fee = history.suggested_priority_fee(60.0);
         let gas_price = base_fee + priority_fee;
-        let estimated_cost = gas_price * 21000; // Estimate for standard transaction
+        let estimated_cost = gas_price * crate::server::monitoring::metrics::GAS_PER_TRANSACTION;
 
         let response = GasInfoResponse {
             status: "success".to_string(),
@@ -420,6 +978,10 @@ impl NetworkHandler {
             estimated_cost,
             base_fee,
             priority_fee,
+            reward_percentiles,
+            base_fee_per_gas: view.base_fee_per_gas,
+            gas_used_ratio: view.gas_used_ratio,
+            reward: view.reward,
         };
 
         info!("API: Gas info - price: {}, estimated cost: {}", gas_price, estimated_cost);
@@ -434,81 +996,78 @@ impl NetworkHandler {
         ))
     }
 
+    /// Get the configured flow-control costs and recharge rate
+    /// GET /api/v1/network/flow
+    async fn handle_get_flow_params(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        info!("API: Getting flow control parameters");
+
+        let response = FlowControlResponse {
+            status: "success".to_string(),
+            costs: self.flow_params.costs.clone(),
+            default_cost: self.flow_params.default_cost,
+            max_credits: self.flow_params.max_credits,
+            recharge_rate_per_ms: self.flow_params.recharge_rate_per_ms,
+        };
+
+        let json_response = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
     /// Get list of connected peers
     /// GET /api/v1/blockchain/network/peers
     async fn handle_get_network_peers(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
         info!("API: Getting network peers");
 
-        match self.runtime.get_connected_peers().await {
-            Ok(peer_list) => {
-                let peers: Vec<PeerInfo> = peer_list.into_iter().enumerate().map(|(i, peer_name)| {
-                    let peer_type = if peer_name.starts_with("local-") {
-                        "local"
-                    } else if peer_name.starts_with("regional-") {
-                        "regional"
-                    } else if peer_name.starts_with("global-") {
-                        "global"
-                    } else if peer_name.starts_with("relay-") {
-                        "relay"
-                    } else {
-                        "unknown"
-                    };
+        let registry_peers = crate::runtime::peer_registry::list_peers().await;
+        let performance_by_id: HashMap<String, i32> =
+            match crate::runtime::mesh_router_provider::list_peer_performance().await {
+                Ok(stats) => stats.into_iter().map(|s| (s.peer_id, s.reputation_score)).collect(),
+                Err(_) => HashMap::new(), // Mesh router not available yet; join with what we have.
+            };
 
-                    PeerInfo {
-                        peer_id: format!("peer_{}", i + 1),
-                        peer_type: peer_type.to_string(),
-                        status: if peer_name == "No peers connected" || peer_name == "Network status unavailable" {
-                            "disconnected"
-                        } else {
-                            "connected"
-                        }.to_string(),
-                        connection_time: if peer_name != "No peers connected" && peer_name != "Network status unavailable" {
-                            Some(std::time::SystemTime::now()
-                                .duration_since(std::time::UNIX_EPOCH)
-                                .unwrap_or_default()
-                                .as_secs())
-                        } else {
-                            None
-                        },
-                    }
-                }).collect();
+        let peers: Vec<PeerInfo> = registry_peers
+            .into_iter()
+            .map(|record| {
+                let status = match record.state {
+                    crate::runtime::peer_registry::PeerConnectionState::Connected => "connected",
+                    crate::runtime::peer_registry::PeerConnectionState::Disconnected => "disconnected",
+                }
+                .to_string();
+
+                PeerInfo {
+                    peer_type: "registered".to_string(),
+                    reputation_score: performance_by_id.get(&record.peer_id).copied(),
+                    peer_id: record.peer_id,
+                    peer_address: record.peer_address,
+                    status,
+                    connection_time: Some(record.first_seen),
+                    last_seen: record.last_seen,
+                }
+            })
+            .collect();
 
-                let response = NetworkPeersResponse {
-                    status: "success".to_string(),
-                    peer_count: peers.len(),
-                    peers,
-                };
+        let response = NetworkPeersResponse {
+            status: "success".to_string(),
+            peer_count: peers.len(),
+            peers,
+        };
 
-                info!("API: Retrieved {} network peers", response.peer_count);
-                
-                let json_response = serde_json::to_vec(&response)
-                    .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
-                
-                Ok(ZhtpResponse::success_with_content_type(
-                    json_response,
-                    "application/json".to_string(),
-                    None,
-                ))
-            }
-            Err(e) => {
-                error!("API: Failed to get network peers: {}", e);
-                
-                let error_response = NetworkPeersResponse {
-                    status: "error".to_string(),
-                    peer_count: 0,
-                    peers: vec![],
-                };
-                
-                let json_response = serde_json::to_vec(&error_response)
-                    .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
-                
-                Ok(ZhtpResponse::success_with_content_type(
-                    json_response,
-                    "application/json".to_string(),
-                    None,
-                ))
-            }
-        }
+        info!("API: Retrieved {} network peers", response.peer_count);
+
+        let json_response = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            "application/json".to_string(),
+            None,
+        ))
     }
 
     /// Get network statistics
@@ -661,53 +1220,225 @@ impl NetworkHandler {
         }
     }
 
+    /// `GET /api/v1/blockchain/sync/schedule` - current range/subchain
+    /// download plan from `runtime::sync_scheduler`.
+    async fn handle_get_sync_schedule(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        info!("API: Getting sync scheduler status");
 
+        let status = crate::runtime::sync_scheduler::status().await;
+        let response = serde_json::json!({
+            "status": "success",
+            "schedule": status,
+        });
 
-    /// Add a new peer to the network
-    /// POST /api/v1/blockchain/network/peer/add
-    async fn handle_add_network_peer(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
-        info!("API: Adding network peer");
-
-        // Parse request body
-        let add_request: AddPeerRequest = if request.body.is_empty() {
-            return Ok(ZhtpResponse::error(
-                ZhtpStatus::BadRequest,
-                "Request body is required".to_string(),
-            ));
-        } else {
-            serde_json::from_slice(&request.body)
-                .map_err(|e| anyhow::anyhow!("Invalid JSON in request body: {}", e))?
-        };
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
 
-        // Validate peer address format
-        if add_request.peer_address.is_empty() {
-            warn!("API: Empty peer address provided");
-            let error_response = AddPeerResponse {
-                status: "error".to_string(),
-                peer_id: "".to_string(),
-                message: "Peer address cannot be empty".to_string(),
-                connected: false,
-            };
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            "application/json".to_string(),
+            None,
+        ))
+    }
 
-            let json_response = serde_json::to_vec(&error_response)
-                .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
-            
-            return Ok(ZhtpResponse::success_with_content_type(
-                json_response,
-                "application/json".to_string(),
-                None,
-            ));
-        }
+    /// `GET /api/v1/blockchain/txpool/status` - pending/queued transaction counts.
+    async fn handle_get_txpool_status(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let pending = match crate::runtime::blockchain_provider::get_mempool().await {
+            Ok(txs) => txs,
+            Err(e) => {
+                error!("API: Failed to read mempool for txpool status: {}", e);
+                return self.json_error(
+                    ZhtpStatus::ServiceUnavailable,
+                    format!("Blockchain unavailable: {}", e),
+                );
+            }
+        };
 
-        // Generate peer ID based on address using cryptographic hash (issue #9)
-        let peer_hash = lib_crypto::hashing::hash_blake3(add_request.peer_address.as_bytes());
-        let peer_id = format!("peer_{}", hex::encode(&peer_hash[..8]));
+        let response = TxPoolStatusResponse {
+            status: "success".to_string(),
+            pending: pending.len(),
+            queued: 0,
+        };
 
-        match self.runtime.connect_to_peer(&add_request.peer_address).await {
-            Ok(()) => {
-                let response = AddPeerResponse {
-                    status: "success".to_string(),
-                    peer_id: peer_id.clone(),
+        let json_response = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// `GET /api/v1/blockchain/txpool/inspect` - compact per-sender summary,
+    /// analogous to `txpool_inspect`.
+    async fn handle_get_txpool_inspect(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let pending = match crate::runtime::blockchain_provider::get_mempool().await {
+            Ok(txs) => txs,
+            Err(e) => {
+                error!("API: Failed to read mempool for txpool inspect: {}", e);
+                return self.json_error(
+                    ZhtpStatus::ServiceUnavailable,
+                    format!("Blockchain unavailable: {}", e),
+                );
+            }
+        };
+
+        let grouped = Self::group_txpool_by_sender(&pending);
+        let mut pending_summary: HashMap<String, HashMap<String, String>> = HashMap::new();
+        for (sender, txs) in grouped {
+            let mut by_rank = HashMap::new();
+            for (rank, tx) in txs.into_iter().enumerate() {
+                let to = tx
+                    .outputs
+                    .first()
+                    .map(|o| format!("{:02x?}", &o.recipient.key_id[..8]))
+                    .unwrap_or_else(|| "unknown".to_string());
+                by_rank.insert(
+                    rank.to_string(),
+                    format!("{}: {} fee + {} bytes", to, tx.fee, tx.size()),
+                );
+            }
+            pending_summary.insert(sender, by_rank);
+        }
+
+        let response = TxPoolInspectResponse {
+            status: "success".to_string(),
+            pending: pending_summary,
+            queued: HashMap::new(),
+        };
+
+        let json_response = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// `GET /api/v1/blockchain/txpool/content` - full pending/queued
+    /// transactions grouped by sender, analogous to `txpool_content`. The
+    /// most expensive of the three txpool endpoints, so it's charged at the
+    /// `txpool_content` flow-credit rate rather than the default `stats` rate.
+    async fn handle_get_txpool_content(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let pending = match crate::runtime::blockchain_provider::get_mempool().await {
+            Ok(txs) => txs,
+            Err(e) => {
+                error!("API: Failed to read mempool for txpool content: {}", e);
+                return self.json_error(
+                    ZhtpStatus::ServiceUnavailable,
+                    format!("Blockchain unavailable: {}", e),
+                );
+            }
+        };
+
+        let grouped = Self::group_txpool_by_sender(&pending);
+        let mut pending_content: HashMap<String, Vec<TxPoolTransaction>> = HashMap::new();
+        for (sender, txs) in grouped {
+            let entries = txs
+                .into_iter()
+                .map(|tx| TxPoolTransaction {
+                    hash: tx.hash().to_string(),
+                    from: sender.clone(),
+                    to: tx
+                        .outputs
+                        .first()
+                        .map(|o| format!("{:02x?}", &o.recipient.key_id[..8]))
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    fee: tx.fee,
+                    transaction_type: format!("{:?}", tx.transaction_type),
+                    timestamp: tx.signature.timestamp,
+                    size: tx.size(),
+                })
+                .collect();
+            pending_content.insert(sender, entries);
+        }
+
+        let response = TxPoolContentResponse {
+            status: "success".to_string(),
+            pending: pending_content,
+            queued: HashMap::new(),
+        };
+
+        let json_response = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+        Ok(ZhtpResponse::success_with_content_type(
+            json_response,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// Add a new peer to the network
+    /// POST /api/v1/blockchain/network/peer/add
+    async fn handle_add_network_peer(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        info!("API: Adding network peer");
+
+        // Parse request body
+        let add_request: AddPeerRequest = if request.body.is_empty() {
+            return Ok(ZhtpResponse::error(
+                ZhtpStatus::BadRequest,
+                "Request body is required".to_string(),
+            ));
+        } else {
+            serde_json::from_slice(&request.body)
+                .map_err(|e| anyhow::anyhow!("Invalid JSON in request body: {}", e))?
+        };
+
+        // Validate peer address format
+        if add_request.peer_address.is_empty() {
+            warn!("API: Empty peer address provided");
+            let error_response = AddPeerResponse {
+                status: "error".to_string(),
+                peer_id: "".to_string(),
+                message: "Peer address cannot be empty".to_string(),
+                connected: false,
+            };
+
+            let json_response = serde_json::to_vec(&error_response)
+                .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+            
+            return Ok(ZhtpResponse::success_with_content_type(
+                json_response,
+                "application/json".to_string(),
+                None,
+            ));
+        }
+
+        // Generate peer ID based on address using cryptographic hash (issue #9),
+        // via the same derivation the peer registry resolves addresses with.
+        let peer_id = crate::runtime::peer_registry::derive_peer_id(&add_request.peer_address);
+
+        // Reject reconnection attempts from a peer serving an active ban
+        // (graduated punishment engine).
+        match crate::runtime::mesh_router_provider::peer_ban_expiry(&peer_id).await {
+            Ok(Some(expires_at)) => {
+                warn!("API: Rejected peer {} - banned until {}", peer_id, expires_at);
+                let response = AddPeerResponse {
+                    status: "error".to_string(),
+                    peer_id,
+                    message: format!("Peer is banned until unix timestamp {}", expires_at),
+                    connected: false,
+                };
+
+                let json_response = serde_json::to_vec(&response)
+                    .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+                return Ok(ZhtpResponse::success_with_content_type(
+                    json_response,
+                    "application/json".to_string(),
+                    None,
+                ));
+            }
+            Ok(None) => {}
+            Err(_) => {} // Mesh router/reputation tracking not yet available; allow the attempt.
+        }
+
+        match self.runtime.connect_to_peer(&add_request.peer_address).await {
+            Ok(()) => {
+                let response = AddPeerResponse {
+                    status: "success".to_string(),
+                    peer_id: peer_id.clone(),
                     message: format!("Successfully initiated connection to peer {}", add_request.peer_address),
                     connected: true,
                 };
@@ -761,9 +1492,18 @@ impl NetworkHandler {
 
         info!(" API: Removing network peer: {}", peer_id);
 
-        // For demonstration, we'll use the peer_id as the address
-        // In a implementation, you'd maintain a mapping of peer_id -> address
-        let peer_address = format!("peer-address-{}", peer_id);
+        // Resolve the real address from the peer registry instead of
+        // guessing it from peer_id.
+        let peer_address = match crate::runtime::peer_registry::resolve_address(&peer_id).await {
+            Some(address) => address,
+            None => {
+                warn!("API: Unknown peer_id for removal: {}", peer_id);
+                return self.json_error(
+                    ZhtpStatus::NotFound,
+                    format!("Unknown peer_id {}; it was never registered", peer_id),
+                );
+            }
+        };
 
         match self.runtime.disconnect_from_peer(&peer_address).await {
             Ok(()) => {
@@ -1002,8 +1742,19 @@ impl NetworkHandler {
     async fn handle_get_alert_thresholds(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
         info!("API: Getting alert thresholds");
         
-        match crate::runtime::mesh_router_provider::get_alert_thresholds().await {
-            Ok(thresholds) => {
+        let thresholds = match crate::runtime::mesh_router_provider::get_alert_thresholds().await {
+            Ok(thresholds) => thresholds,
+            Err(e) => {
+                error!("API: Failed to get alert thresholds: {}", e);
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::InternalServerError,
+                    format!("Failed to get alert thresholds: {}", e),
+                ));
+            }
+        };
+
+        match crate::runtime::mesh_router_provider::get_peer_flow_params().await {
+            Ok(flow_params) => {
                 let response = AlertThresholdsResponse {
                     status: "success".to_string(),
                     max_block_latency_ms: thresholds.max_block_latency_ms,
@@ -1012,13 +1763,23 @@ impl NetworkHandler {
                     min_validation_success_rate: thresholds.min_validation_success_rate,
                     max_duplicate_ratio: thresholds.max_duplicate_ratio,
                     min_peer_score: thresholds.min_peer_score,
+                    disconnect_score_threshold: thresholds.disconnect_score_threshold,
+                    ban_score_threshold: thresholds.ban_score_threshold,
+                    ban_duration_secs: thresholds.ban_duration_secs,
+                    max_ban_duration_secs: thresholds.max_ban_duration_secs,
+                    reputation_decay_per_minute: thresholds.reputation_decay_per_minute,
+                    reputation_half_life_secs: thresholds.reputation_half_life_secs,
+                    peer_flow_max_buffer: flow_params.max_buffer,
+                    peer_flow_recharge_per_sec: flow_params.recharge_per_sec,
+                    peer_flow_costs: flow_params.cost_table,
+                    violation_penalties: thresholds.violation_penalties.clone(),
                 };
-                
+
                 info!("API: Retrieved alert thresholds");
-                
+
                 let json = serde_json::to_vec(&response)
                     .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
-                
+
                 Ok(ZhtpResponse::success_with_content_type(
                     json,
                     "application/json".to_string(),
@@ -1026,10 +1787,10 @@ impl NetworkHandler {
                 ))
             }
             Err(e) => {
-                error!("API: Failed to get alert thresholds: {}", e);
+                error!("API: Failed to get peer flow params: {}", e);
                 Ok(ZhtpResponse::error(
                     ZhtpStatus::InternalServerError,
-                    format!("Failed to get alert thresholds: {}", e),
+                    format!("Failed to get peer flow params: {}", e),
                 ))
             }
         }
@@ -1073,10 +1834,56 @@ impl NetworkHandler {
                 if let Some(val) = update_request.min_peer_score {
                     thresholds.min_peer_score = val;
                 }
-                
-                // Apply the updated thresholds
-                match crate::runtime::mesh_router_provider::update_alert_thresholds(thresholds.clone()).await {
-                    Ok(()) => {
+                if let Some(val) = update_request.disconnect_score_threshold {
+                    thresholds.disconnect_score_threshold = val;
+                }
+                if let Some(val) = update_request.ban_score_threshold {
+                    thresholds.ban_score_threshold = val;
+                }
+                if let Some(val) = update_request.ban_duration_secs {
+                    thresholds.ban_duration_secs = val;
+                }
+                if let Some(val) = update_request.max_ban_duration_secs {
+                    thresholds.max_ban_duration_secs = val;
+                }
+                if let Some(val) = update_request.reputation_decay_per_minute {
+                    thresholds.reputation_decay_per_minute = val;
+                }
+                if let Some(val) = update_request.reputation_half_life_secs {
+                    thresholds.reputation_half_life_secs = val;
+                }
+                if let Some(penalties) = update_request.violation_penalties {
+                    thresholds.violation_penalties = penalties;
+                }
+
+                // Merge any peer flow-control updates on top of the current
+                // defaults before applying either set of changes.
+                let mut flow_params = match crate::runtime::mesh_router_provider::get_peer_flow_params().await {
+                    Ok(params) => params,
+                    Err(e) => {
+                        error!("API: Failed to get current peer flow params: {}", e);
+                        return Ok(ZhtpResponse::error(
+                            ZhtpStatus::InternalServerError,
+                            format!("Failed to get current peer flow params: {}", e),
+                        ));
+                    }
+                };
+                if let Some(val) = update_request.peer_flow_max_buffer {
+                    flow_params.max_buffer = val;
+                }
+                if let Some(val) = update_request.peer_flow_recharge_per_sec {
+                    flow_params.recharge_per_sec = val;
+                }
+                if let Some(costs) = update_request.peer_flow_costs {
+                    flow_params.cost_table = costs;
+                }
+
+                // Apply the updated thresholds and flow params
+                let threshold_update = crate::runtime::mesh_router_provider::update_alert_thresholds(thresholds.clone()).await;
+                let flow_update = crate::runtime::mesh_router_provider::update_peer_flow_params(flow_params.clone()).await;
+
+                match (threshold_update, flow_update) {
+                    (Ok(()), Ok(())) => {
                         let response = AlertThresholdsResponse {
                             status: "success".to_string(),
                             max_block_latency_ms: thresholds.max_block_latency_ms,
@@ -1085,20 +1892,30 @@ impl NetworkHandler {
                             min_validation_success_rate: thresholds.min_validation_success_rate,
                             max_duplicate_ratio: thresholds.max_duplicate_ratio,
                             min_peer_score: thresholds.min_peer_score,
+                            disconnect_score_threshold: thresholds.disconnect_score_threshold,
+                            ban_score_threshold: thresholds.ban_score_threshold,
+                            ban_duration_secs: thresholds.ban_duration_secs,
+                            max_ban_duration_secs: thresholds.max_ban_duration_secs,
+                            reputation_decay_per_minute: thresholds.reputation_decay_per_minute,
+                            reputation_half_life_secs: thresholds.reputation_half_life_secs,
+                            peer_flow_max_buffer: flow_params.max_buffer,
+                            peer_flow_recharge_per_sec: flow_params.recharge_per_sec,
+                            peer_flow_costs: flow_params.cost_table,
+                            violation_penalties: thresholds.violation_penalties.clone(),
                         };
-                        
+
                         info!("API: Successfully updated alert thresholds");
-                        
+
                         let json = serde_json::to_vec(&response)
                             .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
-                        
+
                         Ok(ZhtpResponse::success_with_content_type(
                             json,
                             "application/json".to_string(),
                             None,
                         ))
                     }
-                    Err(e) => {
+                    (Err(e), _) | (_, Err(e)) => {
                         error!("API: Failed to update alert thresholds: {}", e);
                         Ok(ZhtpResponse::error(
                             ZhtpStatus::InternalServerError,
@@ -1146,6 +1963,7 @@ impl NetworkHandler {
                         bandwidth_bps: s.bandwidth_bps,
                         active_peers: s.active_peers,
                         banned_peers: s.banned_peers,
+                        warp_restore_active: s.warp_restore_active,
                     }
                 }).collect();
                 
@@ -1176,22 +1994,30 @@ impl NetworkHandler {
         }
     }
 
-    /// Get all peer performance statistics
+    /// Get all peer performance statistics, optionally filtered, sorted, and
+    /// paginated via query parameters:
+    /// `status=banned|warning|active`, `min_reputation=`, `max_violations=`,
+    /// `seen_since=<unix_ts>` (matches peers whose `last_seen` is at or
+    /// after this), `sort=reputation|acceptance_rate|last_seen`,
+    /// `order=asc|desc` (default `desc`), `limit=`, `offset=`.
     /// GET /api/v1/blockchain/sync/peers
-    async fn handle_get_peer_performance(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+    async fn handle_get_peer_performance(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
         info!("API: Getting peer performance statistics");
-        
+
+        let thresholds = match crate::runtime::mesh_router_provider::get_alert_thresholds().await {
+            Ok(thresholds) => thresholds,
+            Err(e) => {
+                error!("API: Failed to get alert thresholds: {}", e);
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::InternalServerError,
+                    format!("Failed to get alert thresholds: {}", e),
+                ));
+            }
+        };
+
         match crate::runtime::mesh_router_provider::list_peer_performance().await {
             Ok(peer_stats) => {
-                let peer_infos: Vec<PeerPerformanceInfo> = peer_stats.iter().map(|stats| {
-                    let status = if stats.violations > 10 {
-                        "banned"
-                    } else if stats.reputation_score < 0 {
-                        "warning"
-                    } else {
-                        "active"
-                    };
-                    
+                let mut peer_infos: Vec<PeerPerformanceInfo> = peer_stats.iter().map(|stats| {
                     PeerPerformanceInfo {
                         peer_id: stats.peer_id.clone(),
                         reputation_score: stats.reputation_score,
@@ -1203,21 +2029,83 @@ impl NetworkHandler {
                         acceptance_rate: stats.acceptance_rate,
                         first_seen: stats.first_seen,
                         last_seen: stats.last_seen,
-                        status: status.to_string(),
+                        status: Self::peer_status(stats, &thresholds).to_string(),
+                        ban_expires_at: stats.ban_expires_at,
+                        ban_reason: stats.ban_reason.clone(),
+                        ban_count: stats.ban_count,
+                        score_trend: stats.score_trend,
+                        decayed_reputation: stats.decayed_reputation,
+                        credits: stats.credits,
+                        max_buffer: stats.max_buffer,
+                        recharge_per_sec: stats.recharge_per_sec,
                     }
                 }).collect();
-                
-                let response = PeerPerformanceResponse {
+
+                if let Some(status) = Self::query_param(&request, "status") {
+                    peer_infos.retain(|p| p.status == status);
+                }
+                if let Some(min_reputation) = Self::query_param(&request, "min_reputation")
+                    .and_then(|v| v.parse::<i32>().ok())
+                {
+                    peer_infos.retain(|p| p.reputation_score >= min_reputation);
+                }
+                if let Some(max_violations) = Self::query_param(&request, "max_violations")
+                    .and_then(|v| v.parse::<u32>().ok())
+                {
+                    peer_infos.retain(|p| p.violations <= max_violations);
+                }
+                if let Some(seen_since) = Self::query_param(&request, "seen_since")
+                    .and_then(|v| v.parse::<u64>().ok())
+                {
+                    peer_infos.retain(|p| p.last_seen >= seen_since);
+                }
+
+                let total = peer_infos.len();
+
+                let descending = Self::query_param(&request, "order") != Some("asc");
+                if let Some(sort) = Self::query_param(&request, "sort") {
+                    match sort {
+                        "reputation" => peer_infos.sort_by_key(|p| p.reputation_score),
+                        "acceptance_rate" => peer_infos.sort_by(|a, b| {
+                            a.acceptance_rate.total_cmp(&b.acceptance_rate)
+                        }),
+                        "last_seen" => peer_infos.sort_by_key(|p| p.last_seen),
+                        other => {
+                            return Ok(ZhtpResponse::error(
+                                ZhtpStatus::BadRequest,
+                                format!("Unknown sort field '{}': expected reputation, acceptance_rate, or last_seen", other),
+                            ));
+                        }
+                    }
+                    if descending {
+                        peer_infos.reverse();
+                    }
+                }
+
+                let offset = Self::query_param(&request, "offset")
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .unwrap_or(0);
+                let limit = Self::query_param(&request, "limit")
+                    .and_then(|v| v.parse::<usize>().ok());
+
+                let page: Vec<PeerPerformanceInfo> = peer_infos.into_iter()
+                    .skip(offset)
+                    .take(limit.unwrap_or(usize::MAX))
+                    .collect();
+
+                let response = PeerPerformanceQueryResponse {
                     status: "success".to_string(),
-                    total_peers: peer_infos.len(),
-                    peers: peer_infos,
+                    total,
+                    count: page.len(),
+                    offset,
+                    peers: page,
                 };
-                
-                info!("API: Retrieved performance stats for {} peers", response.total_peers);
-                
+
+                info!("API: Retrieved performance stats for {}/{} peers", response.count, response.total);
+
                 let json = serde_json::to_vec(&response)
                     .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
-                
+
                 Ok(ZhtpResponse::success_with_content_type(
                     json,
                     "application/json".to_string(),
@@ -1234,6 +2122,110 @@ impl NetworkHandler {
         }
     }
 
+    /// Network-wide peer health summary: status breakdown, central tendency
+    /// of decayed reputation, aggregate acceptance rate, and the current
+    /// worst/best performers.
+    /// GET /api/v1/blockchain/sync/peers/summary
+    async fn handle_get_peer_summary(&self, _request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        info!("API: Getting peer health summary");
+
+        let thresholds = match crate::runtime::mesh_router_provider::get_alert_thresholds().await {
+            Ok(thresholds) => thresholds,
+            Err(e) => {
+                error!("API: Failed to get alert thresholds: {}", e);
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::InternalServerError,
+                    format!("Failed to get alert thresholds: {}", e),
+                ));
+            }
+        };
+
+        let peer_stats = match crate::runtime::mesh_router_provider::list_peer_performance().await {
+            Ok(stats) => stats,
+            Err(e) => {
+                error!("API: Failed to get peer performance: {}", e);
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::InternalServerError,
+                    format!("Failed to get peer performance: {}", e),
+                ));
+            }
+        };
+
+        let total_peers = peer_stats.len();
+        let mut active_peers = 0;
+        let mut warning_peers = 0;
+        let mut banned_peers = 0;
+        let mut accepted_total: u64 = 0;
+        let mut seen_total: u64 = 0;
+        let mut decayed_reputations: Vec<f64> = Vec::with_capacity(total_peers);
+        let mut best: Option<(String, f64)> = None;
+        let mut worst: Option<(String, f64)> = None;
+
+        for stats in &peer_stats {
+            match Self::peer_status(stats, &thresholds) {
+                "banned" => banned_peers += 1,
+                "warning" => warning_peers += 1,
+                _ => active_peers += 1,
+            }
+
+            accepted_total += stats.blocks_accepted + stats.txs_accepted;
+            seen_total += stats.blocks_accepted + stats.blocks_rejected
+                + stats.txs_accepted + stats.txs_rejected;
+
+            let decayed = stats.decayed_reputation;
+            decayed_reputations.push(decayed);
+
+            if best.as_ref().map_or(true, |(_, score)| decayed > *score) {
+                best = Some((stats.peer_id.clone(), decayed));
+            }
+            if worst.as_ref().map_or(true, |(_, score)| decayed < *score) {
+                worst = Some((stats.peer_id.clone(), decayed));
+            }
+        }
+
+        decayed_reputations.sort_by(|a, b| a.total_cmp(b));
+        let mean_decayed_reputation = if total_peers == 0 {
+            0.0
+        } else {
+            decayed_reputations.iter().sum::<f64>() / total_peers as f64
+        };
+        let median_decayed_reputation = match decayed_reputations.len() {
+            0 => 0.0,
+            n if n % 2 == 1 => decayed_reputations[n / 2],
+            n => (decayed_reputations[n / 2 - 1] + decayed_reputations[n / 2]) / 2.0,
+        };
+        let aggregate_acceptance_rate = if seen_total == 0 {
+            100.0
+        } else {
+            (accepted_total as f64 / seen_total as f64) * 100.0
+        };
+
+        let response = PeerHealthSummaryResponse {
+            status: "success".to_string(),
+            total_peers,
+            active_peers,
+            warning_peers,
+            banned_peers,
+            mean_decayed_reputation,
+            median_decayed_reputation,
+            aggregate_acceptance_rate,
+            best_peer: best.map(|(peer_id, decayed_reputation)| PeerSummaryEntry { peer_id, decayed_reputation }),
+            worst_peer: worst.map(|(peer_id, decayed_reputation)| PeerSummaryEntry { peer_id, decayed_reputation }),
+        };
+
+        info!("API: Peer health summary: {} peers ({} active, {} warning, {} banned)",
+            response.total_peers, response.active_peers, response.warning_peers, response.banned_peers);
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            "application/json".to_string(),
+            None,
+        ))
+    }
+
     /// Get specific peer performance statistics
     /// GET /api/v1/blockchain/sync/peers/{peer_id}
     async fn handle_get_specific_peer_performance(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
@@ -1252,17 +2244,20 @@ impl NetworkHandler {
         };
         
         info!("API: Getting performance statistics for peer: {}", peer_id);
-        
+
+        let thresholds = match crate::runtime::mesh_router_provider::get_alert_thresholds().await {
+            Ok(thresholds) => thresholds,
+            Err(e) => {
+                error!("API: Failed to get alert thresholds: {}", e);
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::InternalServerError,
+                    format!("Failed to get alert thresholds: {}", e),
+                ));
+            }
+        };
+
         match crate::runtime::mesh_router_provider::get_peer_performance(&peer_id).await {
             Ok(Some(stats)) => {
-                let status = if stats.violations > 10 {
-                    "banned"
-                } else if stats.reputation_score < 0 {
-                    "warning"
-                } else {
-                    "active"
-                };
-                
                 let peer_info = PeerPerformanceInfo {
                     peer_id: stats.peer_id.clone(),
                     reputation_score: stats.reputation_score,
@@ -1274,9 +2269,17 @@ impl NetworkHandler {
                     acceptance_rate: stats.acceptance_rate,
                     first_seen: stats.first_seen,
                     last_seen: stats.last_seen,
-                    status: status.to_string(),
+                    status: Self::peer_status(&stats, &thresholds).to_string(),
+                    ban_expires_at: stats.ban_expires_at,
+                    ban_reason: stats.ban_reason.clone(),
+                    ban_count: stats.ban_count,
+                    score_trend: stats.score_trend,
+                    decayed_reputation: stats.decayed_reputation,
+                    credits: stats.credits,
+                    max_buffer: stats.max_buffer,
+                    recharge_per_sec: stats.recharge_per_sec,
                 };
-                
+
                 let response = serde_json::json!({
                     "status": "success",
                     "peer": peer_info
@@ -1309,4 +2312,653 @@ impl NetworkHandler {
             }
         }
     }
+
+    /// Require a bearer token on node-administration endpoints. This
+    /// repo has no peer-admin identity/session concept (unlike
+    /// `DaoHandler`'s DID-backed sessions), so we only check for presence
+    /// of the header, consistent with `StorageHandler`'s lighter Bearer gate.
+    fn require_authenticated(request: &ZhtpRequest) -> Result<(), ZhtpResult<ZhtpResponse>> {
+        let has_token = request.headers.get("Authorization")
+            .map(|h| h.starts_with("Bearer ") && h.len() > "Bearer ".len())
+            .unwrap_or(false);
+
+        if has_token {
+            Ok(())
+        } else {
+            Err(Ok(ZhtpResponse::error(
+                ZhtpStatus::Unauthorized,
+                "Missing or invalid Authorization header".to_string(),
+            )))
+        }
+    }
+
+    /// Manually report a protocol violation for a peer, applying the
+    /// graduated sanction engine.
+    /// POST /api/v1/blockchain/sync/peers/{peer_id}/punish
+    async fn handle_punish_peer(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        if let Err(resp) = Self::require_authenticated(&request) {
+            return resp;
+        }
+
+        let peer_id = match request.uri
+            .split('?').next().unwrap_or(&request.uri)
+            .strip_prefix("/api/v1/blockchain/sync/peers/")
+            .and_then(|rest| rest.strip_suffix("/punish"))
+        {
+            Some(id) => id.to_string(),
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Invalid peer punishment URL format".to_string(),
+                ));
+            }
+        };
+
+        let punish_request: PunishPeerRequest = if request.body.is_empty() {
+            return Ok(ZhtpResponse::error(
+                ZhtpStatus::BadRequest,
+                "Request body with 'violation' is required".to_string(),
+            ));
+        } else {
+            serde_json::from_slice(&request.body)
+                .map_err(|e| anyhow::anyhow!("Invalid JSON in request body: {}", e))?
+        };
+
+        let kind = match crate::unified_server::ViolationKind::parse(&punish_request.violation) {
+            Some(k) => k,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    format!(
+                        "Unknown violation '{}': expected invalid_block, duplicate_flood, bad_proof, timeout, or credit_overrun",
+                        punish_request.violation
+                    ),
+                ));
+            }
+        };
+
+        info!("API: Recording '{}' violation for peer {}", punish_request.violation, peer_id);
+
+        let punishment = crate::runtime::mesh_router_provider::punish_peer(&peer_id, kind).await
+            .map_err(|e| anyhow::anyhow!("Failed to punish peer: {}", e))?;
+
+        let ban_expires_at = crate::runtime::mesh_router_provider::peer_ban_expiry(&peer_id).await
+            .map_err(|e| anyhow::anyhow!("Failed to read ban state: {}", e))?;
+
+        let punishment_str = match punishment {
+            crate::unified_server::Punishment::None => "none",
+            crate::unified_server::Punishment::Disconnect => "disconnect",
+            crate::unified_server::Punishment::Ban => "ban",
+        };
+
+        let response = PunishPeerResponse {
+            status: "success".to_string(),
+            peer_id,
+            punishment: punishment_str.to_string(),
+            ban_expires_at,
+        };
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// Directly ban a peer for `reason`, independent of `handle_punish_peer`'s
+    /// violation-scoring ladder - for admin-imposed bans (`manual_admin`,
+    /// `abusive`) or any ban needing a caller-specified duration.
+    /// POST /api/v1/blockchain/sync/peers/{peer_id}/ban
+    async fn handle_ban_peer(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        if let Err(resp) = Self::require_authenticated(&request) {
+            return resp;
+        }
+
+        let peer_id = match request.uri
+            .split('?').next().unwrap_or(&request.uri)
+            .strip_prefix("/api/v1/blockchain/sync/peers/")
+            .and_then(|rest| rest.strip_suffix("/ban"))
+        {
+            Some(id) => id.to_string(),
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Invalid ban URL format".to_string(),
+                ));
+            }
+        };
+
+        let ban_request: BanPeerRequest = if request.body.is_empty() {
+            return Ok(ZhtpResponse::error(
+                ZhtpStatus::BadRequest,
+                "Request body with 'reason' is required".to_string(),
+            ));
+        } else {
+            serde_json::from_slice(&request.body)
+                .map_err(|e| anyhow::anyhow!("Invalid JSON in request body: {}", e))?
+        };
+
+        let reason = match crate::unified_server::BanReason::parse(&ban_request.reason) {
+            Some(r) => r,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    format!(
+                        "Unknown ban reason '{}': expected bad_block, bad_transaction, protocol_violation, manual_admin, or abusive",
+                        ban_request.reason
+                    ),
+                ));
+            }
+        };
+
+        info!("API: Banning peer {} for '{}'", peer_id, ban_request.reason);
+
+        let expires_at = crate::runtime::mesh_router_provider::ban_peer(
+            &peer_id,
+            reason,
+            ban_request.duration_secs,
+        ).await.map_err(|e| anyhow::anyhow!("Failed to ban peer: {}", e))?;
+
+        let response = BanPeerResponse {
+            status: "success".to_string(),
+            peer_id,
+            reason: reason.as_str().to_string(),
+            expires_at,
+        };
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// Lift an active ban on a peer.
+    /// DELETE /api/v1/blockchain/sync/peers/{peer_id}/ban
+    async fn handle_lift_ban(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        if let Err(resp) = Self::require_authenticated(&request) {
+            return resp;
+        }
+
+        let peer_id = match request.uri
+            .split('?').next().unwrap_or(&request.uri)
+            .strip_prefix("/api/v1/blockchain/sync/peers/")
+            .and_then(|rest| rest.strip_suffix("/ban"))
+        {
+            Some(id) => id.to_string(),
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Invalid ban-lift URL format".to_string(),
+                ));
+            }
+        };
+
+        info!("API: Lifting ban for peer {}", peer_id);
+
+        let lifted = crate::runtime::mesh_router_provider::lift_peer_ban(&peer_id).await
+            .map_err(|e| anyhow::anyhow!("Failed to lift ban: {}", e))?;
+
+        let response = LiftBanResponse {
+            status: if lifted { "success" } else { "not_found" }.to_string(),
+            peer_id,
+            lifted,
+        };
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// Operator override to manually unban a peer, equivalent to
+    /// `handle_lift_ban` but surfaced under the peer-management prefix so
+    /// it sits next to `/peer/add`/`/peer/{id}` (remove).
+    /// POST /api/v1/blockchain/network/peer/{peer_id}/unban
+    async fn handle_unban_peer(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        if let Err(resp) = Self::require_authenticated(&request) {
+            return resp;
+        }
+
+        let peer_id = match request.uri
+            .split('?').next().unwrap_or(&request.uri)
+            .strip_prefix("/api/v1/blockchain/network/peer/")
+            .and_then(|rest| rest.strip_suffix("/unban"))
+        {
+            Some(id) => id.to_string(),
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Invalid unban URL format".to_string(),
+                ));
+            }
+        };
+
+        info!("API: Unbanning peer {}", peer_id);
+
+        let lifted = crate::runtime::mesh_router_provider::lift_peer_ban(&peer_id).await
+            .map_err(|e| anyhow::anyhow!("Failed to unban peer: {}", e))?;
+
+        let response = LiftBanResponse {
+            status: if lifted { "success" } else { "not_found" }.to_string(),
+            peer_id,
+            lifted,
+        };
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// Parse a `SubscribeRequest` body into the filter the drain task
+    /// matches events against, rejecting unknown topic/level names.
+    fn parse_subscription_filter(
+        request: &SubscribeRequest,
+    ) -> Result<crate::unified_server::SubscriptionFilter, String> {
+        let topics = request.topics.iter()
+            .map(|t| crate::unified_server::MonitoringTopic::parse(t)
+                .ok_or_else(|| format!("Unknown topic '{}': expected alerts, performance, or peer_status", t)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let min_level = match request.min_level.as_deref() {
+            None => crate::unified_server::AlertLevel::Info,
+            Some("info") => crate::unified_server::AlertLevel::Info,
+            Some("warning") => crate::unified_server::AlertLevel::Warning,
+            Some("critical") => crate::unified_server::AlertLevel::Critical,
+            Some(other) => return Err(format!("Unknown min_level '{}': expected info, warning, or critical", other)),
+        };
+
+        Ok(crate::unified_server::SubscriptionFilter {
+            topics,
+            min_level,
+            peer_id: request.peer_id.clone(),
+            performance_interval_secs: request.interval_secs.unwrap_or(10),
+        })
+    }
+
+    /// Parse a `topics` query entry for `GET .../sync/events`, accepting
+    /// its `metrics`/`peers` aliases alongside the canonical
+    /// `performance`/`peer_status` names `POST .../subscribe` uses.
+    fn parse_topic_alias(s: &str) -> Option<crate::unified_server::MonitoringTopic> {
+        match s {
+            "metrics" => Some(crate::unified_server::MonitoringTopic::Performance),
+            "peers" => Some(crate::unified_server::MonitoringTopic::PeerStatus),
+            other => crate::unified_server::MonitoringTopic::parse(other),
+        }
+    }
+
+    /// Parse `GET .../sync/events`'s `topics`/`min_level`/`peer_id`/
+    /// `interval_secs` query params into the same
+    /// `crate::unified_server::SubscriptionFilter` `.../subscribe` matches
+    /// events against.
+    fn parse_events_filter(request: &ZhtpRequest) -> Result<crate::unified_server::SubscriptionFilter, String> {
+        let topics = Self::query_param(request, "topics")
+            .map(|v| v.split(',').filter(|t| !t.is_empty())
+                .map(|t| Self::parse_topic_alias(t)
+                    .ok_or_else(|| format!("Unknown topic '{}': expected alerts, metrics, or peers", t)))
+                .collect::<Result<Vec<_>, _>>())
+            .transpose()?
+            .unwrap_or_default();
+
+        let min_level = match Self::query_param(request, "min_level") {
+            None => crate::unified_server::AlertLevel::Info,
+            Some("info") => crate::unified_server::AlertLevel::Info,
+            Some("warning") => crate::unified_server::AlertLevel::Warning,
+            Some("critical") => crate::unified_server::AlertLevel::Critical,
+            Some(other) => return Err(format!("Unknown min_level '{}': expected info, warning, or critical", other)),
+        };
+
+        Ok(crate::unified_server::SubscriptionFilter {
+            topics,
+            min_level,
+            peer_id: Self::query_param(request, "peer_id").map(|s| s.to_string()),
+            performance_interval_secs: Self::query_param(request, "interval_secs")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10),
+        })
+    }
+
+    /// Render a [`crate::unified_server::MonitoringEvent`] as one SSE
+    /// `event:`/`data:` frame.
+    fn monitoring_event_to_sse_frame(event: &crate::unified_server::MonitoringEvent) -> String {
+        format!(
+            "event: {}\ndata: {}\n\n",
+            event.topic().as_str(),
+            Self::monitoring_event_to_frame(event),
+        )
+    }
+
+    /// Render a [`crate::unified_server::MonitoringEvent`] as the JSON frame
+    /// a poll response delivers it as.
+    fn monitoring_event_to_frame(event: &crate::unified_server::MonitoringEvent) -> serde_json::Value {
+        match event {
+            crate::unified_server::MonitoringEvent::Alert(alert) => {
+                let level_str = match alert.level {
+                    crate::unified_server::AlertLevel::Info => "info",
+                    crate::unified_server::AlertLevel::Warning => "warning",
+                    crate::unified_server::AlertLevel::Critical => "critical",
+                };
+                serde_json::json!({
+                    "topic": "alerts",
+                    "alert": AlertInfo {
+                        id: alert.id.clone(),
+                        level: level_str.to_string(),
+                        category: alert.category.clone(),
+                        message: alert.message.clone(),
+                        timestamp: alert.timestamp,
+                        acknowledged: alert.acknowledged,
+                        peer_id: alert.peer_id.clone(),
+                        metric_value: alert.metric_value,
+                        threshold_value: alert.threshold_value,
+                    }
+                })
+            }
+            crate::unified_server::MonitoringEvent::Performance(metrics) => serde_json::json!({
+                "topic": "performance",
+                "performance": PerformanceMetricsResponse {
+                    status: "success".to_string(),
+                    avg_block_propagation_ms: metrics.avg_block_propagation_ms,
+                    avg_tx_propagation_ms: metrics.avg_tx_propagation_ms,
+                    p95_block_latency_ms: metrics.p95_block_latency_ms,
+                    p95_tx_latency_ms: metrics.p95_tx_latency_ms,
+                    min_block_latency_ms: metrics.min_block_latency_ms,
+                    max_block_latency_ms: metrics.max_block_latency_ms,
+                    min_tx_latency_ms: metrics.min_tx_latency_ms,
+                    max_tx_latency_ms: metrics.max_tx_latency_ms,
+                    bytes_sent_per_sec: metrics.bytes_sent_per_sec,
+                    bytes_received_per_sec: metrics.bytes_received_per_sec,
+                    peak_bandwidth_usage_bps: metrics.peak_bandwidth_usage_bps,
+                    duplicate_block_ratio: metrics.duplicate_block_ratio,
+                    duplicate_tx_ratio: metrics.duplicate_tx_ratio,
+                    validation_success_rate: metrics.validation_success_rate,
+                    relay_efficiency: metrics.relay_efficiency,
+                    measurement_duration_secs: metrics.measurement_duration_secs,
+                }
+            }),
+            crate::unified_server::MonitoringEvent::PeerStatus { peer_id, score, punishment } => serde_json::json!({
+                "topic": "peer_status",
+                "peer_id": peer_id,
+                "score": score,
+                "punishment": punishment,
+            }),
+        }
+    }
+
+    /// Spawn the background task that drains the shared monitoring
+    /// broadcast channel into `frames`, filtered by `filter`, for as long as
+    /// the subscription lives.
+    fn spawn_subscription_drain(
+        mut events: tokio::sync::broadcast::Receiver<crate::unified_server::MonitoringEvent>,
+        filter: crate::unified_server::SubscriptionFilter,
+        frames: Arc<RwLock<VecDeque<String>>>,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut last_performance_frame = Instant::now() - Duration::from_secs(filter.performance_interval_secs.max(1));
+
+            loop {
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                };
+
+                if !event.matches(&filter) {
+                    continue;
+                }
+
+                if matches!(event, crate::unified_server::MonitoringEvent::Performance(_)) {
+                    if last_performance_frame.elapsed() < Duration::from_secs(filter.performance_interval_secs) {
+                        continue;
+                    }
+                    last_performance_frame = Instant::now();
+                }
+
+                let frame = Self::monitoring_event_to_frame(&event).to_string();
+                let mut queue = frames.write().await;
+                if queue.len() >= SUBSCRIPTION_QUEUE_CAPACITY {
+                    queue.pop_front();
+                }
+                queue.push_back(frame);
+            }
+        })
+    }
+
+    /// Evict subscriptions nobody has polled in `SUBSCRIPTION_IDLE_TTL`,
+    /// stopping their drain tasks (see `Subscription`'s `Drop`).
+    async fn evict_idle_subscriptions(&self) {
+        let mut subscriptions = self.subscriptions.write().await;
+        let mut idle = Vec::new();
+        for (id, sub) in subscriptions.iter() {
+            if sub.last_polled.read().await.elapsed() >= SUBSCRIPTION_IDLE_TTL {
+                idle.push(id.clone());
+            }
+        }
+        for id in idle {
+            subscriptions.remove(&id);
+        }
+    }
+
+    /// Bounded long-poll for newly pushed alerts, metrics snapshots, and
+    /// peer-status changes, framed as server-sent events and filterable
+    /// with `?topics=alerts,metrics,peers` (aliases for the
+    /// `alerts`/`performance`/`peer_status` topics `.../subscribe` uses),
+    /// `min_level`, `peer_id`, and `interval_secs` query params.
+    ///
+    /// ZHTP's `ZhtpResponse` has a single fixed `Vec<u8>` body with no
+    /// chunked or server-push transport (see `PollSubscriptionResponse`'s
+    /// doc comment), so this can't hold the connection open indefinitely
+    /// the way a real SSE endpoint would. Instead it subscribes to the
+    /// same monitoring broadcast channel `.../subscribe` drains, waits up
+    /// to `wait_ms` (default [`EVENTS_DEFAULT_WAIT`], capped at
+    /// [`EVENTS_MAX_WAIT`]) for at least one matching event, and returns
+    /// whatever it collected - as `event:`/`data:` frames, the same way a
+    /// held-open stream would have written them - in one buffered
+    /// response. A client wanting a continuous feed calls this endpoint
+    /// in a loop; each call blocks for up to `wait_ms` rather than the
+    /// connection itself staying open between events.
+    /// GET /api/v1/blockchain/sync/events
+    async fn handle_get_events(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let filter = match Self::parse_events_filter(&request) {
+            Ok(f) => f,
+            Err(message) => return Ok(ZhtpResponse::error(ZhtpStatus::BadRequest, message)),
+        };
+
+        let wait = Self::query_param(&request, "wait_ms")
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(EVENTS_DEFAULT_WAIT)
+            .min(EVENTS_MAX_WAIT);
+
+        let mut events = crate::runtime::mesh_router_provider::subscribe_monitoring_events().await
+            .map_err(|e| anyhow::anyhow!("Failed to subscribe to monitoring events: {}", e))?;
+
+        let deadline = Instant::now() + wait;
+        let mut last_performance_frame = Instant::now() - Duration::from_secs(filter.performance_interval_secs.max(1));
+        let mut frames = Vec::new();
+
+        while frames.len() < EVENTS_MAX_FRAMES {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+
+            let event = match tokio::time::timeout(remaining, events.recv()).await {
+                Ok(Ok(event)) => event,
+                Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(_))) => continue,
+                Ok(Err(tokio::sync::broadcast::error::RecvError::Closed)) => break,
+                Err(_elapsed) => break,
+            };
+
+            if !event.matches(&filter) {
+                continue;
+            }
+
+            if matches!(event, crate::unified_server::MonitoringEvent::Performance(_)) {
+                if last_performance_frame.elapsed() < Duration::from_secs(filter.performance_interval_secs) {
+                    continue;
+                }
+                last_performance_frame = Instant::now();
+            }
+
+            frames.push(Self::monitoring_event_to_sse_frame(&event));
+        }
+
+        info!("API: Sync events long-poll returned {} frame(s)", frames.len());
+
+        Ok(ZhtpResponse::success_with_content_type(
+            frames.concat().into_bytes(),
+            SSE_CONTENT_TYPE.to_string(),
+            None,
+        ))
+    }
+
+    /// Register a pub/sub filter and start draining matching monitoring
+    /// events into a per-subscription queue.
+    /// POST /api/v1/blockchain/sync/subscribe
+    async fn handle_subscribe(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        self.evict_idle_subscriptions().await;
+
+        let subscribe_request: SubscribeRequest = if request.body.is_empty() {
+            SubscribeRequest { topics: Vec::new(), min_level: None, peer_id: None, interval_secs: None }
+        } else {
+            serde_json::from_slice(&request.body)
+                .map_err(|e| anyhow::anyhow!("Invalid JSON in request body: {}", e))?
+        };
+
+        let filter = match Self::parse_subscription_filter(&subscribe_request) {
+            Ok(f) => f,
+            Err(message) => return Ok(ZhtpResponse::error(ZhtpStatus::BadRequest, message)),
+        };
+
+        let events = crate::runtime::mesh_router_provider::subscribe_monitoring_events().await
+            .map_err(|e| anyhow::anyhow!("Failed to subscribe to monitoring events: {}", e))?;
+
+        let subscription_id = uuid::Uuid::new_v4().to_string();
+        let frames = Arc::new(RwLock::new(VecDeque::new()));
+        let task = Self::spawn_subscription_drain(events, filter.clone(), frames.clone());
+
+        self.subscriptions.write().await.insert(subscription_id.clone(), Subscription {
+            filter,
+            frames,
+            last_polled: RwLock::new(Instant::now()),
+            task,
+        });
+
+        info!("API: Registered monitoring subscription {}", subscription_id);
+
+        let response = SubscribeResponse {
+            status: "success".to_string(),
+            subscription_id,
+        };
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// Drain and return the newline-delimited JSON frames a subscription has
+    /// accumulated since its last poll.
+    /// GET /api/v1/blockchain/sync/subscribe/{id}/poll
+    async fn handle_poll_subscription(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let subscription_id = match request.uri
+            .split('?').next().unwrap_or(&request.uri)
+            .strip_prefix("/api/v1/blockchain/sync/subscribe/")
+            .and_then(|rest| rest.strip_suffix("/poll"))
+        {
+            Some(id) => id.to_string(),
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Invalid subscription poll URL format".to_string(),
+                ));
+            }
+        };
+
+        let subscriptions = self.subscriptions.read().await;
+        let subscription = match subscriptions.get(&subscription_id) {
+            Some(s) => s,
+            None => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::NotFound,
+                    format!("Subscription {} not found", subscription_id),
+                ));
+            }
+        };
+
+        *subscription.last_polled.write().await = Instant::now();
+        let drained: Vec<String> = std::mem::take(&mut *subscription.frames.write().await).into_iter().collect();
+        let frame_count = drained.len();
+
+        let response = PollSubscriptionResponse {
+            status: "success".to_string(),
+            subscription_id,
+            frame_count,
+            frames: drained.join("\n"),
+        };
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
+
+    /// Cancel a subscription, stopping its drain task.
+    /// DELETE /api/v1/blockchain/sync/subscribe/{id}
+    async fn handle_unsubscribe(&self, request: ZhtpRequest) -> ZhtpResult<ZhtpResponse> {
+        let subscription_id = match request.uri
+            .split('?').next().unwrap_or(&request.uri)
+            .strip_prefix("/api/v1/blockchain/sync/subscribe/")
+        {
+            Some(id) if !id.is_empty() => id.to_string(),
+            _ => {
+                return Ok(ZhtpResponse::error(
+                    ZhtpStatus::BadRequest,
+                    "Invalid unsubscribe URL format".to_string(),
+                ));
+            }
+        };
+
+        let removed = self.subscriptions.write().await.remove(&subscription_id).is_some();
+
+        info!("API: Unsubscribed {} (removed: {})", subscription_id, removed);
+
+        let response = UnsubscribeResponse {
+            status: if removed { "success" } else { "not_found" }.to_string(),
+            subscription_id,
+            removed,
+        };
+
+        let json = serde_json::to_vec(&response)
+            .map_err(|e| anyhow::anyhow!("JSON serialization error: {}", e))?;
+
+        Ok(ZhtpResponse::success_with_content_type(
+            json,
+            CONTENT_TYPE_JSON.to_string(),
+            None,
+        ))
+    }
 }
\ No newline at end of file
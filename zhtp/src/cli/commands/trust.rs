@@ -1,18 +1,36 @@
 //! Trust management commands
 
 use anyhow::{anyhow, Result, Context};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
-use lib_network::web4::{TrustDb, TrustConfig, TrustAuditEntry};
+use lib_network::web4::{TrustDb, TrustConfig, TrustAuditEntry, TrustBundle, issue_grant, redeem_grant};
+use lib_crypto::{KeyPair, PrivateKey};
 
 use crate::cli::TrustArgs;
 
+/// Private key storage format (matches identity.rs)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeystorePrivateKey {
+    dilithium_sk: Vec<u8>,
+    kyber_sk: Vec<u8>,
+    master_seed: Vec<u8>,
+}
+
 /// Handle `zhtp trust` commands
 pub async fn handle_trust_command(args: TrustArgs) -> Result<()> {
     match &args.action {
         crate::cli::TrustAction::List => list_trust().await,
         crate::cli::TrustAction::Audit => show_audit().await,
         crate::cli::TrustAction::Reset { node } => reset_trust(node).await,
+        crate::cli::TrustAction::Block { node } => block_trust(node).await,
+        crate::cli::TrustAction::Allow { node } => allow_trust(node).await,
+        crate::cli::TrustAction::Import => import_trust().await,
+        crate::cli::TrustAction::Verify => verify_trust().await,
+        crate::cli::TrustAction::Export { out } => export_trust(out).await,
+        crate::cli::TrustAction::ImportBundle { file } => import_bundle(file).await,
+        crate::cli::TrustAction::Grant { node, ttl } => grant_trust(node, *ttl).await,
+        crate::cli::TrustAction::Redeem { token } => redeem_trust(token).await,
     }
 }
 
@@ -36,6 +54,41 @@ async fn list_trust() -> Result<()> {
         println!("    Policy: {:?}", anchor.policy);
         println!("    First seen: {}", anchor.first_seen);
         println!("    Last seen: {}", anchor.last_seen);
+        if let Some(expires_at) = anchor.expires_at {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            if expires_at <= now {
+                println!("    Expires: already expired at {}", expires_at);
+            } else {
+                println!("    Expires: {} ({}s remaining)", expires_at, expires_at - now);
+            }
+        }
+        if db.is_blocked(addr) {
+            println!("    Status: BLOCKED");
+        } else if db.is_allowed(addr) {
+            println!("    Status: allowed");
+        }
+    }
+
+    if !db.blocked.is_empty() {
+        println!("\nBlocked nodes ({} entries):", db.blocked.len());
+        for (addr, blocked) in db.blocked.iter() {
+            println!(
+                "- {} (blocked at {}, did={})",
+                addr,
+                blocked.blocked_at,
+                blocked.node_did.as_deref().unwrap_or("unknown"),
+            );
+        }
+    }
+
+    if !db.allowed.is_empty() {
+        println!("\nAllowed nodes ({} entries):", db.allowed.len());
+        for addr in db.allowed.iter() {
+            println!("- {}", addr);
+        }
     }
 
     Ok(())
@@ -51,31 +104,93 @@ async fn show_audit() -> Result<()> {
     }
 
     let data = std::fs::read_to_string(&path)?;
-    let mut count = 0;
-    for line in data.lines() {
+    let mut loaded = 0;
+    let mut skipped = 0;
+    let mut expected_prev = TrustAuditEntry::genesis_hash();
+    for (index, line) in data.lines().enumerate() {
         if line.trim().is_empty() {
             continue;
         }
-        let entry: TrustAuditEntry = serde_json::from_str(line)
-            .with_context(|| format!("Failed to parse audit entry: {}", line))?;
-        count += 1;
+        let entry: TrustAuditEntry = match serde_json::from_str(line) {
+            Ok(entry) => entry,
+            Err(e) => {
+                println!("✗ line {}: failed to parse ({})", index + 1, e);
+                skipped += 1;
+                continue;
+            }
+        };
+        loaded += 1;
+        let marker = if entry.verify(&expected_prev) { "✓" } else { "✗" };
         println!(
-            "{} | node={} | did={} | spki={} | version={}",
+            "{} {} | node={} | did={} | spki={} | version={}",
+            marker,
             entry.timestamp,
             entry.node_addr,
             entry.node_did.as_deref().unwrap_or("unknown"),
             entry.spki_sha256,
             entry.tool_version,
         );
+        expected_prev = entry.entry_hash.clone();
     }
 
-    if count == 0 {
+    if loaded == 0 && skipped == 0 {
         println!("Audit log is empty ({:?})", path);
+        return Ok(());
+    }
+
+    println!("{} entries loaded, {} skipped", loaded, skipped);
+
+    if loaded == 0 {
+        return Err(anyhow!("No audit entries could be loaded from {:?}", path));
     }
 
     Ok(())
 }
 
+async fn verify_trust() -> Result<()> {
+    let audit_path = TrustConfig::default_audit_path();
+    let path = PathBuf::from(&audit_path);
+
+    if !path.exists() {
+        println!("No audit log found at {:?}", path);
+        return Ok(());
+    }
+
+    let data = std::fs::read_to_string(&path)?;
+    let mut expected_prev = TrustAuditEntry::genesis_hash();
+    let mut verified = 0;
+    let mut break_index = None;
+
+    for (index, line) in data.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: TrustAuditEntry = serde_json::from_str(line)
+            .with_context(|| format!("Failed to parse audit entry: {}", line))?;
+
+        if !entry.verify(&expected_prev) {
+            break_index = Some(index);
+            break;
+        }
+        expected_prev = entry.entry_hash.clone();
+        verified += 1;
+    }
+
+    match break_index {
+        Some(index) => {
+            println!(
+                "Audit chain BROKEN at line {} ({:?}); {} entries verified before the break",
+                index + 1, path, verified,
+            );
+            Err(anyhow!("Audit log integrity check failed at line {}", index + 1))
+        }
+        None => {
+            println!("Audit chain intact: {} entries verified ({:?})", verified, path);
+            Ok(())
+        }
+    }
+}
+
 async fn reset_trust(node: &str) -> Result<()> {
     let trustdb_path = TrustConfig::default_trustdb_path()?;
     let mut db = TrustDb::load_or_create(&trustdb_path)
@@ -90,3 +205,181 @@ async fn reset_trust(node: &str) -> Result<()> {
 
     Ok(())
 }
+
+async fn block_trust(node: &str) -> Result<()> {
+    let trustdb_path = TrustConfig::default_trustdb_path()?;
+    let mut db = TrustDb::load_or_create(&trustdb_path)
+        .context("Failed to load trustdb")?;
+
+    db.block(node);
+    db.save(&trustdb_path)?;
+    println!("Blocked {}; future connections will be rejected even with a new key", node);
+
+    Ok(())
+}
+
+async fn allow_trust(node: &str) -> Result<()> {
+    let trustdb_path = TrustConfig::default_trustdb_path()?;
+    let mut db = TrustDb::load_or_create(&trustdb_path)
+        .context("Failed to load trustdb")?;
+
+    db.allow(node);
+    db.save(&trustdb_path)?;
+    println!("Allowed {}; trust-on-first-use will be short-circuited for this node", node);
+
+    Ok(())
+}
+
+async fn import_trust() -> Result<()> {
+    let trustdb_path = TrustConfig::default_trustdb_path()?;
+    let mut db = TrustDb::load_or_create(&trustdb_path)
+        .context("Failed to load trustdb")?;
+
+    let report = db.import_system_roots()
+        .context("Failed to import system root store")?;
+    db.save(&trustdb_path)?;
+
+    println!(
+        "Imported {} trust anchors from the system root store ({} failed)",
+        report.imported,
+        report.failures.len(),
+    );
+    for failure in &report.failures {
+        if failure.index == usize::MAX {
+            println!("  - platform loader error: {}", failure.error);
+        } else {
+            println!("  - certificate #{}: {}", failure.index, failure.error);
+        }
+    }
+
+    Ok(())
+}
+
+async fn export_trust(out: &str) -> Result<()> {
+    let trustdb_path = TrustConfig::default_trustdb_path()?;
+    let db = TrustDb::load_or_create(&trustdb_path)
+        .context("Failed to load trustdb")?;
+
+    let keypair = load_node_keypair()
+        .context("Failed to load local node identity for signing")?;
+    let bundle = TrustBundle::sign(&db, &keypair)
+        .context("Failed to sign trust bundle")?;
+
+    let data = serde_json::to_string_pretty(&bundle)?;
+    std::fs::write(out, data)
+        .with_context(|| format!("Failed to write bundle to {}", out))?;
+
+    println!(
+        "Exported {} trust anchors to {} (signed by key {})",
+        bundle.anchors.len(), out, hex::encode(&keypair.public_key.key_id[..8]),
+    );
+
+    Ok(())
+}
+
+async fn import_bundle(file: &str) -> Result<()> {
+    let data = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read bundle from {}", file))?;
+    let bundle: TrustBundle = serde_json::from_str(&data)
+        .with_context(|| format!("Failed to parse bundle from {}", file))?;
+
+    let trustdb_path = TrustConfig::default_trustdb_path()?;
+    let mut db = TrustDb::load_or_create(&trustdb_path)
+        .context("Failed to load trustdb")?;
+
+    let report = db.import_bundle(&bundle)
+        .context("Bundle rejected")?;
+    db.save(&trustdb_path)?;
+
+    println!(
+        "Merged {} trust anchors from {} ({} conflicts skipped)",
+        report.merged, file, report.conflicts.len(),
+    );
+    for conflict in &report.conflicts {
+        println!(
+            "  - {}: kept existing SPKI {}, bundle proposed {}. Resolve with: zhtp trust reset {}",
+            conflict.node_addr, conflict.existing_spki_sha256, conflict.incoming_spki_sha256, conflict.node_addr,
+        );
+    }
+
+    Ok(())
+}
+
+async fn grant_trust(node: &str, ttl: u64) -> Result<()> {
+    let trustdb_path = TrustConfig::default_trustdb_path()?;
+    let db = TrustDb::load_or_create(&trustdb_path)
+        .context("Failed to load trustdb")?;
+    let anchor = db.get(node).ok_or_else(|| anyhow!(
+        "No trust anchor for {} to vouch for. Connect to it first or import its SPKI.", node
+    ))?;
+
+    let keypair = load_node_keypair()
+        .context("Failed to load local node identity for signing")?;
+    let token = issue_grant(node, &anchor.spki_sha256, ttl, &keypair)
+        .context("Failed to issue grant token")?;
+
+    println!("Grant token for {} (valid {}s):", node, ttl);
+    println!("{}", token);
+
+    Ok(())
+}
+
+async fn redeem_trust(token: &str) -> Result<()> {
+    let anchor = redeem_grant(token)
+        .context("Failed to redeem grant token")?;
+
+    let trustdb_path = TrustConfig::default_trustdb_path()?;
+    let mut db = TrustDb::load_or_create(&trustdb_path)
+        .context("Failed to load trustdb")?;
+
+    println!(
+        "Installed delegated anchor for {} (SPKI {}), expires at {}",
+        anchor.node_addr, anchor.spki_sha256, anchor.expires_at.unwrap_or(0),
+    );
+    db.set(anchor);
+    db.save(&trustdb_path)?;
+
+    Ok(())
+}
+
+/// Load the local node's identity keypair from the default keystore, for
+/// signing exported trust bundles and delegation grants
+fn load_node_keypair() -> Result<KeyPair> {
+    let keystore = dirs::home_dir()
+        .ok_or_else(|| anyhow!("Could not determine home directory"))?
+        .join(".zhtp")
+        .join("keystore");
+
+    let identity_file = keystore.join("identity.json");
+    let private_key_file = keystore.join("private_key.json");
+
+    if !identity_file.exists() || !private_key_file.exists() {
+        return Err(anyhow!(
+            "No local identity found in keystore at {:?}\n\
+            Create one first with: zhtp identity create <name>",
+            keystore
+        ));
+    }
+
+    let identity_data = std::fs::read_to_string(&identity_file)
+        .context("Failed to read identity.json")?;
+    let identity: serde_json::Value = serde_json::from_str(&identity_data)
+        .context("Failed to parse identity.json")?;
+    let public_key = serde_json::from_value(
+        identity.get("public_key").cloned().ok_or_else(|| anyhow!("identity.json missing public_key"))?,
+    ).context("Failed to parse public_key from identity.json")?;
+
+    let private_key_data = std::fs::read_to_string(&private_key_file)
+        .context("Failed to read private_key.json")?;
+    let keystore_key: KeystorePrivateKey = serde_json::from_str(&private_key_data)
+        .context("Failed to parse private_key.json")?;
+
+    Ok(KeyPair {
+        public_key,
+        private_key: PrivateKey {
+            dilithium_sk: keystore_key.dilithium_sk,
+            kyber_sk: keystore_key.kyber_sk,
+            master_seed: keystore_key.master_seed,
+        },
+    })
+}
@@ -592,6 +592,51 @@ pub enum TrustAction {
         /// Node address (host:port)
         node: String,
     },
+
+    /// Permanently block a node, rejecting it even if it re-presents a new key
+    Block {
+        /// Node address (host:port)
+        node: String,
+    },
+
+    /// Allow a node, short-circuiting trust-on-first-use for it
+    Allow {
+        /// Node address (host:port)
+        node: String,
+    },
+
+    /// Import trust anchors from the platform's native certificate store
+    Import,
+
+    /// Verify the hash chain of the audit log, reporting the first break
+    Verify,
+
+    /// Export trust anchors as a bundle signed with the local node identity
+    Export {
+        /// Output file path for the signed bundle
+        out: String,
+    },
+
+    /// Verify and merge a signed trust bundle into the local trustdb
+    ImportBundle {
+        /// Path to the signed bundle file
+        file: String,
+    },
+
+    /// Issue a signed, expiring trust-delegation token for a node
+    Grant {
+        /// Node address to vouch for (host:port)
+        node: String,
+        /// Lifetime of the grant in seconds
+        #[arg(long, default_value = "3600")]
+        ttl: u64,
+    },
+
+    /// Redeem a signed trust-delegation token, installing a time-bounded anchor
+    Redeem {
+        /// The grant token (header.claims.signature)
+        token: String,
+    },
 }
 
 /// Main CLI runner
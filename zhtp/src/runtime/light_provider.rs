@@ -0,0 +1,203 @@
+//! Light-client ("Provider") subprotocol
+//!
+//! Mirrors the LES model where a full node answers proof-bearing
+//! requests for resource-constrained clients. This chain has no global
+//! account/storage trie (it's UTXO-based, see `Transaction`'s
+//! commitment-hiding outputs), so an "account proof" here proves
+//! inclusion of the transaction that touched `address` under the
+//! queried block's existing `header.merkle_root`, reusing the same
+//! leaf-hashing rule `calculate_transaction_merkle_root` already uses
+//! (see [`lib_blockchain::merkle`]) rather than inventing a state trie
+//! that doesn't exist on this chain.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+
+use lib_blockchain::merkle::MerkleTree;
+use lib_blockchain::transaction::hashing::hash_transaction;
+use lib_blockchain::Block;
+
+use super::RuntimeOrchestrator;
+
+/// A single block header as returned to light clients.
+#[derive(Debug, Clone)]
+pub struct LightHeader {
+    pub height: u64,
+    pub block_hash: String,
+    pub previous_block_hash: String,
+    pub merkle_root: String,
+    pub timestamp: u64,
+}
+
+impl From<&Block> for LightHeader {
+    fn from(block: &Block) -> Self {
+        Self {
+            height: block.header.height,
+            block_hash: block.header.block_hash.to_string(),
+            previous_block_hash: block.header.previous_block_hash.to_string(),
+            merkle_root: block.header.merkle_root.to_string(),
+            timestamp: block.header.timestamp,
+        }
+    }
+}
+
+/// Proof that `address` was the recipient of a transaction included in
+/// `header`, verifiable against `header.merkle_root`.
+#[derive(Debug, Clone)]
+pub struct AccountProof {
+    pub header: LightHeader,
+    pub balance: u64,
+    /// This chain's UTXO transactions carry no per-account nonce.
+    pub nonce: u64,
+    /// Not a contract account, so there is no code.
+    pub code_hash: String,
+    /// Root the `proof_nodes` verify against (the block's transaction
+    /// merkle root; this chain has no separate storage trie).
+    pub storage_root: String,
+    /// Sibling path proving the matching transaction's inclusion under
+    /// `storage_root`, in the wire format of [`lib_blockchain::merkle::MerkleProof::to_proof_nodes`].
+    pub proof_nodes: Vec<Vec<u8>>,
+}
+
+/// Proof that transaction output `key` of the transaction touching
+/// `address` in `header` carries `value`, verifiable the same way as
+/// [`AccountProof`].
+#[derive(Debug, Clone)]
+pub struct StorageProof {
+    pub header: LightHeader,
+    pub key: u32,
+    pub value: String,
+    pub storage_root: String,
+    pub proof_nodes: Vec<Vec<u8>>,
+}
+
+/// Answers proof-bearing light-client requests, backed by the node's
+/// own chain data via [`RuntimeOrchestrator`].
+#[async_trait]
+pub trait LightProvider: Send + Sync {
+    async fn get_headers(&self, start: u64, count: usize, reverse: bool) -> Result<Vec<LightHeader>>;
+    async fn get_account_proof(&self, address: &str, block: Option<u64>) -> Result<AccountProof>;
+    async fn get_storage_proof(&self, address: &str, key: u32, block: Option<u64>) -> Result<StorageProof>;
+}
+
+/// Find the block touching `address`, returning it plus a proof that
+/// the matching transaction (at `tx_index`) is included under its
+/// `merkle_root`.
+fn prove_transaction_for_address(block: &Block, address: &str) -> Option<(usize, Vec<Vec<u8>>)> {
+    let address = address.trim_start_matches("0x");
+    let tx_index = block
+        .transactions
+        .iter()
+        .position(|tx| tx.outputs.iter().any(|o| hex::encode(o.recipient.key_id) == address))?;
+
+    let leaves: Vec<_> = block.transactions.iter().map(hash_transaction).collect();
+    let tree = MerkleTree::from_leaves(leaves);
+    let proof = tree.proof(tx_index)?;
+    Some((tx_index, proof.to_proof_nodes()))
+}
+
+#[async_trait]
+impl LightProvider for RuntimeOrchestrator {
+    async fn get_headers(&self, start: u64, count: usize, reverse: bool) -> Result<Vec<LightHeader>> {
+        let service = self
+            .get_shared_blockchain_service()
+            .await
+            .ok_or_else(|| anyhow!("Shared blockchain is not initialized"))?;
+        let blockchain = service.get_blockchain_arc();
+        let blockchain = blockchain.read().await;
+
+        let mut headers = Vec::with_capacity(count.min(1000));
+        for i in 0..count.min(1000) {
+            let height = if reverse { start.saturating_sub(i as u64) } else { start + i as u64 };
+            match blockchain.get_block(height) {
+                Some(block) => headers.push(LightHeader::from(block)),
+                None => break,
+            }
+        }
+        Ok(headers)
+    }
+
+    async fn get_account_proof(&self, address: &str, block: Option<u64>) -> Result<AccountProof> {
+        let service = self
+            .get_shared_blockchain_service()
+            .await
+            .ok_or_else(|| anyhow!("Shared blockchain is not initialized"))?;
+        let blockchain_arc = service.get_blockchain_arc();
+        let blockchain = blockchain_arc.read().await;
+
+        let height = block.unwrap_or_else(|| blockchain.get_height());
+        let target_block = blockchain
+            .get_block(height)
+            .ok_or_else(|| anyhow!("No block at height {}", height))?;
+
+        let proof_nodes = prove_transaction_for_address(target_block, address)
+            .map(|(_, nodes)| nodes)
+            .unwrap_or_default();
+
+        let address_hash = lib_crypto::Hash::from_hex(address).ok();
+        let balance = address_hash
+            .and_then(|h| {
+                let bytes = h.as_bytes();
+                if bytes.len() == 32 {
+                    let mut arr = [0u8; 32];
+                    arr.copy_from_slice(bytes);
+                    blockchain.get_wallet_balance(&arr)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(0);
+
+        Ok(AccountProof {
+            header: LightHeader::from(target_block),
+            balance,
+            nonce: 0,
+            code_hash: lib_blockchain::types::Hash::default().to_hex(),
+            storage_root: target_block.header.merkle_root.to_string(),
+            proof_nodes,
+        })
+    }
+
+    async fn get_storage_proof(&self, address: &str, key: u32, block: Option<u64>) -> Result<StorageProof> {
+        let service = self
+            .get_shared_blockchain_service()
+            .await
+            .ok_or_else(|| anyhow!("Shared blockchain is not initialized"))?;
+        let blockchain_arc = service.get_blockchain_arc();
+        let blockchain = blockchain_arc.read().await;
+
+        let height = block.unwrap_or_else(|| blockchain.get_height());
+        let target_block = blockchain
+            .get_block(height)
+            .ok_or_else(|| anyhow!("No block at height {}", height))?;
+
+        let needle = address.trim_start_matches("0x");
+        let tx_index = target_block
+            .transactions
+            .iter()
+            .position(|tx| tx.outputs.iter().any(|o| hex::encode(o.recipient.key_id) == needle));
+
+        let (value, proof_nodes) = match tx_index {
+            Some(idx) => {
+                let leaves: Vec<_> = target_block.transactions.iter().map(hash_transaction).collect();
+                let tree = MerkleTree::from_leaves(leaves);
+                let proof_nodes = tree.proof(idx).map(|p| p.to_proof_nodes()).unwrap_or_default();
+                let value = target_block.transactions[idx]
+                    .outputs
+                    .get(key as usize)
+                    .map(|o| o.commitment.to_string())
+                    .unwrap_or_default();
+                (value, proof_nodes)
+            }
+            None => (String::new(), Vec::new()),
+        };
+
+        Ok(StorageProof {
+            header: LightHeader::from(target_block),
+            key,
+            value,
+            storage_root: target_block.header.merkle_root.to_string(),
+            proof_nodes,
+        })
+    }
+}
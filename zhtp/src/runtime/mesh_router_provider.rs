@@ -3,7 +3,7 @@ use tokio::sync::RwLock;
 use anyhow::Result;
 use tracing::info;
 
-use crate::unified_server::{MeshRouter, BroadcastMetrics};
+use crate::unified_server::{MeshRouter, BroadcastMetrics, GasFeeHistory};
 
 /// Global mesh router provider for shared access across components
 /// This allows API handlers to access mesh router metrics and state
@@ -86,6 +86,12 @@ pub async fn get_broadcast_metrics() -> Result<BroadcastMetrics> {
     Ok(mesh_router.get_broadcast_metrics().await)
 }
 
+/// Get the gas fee-history oracle from the global mesh router
+pub async fn get_gas_fee_history() -> Result<GasFeeHistory> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.get_gas_fee_history().await)
+}
+
 /// Get peer reputation from the global mesh router
 pub async fn get_peer_reputation(peer_id: &str) -> Result<Option<crate::unified_server::PeerReputation>> {
     let mesh_router = get_global_mesh_router().await?;
@@ -155,3 +161,80 @@ pub async fn list_peer_performance() -> Result<Vec<crate::unified_server::PeerPe
     let mesh_router = get_global_mesh_router().await?;
     Ok(mesh_router.list_peer_performance().await)
 }
+
+/// Report a protocol violation for a peer and apply the resulting
+/// graduated sanction (see [`crate::unified_server::Punishment`]).
+pub async fn punish_peer(
+    peer_id: &str,
+    kind: crate::unified_server::ViolationKind,
+) -> Result<crate::unified_server::Punishment> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.punish_peer(peer_id, kind).await)
+}
+
+/// Manually lift an active ban on a peer.
+pub async fn lift_peer_ban(peer_id: &str) -> Result<bool> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.lift_peer_ban(peer_id).await)
+}
+
+/// Get the ban expiry timestamp for a peer, if it is currently banned.
+pub async fn peer_ban_expiry(peer_id: &str) -> Result<Option<u64>> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.peer_ban_expiry(peer_id).await)
+}
+
+/// Directly impose a ban on a peer for `reason`, independent of the
+/// violation-scoring ladder `punish_peer` drives. Returns the ban's expiry
+/// timestamp.
+pub async fn ban_peer(
+    peer_id: &str,
+    reason: crate::unified_server::BanReason,
+    duration_secs: Option<u64>,
+) -> Result<u64> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.ban_peer(peer_id, reason, duration_secs).await)
+}
+
+/// Whether a peer is currently banned (manually or via the score ladder).
+pub async fn is_peer_banned(peer_id: &str) -> Result<bool> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.is_peer_banned(peer_id).await)
+}
+
+/// Charge a peer's flow-control credit buffer for an inbound mesh request.
+/// Returns `false` if the peer didn't have enough credits (and a violation
+/// was recorded), so the caller can defer or drop the request.
+pub async fn charge_peer_request(peer_id: &str, kind: &str) -> Result<bool> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.charge_peer_request(peer_id, kind).await)
+}
+
+/// Get a peer's current flow-control credit balance and the flow params
+/// it's measured against.
+pub async fn get_peer_credits(peer_id: &str) -> Result<(f64, crate::unified_server::PeerFlowParams)> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.get_peer_credits(peer_id).await)
+}
+
+/// Get the default per-peer flow-control params.
+pub async fn get_peer_flow_params() -> Result<crate::unified_server::PeerFlowParams> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.get_peer_flow_params().await)
+}
+
+/// Update the default per-peer flow-control params.
+pub async fn update_peer_flow_params(params: crate::unified_server::PeerFlowParams) -> Result<()> {
+    let mesh_router = get_global_mesh_router().await?;
+    mesh_router.update_peer_flow_params(params).await;
+    Ok(())
+}
+
+/// Subscribe to the global mesh router's monitoring event broadcast (alerts,
+/// performance snapshots, peer-status changes). Each call yields an
+/// independent receiver backed by the same channel `get_broadcast_metrics`
+/// and friends already read their state from.
+pub async fn subscribe_monitoring_events() -> Result<tokio::sync::broadcast::Receiver<crate::unified_server::MonitoringEvent>> {
+    let mesh_router = get_global_mesh_router().await?;
+    Ok(mesh_router.monitoring_events.subscribe())
+}
@@ -32,6 +32,9 @@ pub mod edge_state_provider;  // Global access to edge node state for header-onl
 pub mod identity_manager_provider;
 pub mod network_blockchain_provider;
 pub mod mesh_router_provider;
+pub mod peer_registry;
+pub mod sync_scheduler;
+pub mod light_provider;
 pub mod bootstrap_peers_provider;  // FIX: Global access to bootstrap peers for UnifiedServer
 pub mod did_startup;
 pub mod dht_indexing;
@@ -48,6 +51,7 @@ pub use blockchain_provider::{initialize_global_blockchain_provider, set_global_
 pub use identity_manager_provider::{initialize_global_identity_manager_provider, set_global_identity_manager, get_global_identity_manager};
 pub use network_blockchain_provider::ZhtpBlockchainProvider;
 pub use mesh_router_provider::{initialize_global_mesh_router_provider, set_global_mesh_router, get_broadcast_metrics};
+pub use peer_registry::initialize_global_peer_registry;
 
 /// Component status information
 #[derive(Debug, Clone, PartialEq)]
@@ -1344,13 +1348,16 @@ impl RuntimeOrchestrator {
     /// Connect to a peer
     pub async fn connect_to_peer(&self, addr: &str) -> Result<()> {
         info!("Attempting to connect to peer: {}", addr);
-        
+
         // Send connect message to network component
         self.send_message(ComponentId::Network, ComponentMessage::Custom(
             format!("connect_to_peer:{}", addr),
             addr.as_bytes().to_vec()
         )).await?;
-        
+
+        // Record/refresh the peer_id <-> address mapping (Issue: peer registry)
+        crate::runtime::peer_registry::record_connect(addr).await;
+
         info!("Connect request sent to network component for peer: {}", addr);
         Ok(())
     }
@@ -1358,13 +1365,16 @@ impl RuntimeOrchestrator {
     /// Disconnect from a peer
     pub async fn disconnect_from_peer(&self, addr: &str) -> Result<()> {
         info!(" Attempting to disconnect from peer: {}", addr);
-        
+
         // Send disconnect message to network component
         self.send_message(ComponentId::Network, ComponentMessage::Custom(
             format!("disconnect_from_peer:{}", addr),
             addr.as_bytes().to_vec()
         )).await?;
-        
+
+        // Update the peer registry's connection state (Issue: peer registry)
+        crate::runtime::peer_registry::record_disconnect(addr).await;
+
         info!("Disconnect request sent to network component for peer: {}", addr);
         Ok(())
     }
@@ -0,0 +1,178 @@
+//! Peer Registry - persistent peer_id <-> peer_address mapping
+//!
+//! `handle_remove_network_peer` used to fake the lookup with
+//! `format!("peer-address-{}", peer_id)` and admitted in a comment that a
+//! real mapping was missing, while `handle_add_network_peer` derived
+//! `peer_id` from a blake3 hash of the address but then threw the
+//! association away. This module is that real bidirectional mapping, plus
+//! connection state and first/last-seen timestamps, persisted to disk so it
+//! survives a restart.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use tokio::sync::RwLock;
+use serde::{Serialize, Deserialize};
+use anyhow::Result;
+use tracing::{info, warn};
+
+/// Connection state of a registered peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PeerConnectionState {
+    Connected,
+    Disconnected,
+}
+
+/// One registry entry: everything needed to resolve `peer_id` back to an
+/// address and show its connection history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeerRecord {
+    pub peer_id: String,
+    pub peer_address: String,
+    /// Transport/multiaddr used to reach this peer. Currently identical to
+    /// `peer_address`, since this node only tracks one transport per peer.
+    pub multiaddr: String,
+    pub state: PeerConnectionState,
+    pub first_seen: u64,
+    pub last_seen: u64,
+}
+
+struct PeerRegistryState {
+    peers: HashMap<String, PeerRecord>,
+    path: Option<PathBuf>,
+}
+
+impl PeerRegistryState {
+    async fn persist(&self) {
+        let Some(path) = &self.path else { return };
+        let records: Vec<&PeerRecord> = self.peers.values().collect();
+        match serde_json::to_string_pretty(&records) {
+            Ok(json) => {
+                if let Some(parent) = path.parent() {
+                    if let Err(e) = tokio::fs::create_dir_all(parent).await {
+                        warn!("Failed to create peer registry directory: {}", e);
+                        return;
+                    }
+                }
+                if let Err(e) = tokio::fs::write(path, json).await {
+                    warn!("Failed to persist peer registry: {}", e);
+                }
+            }
+            Err(e) => warn!("Failed to serialize peer registry: {}", e),
+        }
+    }
+}
+
+static PEER_REGISTRY: OnceLock<RwLock<PeerRegistryState>> = OnceLock::new();
+
+fn registry() -> &'static RwLock<PeerRegistryState> {
+    PEER_REGISTRY.get_or_init(|| {
+        RwLock::new(PeerRegistryState {
+            peers: HashMap::new(),
+            path: None,
+        })
+    })
+}
+
+/// Derive a peer's ID from its address, matching the hash used before this
+/// registry existed so reconnecting to the same address resolves to the
+/// same peer_id across restarts.
+pub fn derive_peer_id(peer_address: &str) -> String {
+    let hash = lib_crypto::hashing::hash_blake3(peer_address.as_bytes());
+    format!("peer_{}", hex::encode(&hash[..8]))
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Load any previously persisted registry from `{data_directory}/peer_registry.json`
+/// and point future writes at that file.
+pub async fn initialize_global_peer_registry(data_directory: &str) -> Result<()> {
+    let path = PathBuf::from(data_directory).join("peer_registry.json");
+
+    let peers = match tokio::fs::read_to_string(&path).await {
+        Ok(json) => match serde_json::from_str::<Vec<PeerRecord>>(&json) {
+            Ok(records) => records
+                .into_iter()
+                .map(|r| (r.peer_id.clone(), r))
+                .collect(),
+            Err(e) => {
+                warn!("Failed to parse persisted peer registry, starting empty: {}", e);
+                HashMap::new()
+            }
+        },
+        Err(_) => HashMap::new(),
+    };
+
+    info!("Peer registry loaded: {} peers from {}", peers.len(), path.display());
+
+    let mut state = registry().write().await;
+    state.peers = peers;
+    state.path = Some(path);
+    Ok(())
+}
+
+/// Record a connection attempt to `peer_address`, creating the entry (with
+/// `first_seen` set) if this is the first time it's been seen, or touching
+/// `last_seen` and its state otherwise. Returns the resulting record.
+pub async fn record_connect(peer_address: &str) -> PeerRecord {
+    let peer_id = derive_peer_id(peer_address);
+    let now = now_secs();
+
+    let mut state = registry().write().await;
+    let record = state
+        .peers
+        .entry(peer_id.clone())
+        .and_modify(|r| {
+            r.state = PeerConnectionState::Connected;
+            r.last_seen = now;
+        })
+        .or_insert_with(|| PeerRecord {
+            peer_id,
+            peer_address: peer_address.to_string(),
+            multiaddr: peer_address.to_string(),
+            state: PeerConnectionState::Connected,
+            first_seen: now,
+            last_seen: now,
+        })
+        .clone();
+
+    state.persist().await;
+    record
+}
+
+/// Record a disconnection from `peer_address`, if it's a known peer.
+pub async fn record_disconnect(peer_address: &str) {
+    let peer_id = derive_peer_id(peer_address);
+    let mut state = registry().write().await;
+    if let Some(record) = state.peers.get_mut(&peer_id) {
+        record.state = PeerConnectionState::Disconnected;
+        record.last_seen = now_secs();
+        state.persist().await;
+    }
+}
+
+/// Resolve a peer_id back to the address it was registered under.
+pub async fn resolve_address(peer_id: &str) -> Option<String> {
+    registry().read().await.peers.get(peer_id).map(|r| r.peer_address.clone())
+}
+
+/// Mark a peer disconnected by peer_id (used once its address has already
+/// been resolved, to avoid re-deriving the id from the address).
+pub async fn mark_disconnected(peer_id: &str) {
+    let mut state = registry().write().await;
+    if let Some(record) = state.peers.get_mut(peer_id) {
+        record.state = PeerConnectionState::Disconnected;
+        record.last_seen = now_secs();
+        state.persist().await;
+    }
+}
+
+/// List every registered peer.
+pub async fn list_peers() -> Vec<PeerRecord> {
+    registry().read().await.peers.values().cloned().collect()
+}
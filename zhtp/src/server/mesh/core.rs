@@ -78,9 +78,16 @@ use crate::session_manager::SessionManager;
 use super::super::monitoring::{
     PeerRateLimit, BroadcastMetrics, PeerReputation,
     SyncPerformanceMetrics, SyncAlert, AlertThresholds,
-    MetricsHistory
+    MetricsHistory, GasFeeHistory, MonitoringEvent,
+    PeerCredits, PeerFlowParams,
 };
 
+/// Capacity of the monitoring event broadcast channel. Slow subscribers that
+/// fall this far behind the fastest producer just miss older events on their
+/// next `recv()` (see `tokio::sync::broadcast::error::RecvError::Lagged`)
+/// rather than applying backpressure to the rest of the router.
+const MONITORING_EVENT_CHANNEL_CAPACITY: usize = 1024;
+
 /// UDP mesh protocol routing and handling
 pub struct MeshRouter {
     // Core connection management (Ticket #149: Use unified PeerRegistry)
@@ -120,10 +127,18 @@ pub struct MeshRouter {
     pub performance_metrics: Arc<RwLock<SyncPerformanceMetrics>>,
     pub active_alerts: Arc<RwLock<Vec<SyncAlert>>>,
     pub alert_thresholds: Arc<RwLock<AlertThresholds>>,
+    /// Per-peer mesh-protocol flow-control credit buffers, keyed by peer_id
+    /// (distinct from the per-API-client buckets in `api::handlers::network`).
+    pub peer_credits: Arc<RwLock<HashMap<String, PeerCredits>>>,
+    pub peer_flow_params: Arc<RwLock<PeerFlowParams>>,
     pub metrics_history: Arc<RwLock<MetricsHistory>>,
+    pub gas_fee_history: Arc<RwLock<GasFeeHistory>>,
     pub latency_samples_blocks: Arc<RwLock<Vec<u64>>>,
     pub latency_samples_txs: Arc<RwLock<Vec<u64>>>,
-    
+    /// Pushed alert/performance/peer-status updates for subscribers of the
+    /// `/api/v1/blockchain/sync/subscribe` endpoint (see `events::MonitoringEvent`).
+    pub monitoring_events: tokio::sync::broadcast::Sender<MonitoringEvent>,
+
     // Multi-hop routing
     pub mesh_message_router: Arc<RwLock<MeshMessageRouter>>,
     
@@ -344,9 +359,13 @@ impl MeshRouter {
             performance_metrics: Arc::new(RwLock::new(SyncPerformanceMetrics::new())),
             active_alerts: Arc::new(RwLock::new(Vec::new())),
             alert_thresholds: Arc::new(RwLock::new(AlertThresholds::default())),
+            peer_credits: Arc::new(RwLock::new(HashMap::new())),
+            peer_flow_params: Arc::new(RwLock::new(PeerFlowParams::default())),
             metrics_history: Arc::new(RwLock::new(MetricsHistory::new(720, 60))),
+            gas_fee_history: Arc::new(RwLock::new(GasFeeHistory::new(1024))),
             latency_samples_blocks: Arc::new(RwLock::new(Vec::new())),
             latency_samples_txs: Arc::new(RwLock::new(Vec::new())),
+            monitoring_events: tokio::sync::broadcast::channel(MONITORING_EVENT_CHANNEL_CAPACITY).0,
             mesh_message_router,
             dht_storage,
             dht_handler: Arc::new(RwLock::new(None)),
@@ -376,7 +395,12 @@ impl MeshRouter {
     pub async fn get_broadcast_metrics(&self) -> BroadcastMetrics {
         self.broadcast_metrics.read().await.clone()
     }
-    
+
+    /// Get the fee-history oracle backing the gas-pricing API
+    pub async fn get_gas_fee_history(&self) -> GasFeeHistory {
+        self.gas_fee_history.read().await.clone()
+    }
+
     /// Get list of connected peer addresses
     pub async fn get_peer_addresses(&self) -> Vec<String> {
         self.connections.read().await
@@ -507,9 +531,13 @@ impl Clone for MeshRouter {
             performance_metrics: self.performance_metrics.clone(),
             active_alerts: self.active_alerts.clone(),
             alert_thresholds: self.alert_thresholds.clone(),
+            peer_credits: self.peer_credits.clone(),
+            peer_flow_params: self.peer_flow_params.clone(),
             metrics_history: self.metrics_history.clone(),
+            gas_fee_history: self.gas_fee_history.clone(),
             latency_samples_blocks: self.latency_samples_blocks.clone(),
             latency_samples_txs: self.latency_samples_txs.clone(),
+            monitoring_events: self.monitoring_events.clone(),
             mesh_message_router: self.mesh_message_router.clone(),
             dht_storage: self.dht_storage.clone(),
             dht_handler: self.dht_handler.clone(),
@@ -15,8 +15,9 @@ use anyhow::Result;
 
 use super::core::MeshRouter;
 use crate::server::monitoring::{
-    PeerPerformanceStats, SyncPerformanceMetrics, SyncAlert, 
-    AlertLevel, AlertThresholds, MetricsSnapshot
+    PeerPerformanceStats, SyncPerformanceMetrics, SyncAlert,
+    AlertLevel, AlertThresholds, MetricsSnapshot, PeerReputation, Punishment, ViolationKind,
+    MonitoringEvent, PeerCredits, PeerFlowParams, BanReason,
 };
 
 // ✅ Phase 4: Import lib-network monitoring components
@@ -75,9 +76,44 @@ impl MeshRouter {
     
     /// Get peer-specific performance metrics
     pub async fn get_peer_performance(&self, peer_id: &str) -> Option<PeerPerformanceStats> {
-        let reputation = self.peer_reputations.read().await.get(peer_id).cloned()?;
-        
-        Some(PeerPerformanceStats {
+        let thresholds = self.alert_thresholds.read().await.clone();
+        let mut stats = {
+            let mut reputations = self.peer_reputations.write().await;
+            let reputation = reputations.get_mut(peer_id)?;
+            reputation.decay(thresholds.reputation_decay_per_minute, thresholds.disconnect_score_threshold);
+            Self::reputation_to_stats(peer_id, reputation, &thresholds)
+        };
+        let (credits, params) = self.get_peer_credits(peer_id).await;
+        stats.credits = credits;
+        stats.max_buffer = params.max_buffer;
+        stats.recharge_per_sec = params.recharge_per_sec;
+        Some(stats)
+    }
+
+    /// List all peers with performance stats
+    pub async fn list_peer_performance(&self) -> Vec<PeerPerformanceStats> {
+        let thresholds = self.alert_thresholds.read().await.clone();
+        let stats: Vec<PeerPerformanceStats> = {
+            let mut reputations = self.peer_reputations.write().await;
+            reputations.iter_mut().map(|(peer_id, rep)| {
+                rep.decay(thresholds.reputation_decay_per_minute, thresholds.disconnect_score_threshold);
+                Self::reputation_to_stats(peer_id, rep, &thresholds)
+            }).collect()
+        };
+
+        let mut results = Vec::with_capacity(stats.len());
+        for mut entry in stats {
+            let (credits, params) = self.get_peer_credits(&entry.peer_id).await;
+            entry.credits = credits;
+            entry.max_buffer = params.max_buffer;
+            entry.recharge_per_sec = params.recharge_per_sec;
+            results.push(entry);
+        }
+        results
+    }
+
+    fn reputation_to_stats(peer_id: &str, reputation: &PeerReputation, thresholds: &AlertThresholds) -> PeerPerformanceStats {
+        PeerPerformanceStats {
             peer_id: peer_id.to_string(),
             reputation_score: reputation.score,
             blocks_accepted: reputation.blocks_accepted,
@@ -88,26 +124,176 @@ impl MeshRouter {
             acceptance_rate: reputation.get_acceptance_rate(),
             first_seen: reputation.first_seen,
             last_seen: reputation.last_seen,
-        })
+            ban_expires_at: reputation.ban_expires_at,
+            ban_reason: reputation.ban_reason.clone(),
+            ban_count: reputation.ban_count,
+            score_trend: reputation.score_trend,
+            decayed_reputation: reputation.decayed_score(thresholds.reputation_half_life_secs as f64),
+            // Filled in by the caller via `get_peer_credits`, which needs
+            // its own lock on `peer_credits` separate from `peer_reputations`.
+            credits: 0.0,
+            max_buffer: 0.0,
+            recharge_per_sec: 0.0,
+        }
     }
-    
-    /// List all peers with performance stats
-    pub async fn list_peer_performance(&self) -> Vec<PeerPerformanceStats> {
-        let reputations = self.peer_reputations.read().await;
-        reputations.iter().map(|(peer_id, rep)| {
-            PeerPerformanceStats {
-                peer_id: peer_id.clone(),
-                reputation_score: rep.score,
-                blocks_accepted: rep.blocks_accepted,
-                blocks_rejected: rep.blocks_rejected,
-                txs_accepted: rep.txs_accepted,
-                txs_rejected: rep.txs_rejected,
-                violations: rep.violations,
-                acceptance_rate: rep.get_acceptance_rate(),
-                first_seen: rep.first_seen,
-                last_seen: rep.last_seen,
+
+    /// Report a protocol violation for `peer_id`, applying the
+    /// category-weighted penalty and graduated sanction (LES-style
+    /// `Punishment`: None -> Disconnect -> Ban) configured in
+    /// [`AlertThresholds`].
+    pub async fn punish_peer(&self, peer_id: &str, kind: ViolationKind) -> Punishment {
+        let thresholds = self.alert_thresholds.read().await.clone();
+        let (punishment, score) = {
+            let mut reputations = self.peer_reputations.write().await;
+            let reputation = reputations
+                .entry(peer_id.to_string())
+                .or_insert_with(|| PeerReputation::new(peer_id.to_string()));
+            reputation.decay(thresholds.reputation_decay_per_minute, thresholds.disconnect_score_threshold);
+            // `Disconnect`/`Ban` take effect the same way an existing ban does
+            // today: the reputation gate in `udp_handler`'s message handlers
+            // rejects further traffic from this peer (see `is_banned`), rather
+            // than tearing down an active connection directly.
+            let punishment = reputation.apply_violation(
+                kind,
+                thresholds.violation_penalty(kind),
+                thresholds.disconnect_score_threshold,
+                thresholds.ban_score_threshold,
+                thresholds.ban_duration_secs,
+                thresholds.max_ban_duration_secs,
+            );
+            (punishment, reputation.score)
+        };
+
+        let punishment_str = match punishment {
+            Punishment::None => "none",
+            Punishment::Disconnect => "disconnect",
+            Punishment::Ban => "ban",
+        };
+        let _ = self.monitoring_events.send(MonitoringEvent::PeerStatus {
+            peer_id: peer_id.to_string(),
+            score,
+            punishment: punishment_str,
+        });
+
+        punishment
+    }
+
+    /// Lift an active ban on `peer_id`, returning `true` if a reputation
+    /// record for that peer existed.
+    pub async fn lift_peer_ban(&self, peer_id: &str) -> bool {
+        let disconnect_threshold = self.alert_thresholds.read().await.disconnect_score_threshold;
+        let mut reputations = self.peer_reputations.write().await;
+        match reputations.get_mut(peer_id) {
+            Some(reputation) => {
+                reputation.lift_ban(disconnect_threshold);
+                true
             }
-        }).collect()
+            None => false,
+        }
+    }
+
+    /// Return the ban expiry timestamp for `peer_id` if it is currently
+    /// banned, decaying first so an expired ban is reported as lifted.
+    pub async fn peer_ban_expiry(&self, peer_id: &str) -> Option<u64> {
+        let thresholds = self.alert_thresholds.read().await.clone();
+        let mut reputations = self.peer_reputations.write().await;
+        let reputation = reputations.get_mut(peer_id)?;
+        reputation.decay(thresholds.reputation_decay_per_minute, thresholds.disconnect_score_threshold);
+        reputation.ban_expires_at
+    }
+
+    /// Directly impose a ban on `peer_id` for `reason`, independent of the
+    /// violation-scoring ladder `punish_peer` drives - the admin-override
+    /// entry point `ManualAdmin`/`Abusive` bans (and any ban needing a
+    /// caller-specified duration rather than the configured
+    /// `ban_duration_secs`) go through. Returns the ban's expiry timestamp.
+    pub async fn ban_peer(&self, peer_id: &str, reason: BanReason, duration_secs: Option<u64>) -> u64 {
+        let default_duration = self.alert_thresholds.read().await.ban_duration_secs;
+        let mut reputations = self.peer_reputations.write().await;
+        let reputation = reputations
+            .entry(peer_id.to_string())
+            .or_insert_with(|| PeerReputation::new(peer_id.to_string()));
+        let expires_at = reputation.ban(reason, duration_secs.unwrap_or(default_duration));
+
+        let _ = self.monitoring_events.send(MonitoringEvent::PeerStatus {
+            peer_id: peer_id.to_string(),
+            score: reputation.score,
+            punishment: "ban",
+        });
+
+        expires_at
+    }
+
+    /// Whether `peer_id` is currently banned (manually or via the score
+    /// ladder), decaying first so an expired ban reports as lifted.
+    pub async fn is_peer_banned(&self, peer_id: &str) -> bool {
+        let thresholds = self.alert_thresholds.read().await.clone();
+        let mut reputations = self.peer_reputations.write().await;
+        match reputations.get_mut(peer_id) {
+            Some(reputation) => {
+                reputation.decay(thresholds.reputation_decay_per_minute, thresholds.disconnect_score_threshold);
+                reputation.is_banned()
+            }
+            None => false,
+        }
+    }
+
+    // ==================== Peer Flow-Control Credits ====================
+
+    /// Charge `peer_id` for an inbound mesh request of `kind` (e.g.
+    /// `"block_announce"`, `"tx_relay"`, `"header_request"`), recharging
+    /// its credit buffer first. Returns `true` (and deducts the cost) if
+    /// the peer had enough credits; otherwise applies a `CreditOverrun`
+    /// violation through the same graduated sanction engine
+    /// `punish_peer` uses and returns `false`, so the caller can defer or
+    /// drop the request instead of processing it.
+    pub async fn charge_peer_request(&self, peer_id: &str, kind: &str) -> bool {
+        let params = self.peer_flow_params.read().await.clone();
+        let cost = params.cost_for(kind);
+
+        let allowed = {
+            let mut credits = self.peer_credits.write().await;
+            let bucket = credits
+                .entry(peer_id.to_string())
+                .or_insert_with(|| PeerCredits::new(params.max_buffer));
+            bucket.try_spend(cost, params.max_buffer, params.recharge_per_sec)
+        };
+
+        if !allowed {
+            self.punish_peer(peer_id, ViolationKind::CreditOverrun).await;
+        }
+
+        allowed
+    }
+
+    /// Current credit balance and configured flow params for `peer_id`,
+    /// recharging first so the reported balance is up to date. A peer with
+    /// no buffer yet is reported at a full, un-persisted buffer rather than
+    /// implicitly creating one (mirrors `get_peer_reputation`'s read-only
+    /// `Option` semantics for peers that haven't made a request).
+    pub async fn get_peer_credits(&self, peer_id: &str) -> (f64, PeerFlowParams) {
+        let params = self.peer_flow_params.read().await.clone();
+        let mut credits = self.peer_credits.write().await;
+        let current = match credits.get_mut(peer_id) {
+            Some(bucket) => {
+                bucket.recharge(params.max_buffer, params.recharge_per_sec);
+                bucket.current
+            }
+            None => params.max_buffer,
+        };
+        (current, params)
+    }
+
+    /// Get the default per-peer flow-control params.
+    pub async fn get_peer_flow_params(&self) -> PeerFlowParams {
+        self.peer_flow_params.read().await.clone()
+    }
+
+    /// Update the default per-peer flow-control params. Existing peers'
+    /// credit buffers are left as-is and simply recharge/cap against the
+    /// new values from their next request onward.
+    pub async fn update_peer_flow_params(&self, params: PeerFlowParams) {
+        *self.peer_flow_params.write().await = params;
     }
 
     // ==================== Performance Tracking Methods ====================
@@ -261,6 +447,7 @@ impl MeshRouter {
             ).with_metric(metrics.avg_block_propagation_ms, thresholds.max_block_latency_ms as f64);
             
             if !alerts.iter().any(|a| a.category == "block_latency" && !a.acknowledged) {
+                let _ = self.monitoring_events.send(MonitoringEvent::Alert(alert.clone()));
                 alerts.push(alert);
             }
         }
@@ -275,6 +462,7 @@ impl MeshRouter {
             ).with_metric(metrics.avg_tx_propagation_ms, thresholds.max_tx_latency_ms as f64);
             
             if !alerts.iter().any(|a| a.category == "tx_latency" && !a.acknowledged) {
+                let _ = self.monitoring_events.send(MonitoringEvent::Alert(alert.clone()));
                 alerts.push(alert);
             }
         }
@@ -290,6 +478,7 @@ impl MeshRouter {
             ).with_metric(bandwidth_mbps, thresholds.max_bandwidth_mbps);
             
             if !alerts.iter().any(|a| a.category == "bandwidth" && !a.acknowledged) {
+                let _ = self.monitoring_events.send(MonitoringEvent::Alert(alert.clone()));
                 alerts.push(alert);
             }
         }
@@ -304,6 +493,7 @@ impl MeshRouter {
             ).with_metric(metrics.validation_success_rate, thresholds.min_validation_success_rate);
             
             if !alerts.iter().any(|a| a.category == "validation_rate" && !a.acknowledged) {
+                let _ = self.monitoring_events.send(MonitoringEvent::Alert(alert.clone()));
                 alerts.push(alert);
             }
         }
@@ -318,6 +508,7 @@ impl MeshRouter {
             ).with_metric(metrics.duplicate_block_ratio, thresholds.max_duplicate_ratio);
             
             if !alerts.iter().any(|a| a.category == "duplicate_blocks" && !a.acknowledged) {
+                let _ = self.monitoring_events.send(MonitoringEvent::Alert(alert.clone()));
                 alerts.push(alert);
             }
         }
@@ -334,9 +525,10 @@ impl MeshRouter {
                 ).with_peer(peer_id.clone())
                  .with_metric(rep.score as f64, thresholds.min_peer_score as f64);
                 
-                if !alerts.iter().any(|a| a.category == "peer_score" && 
-                                       a.peer_id.as_ref() == Some(peer_id) && 
+                if !alerts.iter().any(|a| a.category == "peer_score" &&
+                                       a.peer_id.as_ref() == Some(peer_id) &&
                                        !a.acknowledged) {
+                    let _ = self.monitoring_events.send(MonitoringEvent::Alert(alert.clone()));
                     alerts.push(alert);
                 }
             }
@@ -351,19 +543,21 @@ impl MeshRouter {
         let reputations = self.peer_reputations.read().await;
         
         let banned_count = reputations.values().filter(|r| r.is_banned()).count();
-        
+        let warp_restore_active = crate::runtime::sync_scheduler::status().await.state == "warp_restore";
+
         MetricsSnapshot {
             timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
             blocks_received: broadcast_metrics.blocks_received,
             txs_received: broadcast_metrics.transactions_received,
             blocks_rejected: broadcast_metrics.blocks_rejected,
             txs_rejected: broadcast_metrics.transactions_rejected,
-            avg_latency_ms: (performance_metrics.avg_block_propagation_ms + 
+            avg_latency_ms: (performance_metrics.avg_block_propagation_ms +
                            performance_metrics.avg_tx_propagation_ms) / 2.0,
-            bandwidth_bps: (performance_metrics.bytes_sent_per_sec + 
+            bandwidth_bps: (performance_metrics.bytes_sent_per_sec +
                           performance_metrics.bytes_received_per_sec) as u64,
             active_peers: connections.all_peers().count() as usize,
             banned_peers: banned_count,
+            warp_restore_active,
         }
     }
 
@@ -380,13 +574,18 @@ impl MeshRouter {
                 
                 // Create snapshot
                 let snapshot = mesh_router.create_metrics_snapshot().await;
-                
+
                 // Add to history
                 let mut history = metrics_history.write().await;
                 history.add_snapshot(snapshot);
-                
+
                 // Check and generate alerts
                 mesh_router.check_and_generate_alerts().await;
+
+                // Push the refreshed performance metrics to subscribers of
+                // the "performance" topic (see `events::MonitoringEvent`).
+                let performance = mesh_router.get_performance_metrics().await;
+                let _ = mesh_router.monitoring_events.send(MonitoringEvent::Performance(performance));
                 
                 debug!("📊 Metrics snapshot created ({} total snapshots)", history.snapshots.len());
             }
@@ -472,7 +472,17 @@ impl MeshRouter {
             return Ok(());
         }
         drop(rate_limits);
-        
+
+        // Flow-control credit check: deduct this peer's "block_announce"
+        // cost, or drop the message and record a violation if its buffer
+        // is exhausted (see `MeshRouter::charge_peer_request`).
+        if !self.charge_peer_request(&sender_key, "block_announce").await {
+            warn!("⚠️ Flow-control credits exhausted for peer {} - dropping block {}",
+                  &sender_key[..16], height);
+            self.broadcast_metrics.write().await.blocks_rejected += 1;
+            return Ok(());
+        }
+
         // Update metrics
         self.broadcast_metrics.write().await.blocks_received += 1;
         
@@ -506,6 +516,14 @@ impl MeshRouter {
             return Ok(());
         }
         
+        // Feed the gas fee-history oracle from real block data (same source
+        // `broadcast_metrics` is updated from) so gas pricing tracks actual
+        // chain load rather than staying static.
+        let gas_used = received_block.transactions.len() as u64
+            * crate::server::monitoring::metrics::GAS_PER_TRANSACTION;
+        let priority_fees: Vec<u64> = received_block.transactions.iter().map(|tx| tx.fee).collect();
+        self.gas_fee_history.write().await.record_block(gas_used, priority_fees);
+
         // Check if this is an edge node
         let edge_sync_mgr = self.edge_sync_manager.read().await;
         let is_edge_node = edge_sync_mgr.is_some();
@@ -706,7 +724,16 @@ impl MeshRouter {
             return Ok(());
         }
         drop(rate_limits);
-        
+
+        // Flow-control credit check: deduct this peer's "tx_relay" cost, or
+        // drop the message and record a violation if its buffer is
+        // exhausted (see `MeshRouter::charge_peer_request`).
+        if !self.charge_peer_request(&sender_key, "tx_relay").await {
+            warn!("⚠️ Flow-control credits exhausted for peer {} - dropping transaction", &sender_key[..16]);
+            self.broadcast_metrics.write().await.transactions_rejected += 1;
+            return Ok(());
+        }
+
         // Update metrics
         self.broadcast_metrics.write().await.transactions_received += 1;
         
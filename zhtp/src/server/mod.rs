@@ -62,9 +62,11 @@ pub use quic_handler::QuicHandler;  // QUIC-native handler
 // ❌ DELETED: HttpRouter - QUIC is the only entry point, HttpCompatibilityLayer converts HTTP → ZHTP
 pub use http::middleware::{Middleware, CorsMiddleware, RateLimitMiddleware, AuthMiddleware};
 
-pub use monitoring::reputation::{PeerReputation, PeerRateLimit, PeerPerformanceStats};
-pub use monitoring::metrics::{SyncPerformanceMetrics, BroadcastMetrics, MetricsSnapshot, MetricsHistory};
+pub use monitoring::reputation::{PeerReputation, PeerRateLimit, PeerPerformanceStats, ViolationKind, Punishment};
+pub use monitoring::metrics::{SyncPerformanceMetrics, BroadcastMetrics, MetricsSnapshot, MetricsHistory, GasFeeHistory, FeeHistoryEntry, FeeHistoryView};
 pub use monitoring::alerts::{AlertLevel, SyncAlert, AlertThresholds};
+pub use monitoring::events::{MonitoringEvent, MonitoringTopic, SubscriptionFilter};
+pub use monitoring::credits::{PeerCredits, PeerFlowParams};
 
 pub use mesh::core::MeshRouter;
 
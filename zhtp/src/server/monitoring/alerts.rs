@@ -2,8 +2,11 @@
 //! 
 //! Generates and manages system alerts based on metrics thresholds
 
+use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::reputation::ViolationKind;
+
 /// Alert severity levels
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlertLevel {
@@ -63,6 +66,24 @@ pub struct AlertThresholds {
     pub min_validation_success_rate: f64,
     pub max_duplicate_ratio: f64,
     pub min_peer_score: i32,
+    /// Score at/below which a peer's connection is dropped ("warning").
+    pub disconnect_score_threshold: i32,
+    /// Score at/below which a peer is banned outright.
+    pub ban_score_threshold: i32,
+    /// Base duration of a score-triggered ban, before exponential backoff
+    /// (see `PeerReputation::apply_violation`).
+    pub ban_duration_secs: u64,
+    /// Cap on a score-triggered ban's escalated duration, no matter how
+    /// many times a peer has been banned before.
+    pub max_ban_duration_secs: u64,
+    /// How fast a negative score recovers toward the neutral baseline.
+    pub reputation_decay_per_minute: i32,
+    /// Half-life, in seconds, used by `PeerReputation::decayed_score` to
+    /// weight recent score events over old ones.
+    pub reputation_half_life_secs: u64,
+    /// Per-[`ViolationKind`] score penalty, keyed by `ViolationKind::as_str()`.
+    /// A kind missing from this map falls back to its own `penalty()`.
+    pub violation_penalties: HashMap<String, i32>,
 }
 
 impl Default for AlertThresholds {
@@ -74,6 +95,24 @@ impl Default for AlertThresholds {
             min_validation_success_rate: 95.0, // 95%
             max_duplicate_ratio: 20.0,       // 20%
             min_peer_score: -25,             // Warning before ban threshold
+            disconnect_score_threshold: -50,
+            ban_score_threshold: -100,
+            ban_duration_secs: 3600,         // 1 hour
+            max_ban_duration_secs: 604800,   // 1 week
+            reputation_decay_per_minute: 1,
+            reputation_half_life_secs: 86400, // 1 day
+            violation_penalties: ViolationKind::default_penalties(),
         }
     }
 }
+
+impl AlertThresholds {
+    /// Configured penalty for `kind`, falling back to its built-in default
+    /// if this config predates it or an operator hasn't overridden it.
+    pub fn violation_penalty(&self, kind: ViolationKind) -> i32 {
+        self.violation_penalties
+            .get(kind.as_str())
+            .copied()
+            .unwrap_or_else(|| kind.penalty())
+    }
+}
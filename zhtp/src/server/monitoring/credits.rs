@@ -0,0 +1,84 @@
+//! Peer Credit/Flow-Control System
+//!
+//! `handle_get_peer_performance` tracks violations and reputation per peer
+//! but nothing stops a single peer from flooding the mesh router with
+//! requests between violations. This mirrors the LES-style buffer-flow
+//! accounting already used for per-API-client throttling (see `Credits`/
+//! `FlowParams` in `api::handlers::network`), scoped instead to per-peer
+//! mesh protocol traffic: each peer holds a [`PeerCredits`] buffer bounded
+//! by the shared [`PeerFlowParams`], deducted per inbound request kind and
+//! recharged lazily from elapsed time, with no timer thread required.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// One peer's flow-control credit buffer.
+#[derive(Debug, Clone)]
+pub struct PeerCredits {
+    pub current: f64,
+    last_update: u64,
+}
+
+impl PeerCredits {
+    pub fn new(max_buffer: f64) -> Self {
+        Self { current: max_buffer, last_update: now_secs() }
+    }
+
+    /// Recharge toward `max_buffer` at `recharge_per_sec` based on elapsed
+    /// time since the last update (lazy, no timer thread needed).
+    pub(crate) fn recharge(&mut self, max_buffer: f64, recharge_per_sec: f64) {
+        let now = now_secs();
+        let elapsed_secs = now.saturating_sub(self.last_update) as f64;
+        self.current = (self.current + elapsed_secs * recharge_per_sec).min(max_buffer);
+        self.last_update = now;
+    }
+
+    /// Recharge, then deduct `cost` if there's enough buffer. Returns
+    /// `false` (buffer unchanged) if the peer doesn't have enough credits.
+    pub fn try_spend(&mut self, cost: f64, max_buffer: f64, recharge_per_sec: f64) -> bool {
+        self.recharge(max_buffer, recharge_per_sec);
+        if self.current >= cost {
+            self.current -= cost;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Per-request-kind credit costs and recharge rate shared by every peer's
+/// [`PeerCredits`] buffer.
+#[derive(Debug, Clone)]
+pub struct PeerFlowParams {
+    pub max_buffer: f64,
+    pub recharge_per_sec: f64,
+    pub cost_table: HashMap<String, f64>,
+    pub default_cost: f64,
+}
+
+impl PeerFlowParams {
+    pub fn cost_for(&self, kind: &str) -> f64 {
+        *self.cost_table.get(kind).unwrap_or(&self.default_cost)
+    }
+}
+
+impl Default for PeerFlowParams {
+    fn default() -> Self {
+        let mut cost_table = HashMap::new();
+        cost_table.insert("block_announce".to_string(), 20.0);
+        cost_table.insert("tx_relay".to_string(), 5.0);
+        cost_table.insert("header_request".to_string(), 15.0);
+        cost_table.insert("block_request".to_string(), 50.0);
+
+        Self {
+            max_buffer: 1000.0,
+            recharge_per_sec: 50.0,
+            cost_table,
+            default_cost: 10.0,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Monitoring Event Bus
+//!
+//! `handle_get_alerts`, `handle_get_performance_metrics`, and
+//! `handle_get_peer_performance` are poll-only: a client only learns about a
+//! new alert, metrics snapshot, or reputation change by asking again. This
+//! module defines the pushed-update side of that data - a [`MonitoringEvent`]
+//! broadcast from [`crate::server::mesh::core::MeshRouter`] every time one of
+//! those getters' backing state changes, and the [`SubscriptionFilter`] a
+//! client registers to select which events it cares about.
+
+use super::alerts::{AlertLevel, SyncAlert};
+use super::metrics::SyncPerformanceMetrics;
+
+/// Topic a [`MonitoringEvent`] belongs to, matched against a subscriber's
+/// requested topic list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitoringTopic {
+    Alerts,
+    Performance,
+    PeerStatus,
+}
+
+impl MonitoringTopic {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Alerts => "alerts",
+            Self::Performance => "performance",
+            Self::PeerStatus => "peer_status",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "alerts" => Some(Self::Alerts),
+            "performance" => Some(Self::Performance),
+            "peer_status" => Some(Self::PeerStatus),
+            _ => None,
+        }
+    }
+}
+
+/// A single pushed update. Cloned onto the broadcast channel wherever the
+/// corresponding poll-only getter's backing state already changes, so no new
+/// source of truth is introduced.
+#[derive(Debug, Clone)]
+pub enum MonitoringEvent {
+    Alert(SyncAlert),
+    Performance(SyncPerformanceMetrics),
+    PeerStatus {
+        peer_id: String,
+        score: i32,
+        punishment: &'static str,
+    },
+}
+
+impl MonitoringEvent {
+    pub fn topic(&self) -> MonitoringTopic {
+        match self {
+            Self::Alert(_) => MonitoringTopic::Alerts,
+            Self::Performance(_) => MonitoringTopic::Performance,
+            Self::PeerStatus { .. } => MonitoringTopic::PeerStatus,
+        }
+    }
+
+    fn level_rank(level: &AlertLevel) -> u8 {
+        match level {
+            AlertLevel::Info => 0,
+            AlertLevel::Warning => 1,
+            AlertLevel::Critical => 2,
+        }
+    }
+
+    /// Whether this event clears `filter`'s topic/level/peer constraints.
+    /// Performance events aren't level- or peer-scoped, so `min_level` only
+    /// constrains `Alert` and `peer_id` only constrains `Alert`/`PeerStatus`.
+    pub fn matches(&self, filter: &SubscriptionFilter) -> bool {
+        if !filter.topics.is_empty() && !filter.topics.contains(&self.topic()) {
+            return false;
+        }
+
+        match self {
+            Self::Alert(alert) => {
+                if Self::level_rank(&alert.level) < Self::level_rank(&filter.min_level) {
+                    return false;
+                }
+                filter.peer_id.is_none() || filter.peer_id.as_deref() == alert.peer_id.as_deref()
+            }
+            Self::PeerStatus { peer_id, .. } => {
+                filter.peer_id.is_none() || filter.peer_id.as_deref() == Some(peer_id.as_str())
+            }
+            Self::Performance(_) => true,
+        }
+    }
+}
+
+/// A registered subscriber's filter, parsed from a `POST .../subscribe` body.
+#[derive(Debug, Clone)]
+pub struct SubscriptionFilter {
+    /// Topics to receive; an empty list means "all topics".
+    pub topics: Vec<MonitoringTopic>,
+    pub min_level: AlertLevel,
+    pub peer_id: Option<String>,
+    /// Minimum spacing between delivered `Performance` frames.
+    pub performance_interval_secs: u64,
+}
+
+impl Default for SubscriptionFilter {
+    fn default() -> Self {
+        Self {
+            topics: Vec::new(),
+            min_level: AlertLevel::Info,
+            peer_id: None,
+            performance_interval_secs: 10,
+        }
+    }
+}
@@ -90,6 +90,151 @@ impl BroadcastMetrics {
     }
 }
 
+/// Synthetic gas cost charged per transaction by the gas-pricing API
+/// (this chain is UTXO-based and has no real EVM gas metering, so the
+/// oracle below treats a block's gas usage as `transaction_count * this`).
+pub const GAS_PER_TRANSACTION: u64 = 21_000;
+
+/// Target block gas capacity the EIP-1559-style oracle tunes toward;
+/// `gas_target` is half of this, matching the Ethereum convention.
+pub const BLOCK_GAS_LIMIT: u64 = 30_000_000;
+
+/// Base fee used to seed the oracle before any blocks have been recorded.
+pub const DEFAULT_BASE_FEE: u64 = 100;
+
+/// Priority fee suggested before any blocks have been recorded.
+pub const DEFAULT_PRIORITY_FEE: u64 = 50;
+
+/// Fee-relevant summary of a single block, as fed to [`GasFeeHistory`].
+#[derive(Debug, Clone)]
+pub struct FeeHistoryEntry {
+    pub base_fee: u64,
+    pub gas_used: u64,
+    pub gas_limit: u64,
+    /// Sorted ascending, one entry per transaction's `fee`.
+    pub priority_fees: Vec<u64>,
+}
+
+/// Compute the next block's base fee from its parent via the standard
+/// EIP-1559 rule: move toward `gas_target` (half of `gas_limit`) at a
+/// rate proportional to how far `gas_used` is from it, capped at 12.5%
+/// of the parent base fee per block by construction (the `/ 8` divisor).
+fn next_base_fee(parent_base_fee: u64, gas_used: u64, gas_limit: u64) -> u64 {
+    let gas_target = gas_limit / 2;
+    if gas_used == gas_target || gas_target == 0 {
+        return parent_base_fee;
+    }
+
+    if gas_used > gas_target {
+        let gas_used_delta = gas_used - gas_target;
+        let increase = ((parent_base_fee as u128 * gas_used_delta as u128)
+            / gas_target as u128
+            / 8)
+            .max(1) as u64;
+        parent_base_fee.saturating_add(increase)
+    } else {
+        let gas_used_delta = gas_target - gas_used;
+        let decrease =
+            (parent_base_fee as u128 * gas_used_delta as u128 / gas_target as u128 / 8) as u64;
+        parent_base_fee.saturating_sub(decrease)
+    }
+}
+
+/// Value at `percentile` (0-100) of an ascending-sorted slice, via
+/// nearest-rank interpolation. Returns 0 for an empty slice.
+fn percentile_value(sorted: &[u64], percentile: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let idx = ((percentile.clamp(0.0, 100.0) / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+/// Ring buffer of recent blocks' fee data (the `eth_feeHistory` model),
+/// driving a dynamic gas-price oracle in place of static pricing.
+#[derive(Debug, Clone)]
+pub struct GasFeeHistory {
+    pub blocks: std::collections::VecDeque<FeeHistoryEntry>,
+    pub max_blocks: usize,
+}
+
+impl GasFeeHistory {
+    pub fn new(max_blocks: usize) -> Self {
+        Self { blocks: std::collections::VecDeque::with_capacity(max_blocks), max_blocks }
+    }
+
+    /// Record a newly-arrived block's gas usage and per-transaction
+    /// priority fees, deriving its base fee from the previous block.
+    pub fn record_block(&mut self, gas_used: u64, mut priority_fees: Vec<u64>) {
+        priority_fees.sort_unstable();
+
+        let parent_base_fee = self.blocks.back().map(|b| b.base_fee).unwrap_or(DEFAULT_BASE_FEE);
+        let base_fee = next_base_fee(parent_base_fee, gas_used, BLOCK_GAS_LIMIT);
+
+        self.blocks.push_back(FeeHistoryEntry {
+            base_fee,
+            gas_used,
+            gas_limit: BLOCK_GAS_LIMIT,
+            priority_fees,
+        });
+
+        if self.blocks.len() > self.max_blocks {
+            self.blocks.pop_front();
+        }
+    }
+
+    /// Base fee the next block would be mined at, given the most recent
+    /// recorded block (or [`DEFAULT_BASE_FEE`] if none yet).
+    pub fn next_base_fee(&self) -> u64 {
+        self.blocks
+            .back()
+            .map(|b| next_base_fee(b.base_fee, b.gas_used, b.gas_limit))
+            .unwrap_or(DEFAULT_BASE_FEE)
+    }
+
+    /// Suggested priority fee: the given percentile of priority fees
+    /// across all recorded blocks (default callers should pass p60).
+    pub fn suggested_priority_fee(&self, percentile: f64) -> u64 {
+        let mut all: Vec<u64> = self.blocks.iter().flat_map(|b| b.priority_fees.iter().copied()).collect();
+        if all.is_empty() {
+            return DEFAULT_PRIORITY_FEE;
+        }
+        all.sort_unstable();
+        percentile_value(&all, percentile)
+    }
+
+    /// `eth_feeHistory`-style view over the last `block_count` blocks:
+    /// base fees per block (plus the projected next one), gas-used
+    /// ratios, and per-block reward percentiles.
+    pub fn fee_history(&self, block_count: usize, percentiles: &[f64]) -> FeeHistoryView {
+        let skip = self.blocks.len().saturating_sub(block_count);
+        let window: Vec<&FeeHistoryEntry> = self.blocks.iter().skip(skip).collect();
+
+        let mut base_fee_per_gas: Vec<u64> = window.iter().map(|b| b.base_fee).collect();
+        base_fee_per_gas.push(self.next_base_fee());
+
+        let gas_used_ratio: Vec<f64> = window
+            .iter()
+            .map(|b| b.gas_used as f64 / b.gas_limit.max(1) as f64)
+            .collect();
+
+        let reward: Vec<Vec<u64>> = window
+            .iter()
+            .map(|b| percentiles.iter().map(|p| percentile_value(&b.priority_fees, *p)).collect())
+            .collect();
+
+        FeeHistoryView { base_fee_per_gas, gas_used_ratio, reward }
+    }
+}
+
+/// Output of [`GasFeeHistory::fee_history`].
+#[derive(Debug, Clone)]
+pub struct FeeHistoryView {
+    pub base_fee_per_gas: Vec<u64>,
+    pub gas_used_ratio: Vec<f64>,
+    pub reward: Vec<Vec<u64>>,
+}
+
 /// Historical data point for time-series tracking
 #[derive(Debug, Clone)]
 pub struct MetricsSnapshot {
@@ -102,6 +247,10 @@ pub struct MetricsSnapshot {
     pub bandwidth_bps: u64,
     pub active_peers: usize,
     pub banned_peers: usize,
+    /// Whether `runtime::sync_scheduler` was mid warp/snapshot restore at
+    /// this snapshot's timestamp, so history can distinguish warp-restore
+    /// activity from ordinary block-range reception.
+    pub warp_restore_active: bool,
 }
 
 /// Time-series metrics storage with rolling window
@@ -5,7 +5,11 @@
 pub mod reputation;
 pub mod metrics;
 pub mod alerts;
+pub mod events;
+pub mod credits;
 
-pub use reputation::{PeerReputation, PeerRateLimit, PeerPerformanceStats};
-pub use metrics::{SyncPerformanceMetrics, BroadcastMetrics, MetricsSnapshot, MetricsHistory};
+pub use reputation::{PeerReputation, PeerRateLimit, PeerPerformanceStats, ViolationKind, Punishment, BanReason};
+pub use metrics::{SyncPerformanceMetrics, BroadcastMetrics, MetricsSnapshot, MetricsHistory, GasFeeHistory, FeeHistoryEntry, FeeHistoryView};
 pub use alerts::{AlertLevel, SyncAlert, AlertThresholds};
+pub use events::{MonitoringEvent, MonitoringTopic, SubscriptionFilter};
+pub use credits::{PeerCredits, PeerFlowParams};
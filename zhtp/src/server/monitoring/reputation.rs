@@ -1,9 +1,146 @@
 //! Peer Reputation System
-//! 
+//!
 //! Tracks peer behavior and maintains reputation scores
 
+use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// `base_secs * 2^ban_count`, saturating rather than overflowing for very
+/// large `ban_count`, capped at `max_secs` (or `base_secs` if that's larger,
+/// so a misconfigured cap below the base can't shrink the first ban).
+fn escalated_ban_duration(base_secs: u64, max_secs: u64, ban_count: u32) -> u64 {
+    let shift = ban_count.min(63);
+    let scaled = base_secs.saturating_mul(1u64.checked_shl(shift).unwrap_or(u64::MAX));
+    scaled.min(max_secs.max(base_secs))
+}
+
+/// Category of protocol violation a peer can be punished for, weighted by
+/// how costly it is to verify/recover from (LES-style graduated scoring).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViolationKind {
+    InvalidBlock,
+    DuplicateFlood,
+    BadProof,
+    Timeout,
+    CreditOverrun,
+}
+
+impl ViolationKind {
+    /// Built-in penalty, used as the fallback when a kind has no entry in
+    /// `AlertThresholds::violation_penalties` (e.g. an older config).
+    pub fn penalty(&self) -> i32 {
+        match self {
+            ViolationKind::InvalidBlock => -50,
+            ViolationKind::DuplicateFlood => -10,
+            ViolationKind::BadProof => -100,
+            ViolationKind::Timeout => -15,
+            ViolationKind::CreditOverrun => -10,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViolationKind::InvalidBlock => "invalid_block",
+            ViolationKind::DuplicateFlood => "duplicate_flood",
+            ViolationKind::BadProof => "bad_proof",
+            ViolationKind::Timeout => "timeout",
+            ViolationKind::CreditOverrun => "credit_overrun",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "invalid_block" => Some(ViolationKind::InvalidBlock),
+            "duplicate_flood" => Some(ViolationKind::DuplicateFlood),
+            "bad_proof" => Some(ViolationKind::BadProof),
+            "timeout" => Some(ViolationKind::Timeout),
+            "credit_overrun" => Some(ViolationKind::CreditOverrun),
+            _ => None,
+        }
+    }
+
+    /// All known kinds paired with their built-in default penalty - the
+    /// starting point for `AlertThresholds::violation_penalties`, so
+    /// operators can see and override every weight rather than just the
+    /// ones they've already touched.
+    pub fn default_penalties() -> HashMap<String, i32> {
+        [
+            ViolationKind::InvalidBlock,
+            ViolationKind::DuplicateFlood,
+            ViolationKind::BadProof,
+            ViolationKind::Timeout,
+            ViolationKind::CreditOverrun,
+        ]
+        .iter()
+        .map(|k| (k.as_str().to_string(), k.penalty()))
+        .collect()
+    }
+}
+
+/// Sanction applied as a peer's score crosses a configured threshold,
+/// modeled on the LES `Punishment` enum (`None` -> `Disconnect` -> `Ban`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Punishment {
+    None,
+    Disconnect,
+    Ban,
+}
+
+/// Reason an operator or the mesh layer directly imposed a ban via
+/// [`PeerReputation::ban`], as opposed to one that accumulated through
+/// [`apply_violation`]'s score thresholds. Modeled on grin_p2p's peer
+/// store, which records *why* a peer was banned separately from the
+/// scoring that led there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BanReason {
+    BadBlock,
+    BadTransaction,
+    ProtocolViolation,
+    ManualAdmin,
+    Abusive,
+}
+
+impl BanReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BanReason::BadBlock => "bad_block",
+            BanReason::BadTransaction => "bad_transaction",
+            BanReason::ProtocolViolation => "protocol_violation",
+            BanReason::ManualAdmin => "manual_admin",
+            BanReason::Abusive => "abusive",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "bad_block" => Some(BanReason::BadBlock),
+            "bad_transaction" => Some(BanReason::BadTransaction),
+            "protocol_violation" => Some(BanReason::ProtocolViolation),
+            "manual_admin" => Some(BanReason::ManualAdmin),
+            "abusive" => Some(BanReason::Abusive),
+            _ => None,
+        }
+    }
+}
+
+/// A single score-affecting event, timestamped so [`PeerReputation::decayed_score`]
+/// can weight recent behavior more heavily than old behavior already baked
+/// into the lifetime `score`/counters.
+#[derive(Debug, Clone, Copy)]
+struct ScoreEvent {
+    timestamp: u64,
+    delta: i32,
+}
+
+/// Cap on how many score events a peer's log retains; oldest entries are
+/// evicted first once exceeded, the same bounded-history approach
+/// `MetricsHistory` uses for snapshots.
+const MAX_SCORE_EVENTS: usize = 256;
+
 /// Peer reputation scoring
 #[derive(Debug, Clone)]
 pub struct PeerReputation {
@@ -16,11 +153,30 @@ pub struct PeerReputation {
     pub violations: u32,
     pub first_seen: u64,
     pub last_seen: u64,
+    /// Unix timestamp the current ban lifts at, if any.
+    pub ban_expires_at: Option<u64>,
+    /// `ViolationKind::as_str()` of the infraction that triggered the
+    /// current ban, if any.
+    pub ban_reason: Option<String>,
+    /// Number of times this peer has been banned via `apply_violation`
+    /// crossing `ban_threshold`. Drives the exponential backoff in
+    /// `apply_violation`: each successive ban lasts longer than the last,
+    /// so chronic offenders accrue ever-longer timeouts.
+    pub ban_count: u32,
+    /// Last time-based decay was applied, for lazy recharge-style recovery.
+    last_decay: u64,
+    /// Signed delta of the most recent score change (decay or violation),
+    /// surfaced to clients as `score_trend`.
+    pub score_trend: i32,
+    /// Recent score-affecting events, bounded to `MAX_SCORE_EVENTS`, used
+    /// to compute [`decayed_score`](Self::decayed_score) at query time
+    /// without mutating the always-current `score`.
+    event_log: Vec<ScoreEvent>,
 }
 
 impl PeerReputation {
     pub fn new(peer_id: String) -> Self {
-        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let now = now_secs();
         Self {
             peer_id,
             score: 50, // Start neutral
@@ -31,47 +187,167 @@ impl PeerReputation {
             violations: 0,
             first_seen: now,
             last_seen: now,
+            ban_expires_at: None,
+            ban_reason: None,
+            ban_count: 0,
+            last_decay: now,
+            score_trend: 0,
+            event_log: Vec::new(),
         }
     }
-    
+
     pub fn update_last_seen(&mut self) {
-        self.last_seen = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        self.last_seen = now_secs();
     }
-    
+
+    /// Append a score-affecting event, evicting the oldest entry once the
+    /// log exceeds `MAX_SCORE_EVENTS` rather than growing unbounded.
+    fn record_event(&mut self, delta: i32) {
+        if self.event_log.len() >= MAX_SCORE_EVENTS {
+            self.event_log.remove(0);
+        }
+        self.event_log.push(ScoreEvent { timestamp: now_secs(), delta });
+    }
+
     pub fn record_block_accepted(&mut self) {
         self.blocks_accepted += 1;
         self.score = (self.score + 1).min(100);
+        self.record_event(1);
         self.update_last_seen();
     }
-    
+
     pub fn record_block_rejected(&mut self) {
         self.blocks_rejected += 1;
         self.score = (self.score - 2).max(-100);
+        self.record_event(-2);
         self.update_last_seen();
     }
-    
+
     pub fn record_tx_accepted(&mut self) {
         self.txs_accepted += 1;
         self.score = (self.score + 1).min(100);
+        self.record_event(1);
         self.update_last_seen();
     }
-    
+
     pub fn record_tx_rejected(&mut self) {
         self.txs_rejected += 1;
         self.score = (self.score - 2).max(-100);
+        self.record_event(-2);
         self.update_last_seen();
     }
-    
+
     pub fn record_violation(&mut self) {
         self.violations += 1;
         self.score = (self.score - 10).max(-100);
+        self.record_event(-10);
         self.update_last_seen();
     }
-    
+
+    /// Decay a negative score back toward the neutral baseline of 0 at
+    /// `decay_per_minute` per elapsed minute (never above 0 via decay
+    /// alone), and auto-reinstate an expired ban: the score floors at
+    /// `disconnect_threshold` (a "warning" re-entry, not a clean slate) but
+    /// `ban_count` is left untouched so a later re-ban keeps escalating.
+    /// Call before every read/write so reputation recovers even between
+    /// violations, the same lazy recharge-on-access pattern
+    /// `Credits::recharge` uses for flow control.
+    pub fn decay(&mut self, decay_per_minute: i32, disconnect_threshold: i32) {
+        let now = now_secs();
+        if let Some(expires_at) = self.ban_expires_at {
+            if now >= expires_at {
+                self.ban_expires_at = None;
+                self.ban_reason = None;
+                self.score = self.score.max(disconnect_threshold);
+            }
+        }
+
+        if self.score >= 0 || decay_per_minute <= 0 {
+            self.last_decay = now;
+            return;
+        }
+
+        let elapsed_minutes = now.saturating_sub(self.last_decay) / 60;
+        if elapsed_minutes == 0 {
+            return;
+        }
+
+        let recovered = (elapsed_minutes as i64 * decay_per_minute as i64).min(i32::MAX as i64) as i32;
+        let new_score = (self.score + recovered).min(0);
+        self.score_trend = new_score - self.score;
+        self.score = new_score;
+        self.last_decay = now;
+    }
+
+    /// Apply a graduated sanction for `kind`, weighted by `penalty` (the
+    /// caller looks this up from `AlertThresholds::violation_penalty` so
+    /// operators can tune it per kind), returning the resulting
+    /// [`Punishment`] so callers can drop the connection or record a ban.
+    ///
+    /// A `Ban` escalates exponentially with `ban_count` (Alfis-style
+    /// failing-node backoff): `base_ban_duration_secs * 2^ban_count`,
+    /// capped at `max_ban_duration_secs`, so an 11th violation bans briefly
+    /// but a chronic offender's bans grow ever longer.
+    pub fn apply_violation(
+        &mut self,
+        kind: ViolationKind,
+        penalty: i32,
+        disconnect_threshold: i32,
+        ban_threshold: i32,
+        base_ban_duration_secs: u64,
+        max_ban_duration_secs: u64,
+    ) -> Punishment {
+        self.violations += 1;
+        let before = self.score;
+        self.score = (self.score + penalty).max(-100);
+        self.score_trend = self.score - before;
+        self.record_event(self.score_trend);
+        self.update_last_seen();
+
+        if self.score <= ban_threshold {
+            let duration = escalated_ban_duration(
+                base_ban_duration_secs,
+                max_ban_duration_secs,
+                self.ban_count,
+            );
+            self.ban_expires_at = Some(now_secs() + duration);
+            self.ban_reason = Some(kind.as_str().to_string());
+            self.ban_count = self.ban_count.saturating_add(1);
+            Punishment::Ban
+        } else if self.score <= disconnect_threshold {
+            Punishment::Disconnect
+        } else {
+            Punishment::None
+        }
+    }
+
+    /// Directly impose a ban for `reason`, independent of the score-based
+    /// sanction ladder `apply_violation` drives - the admin-override path
+    /// `ban_peer` uses for `ManualAdmin`/`Abusive` bans and for capping a
+    /// specific duration rather than the configured `ban_duration_secs`.
+    /// Returns the expiry timestamp.
+    pub fn ban(&mut self, reason: BanReason, duration_secs: u64) -> u64 {
+        let expires_at = now_secs() + duration_secs;
+        self.ban_expires_at = Some(expires_at);
+        self.ban_reason = Some(reason.as_str().to_string());
+        self.update_last_seen();
+        expires_at
+    }
+
+    /// Manually lift an active ban, restoring the score to the disconnect
+    /// threshold so the peer re-enters as "warning" rather than neutral.
+    pub fn lift_ban(&mut self, disconnect_threshold: i32) {
+        self.ban_expires_at = None;
+        self.ban_reason = None;
+        self.score = self.score.max(disconnect_threshold);
+        self.update_last_seen();
+    }
+
     pub fn is_banned(&self) -> bool {
-        self.score <= -50 || self.violations >= 10
+        let ban_active = self.ban_expires_at.map(|exp| exp > now_secs()).unwrap_or(false);
+        ban_active || self.score <= -50 || self.violations >= 10
     }
-    
+
     pub fn get_acceptance_rate(&self) -> f64 {
         let total = self.blocks_accepted + self.blocks_rejected + self.txs_accepted + self.txs_rejected;
         if total == 0 {
@@ -79,6 +355,25 @@ impl PeerReputation {
         }
         ((self.blocks_accepted + self.txs_accepted) as f64 / total as f64) * 100.0
     }
+
+    /// Exponentially-decayed reputation computed at query time from the
+    /// event log rather than read off the always-current `score`: an event
+    /// `half_life_secs` old counts for half as much as a fresh one, so a
+    /// peer that misbehaved long ago is judged mostly on what it's done
+    /// since. Baseline matches `score`'s neutral starting value (50) so a
+    /// peer with an empty or fully-decayed log doesn't read as "perfect".
+    pub fn decayed_score(&self, half_life_secs: f64) -> f64 {
+        if half_life_secs <= 0.0 {
+            return self.score as f64;
+        }
+        let now = now_secs() as f64;
+        let weighted: f64 = self.event_log.iter().map(|event| {
+            let age = (now - event.timestamp as f64).max(0.0);
+            let weight = 0.5f64.powf(age / half_life_secs);
+            event.delta as f64 * weight
+        }).sum();
+        (50.0 + weighted).clamp(-100.0, 100.0)
+    }
 }
 
 /// Peer rate limiting tracker
@@ -147,4 +442,24 @@ pub struct PeerPerformanceStats {
     pub acceptance_rate: f64,
     pub first_seen: u64,
     pub last_seen: u64,
+    /// Unix timestamp the current ban lifts at, if any.
+    pub ban_expires_at: Option<u64>,
+    /// `ViolationKind::as_str()` of the infraction that triggered the
+    /// current ban, if any.
+    pub ban_reason: Option<String>,
+    /// Number of times this peer has been banned; each ban's duration
+    /// escalates exponentially with this count (see `apply_violation`).
+    pub ban_count: u32,
+    /// Signed delta of the most recent score change.
+    pub score_trend: i32,
+    /// Time-windowed reputation from [`PeerReputation::decayed_score`]:
+    /// recent behavior weighted more heavily than old behavior already
+    /// baked into the lifetime `reputation_score`.
+    pub decayed_reputation: f64,
+    /// Current mesh-protocol flow-control credit balance (see
+    /// `monitoring::credits::PeerCredits`).
+    pub credits: f64,
+    /// Flow-control buffer cap and recharge rate applied to `credits`.
+    pub max_buffer: f64,
+    pub recharge_per_sec: f64,
 }
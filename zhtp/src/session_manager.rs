@@ -4,7 +4,7 @@
 
 use std::collections::HashMap;
 use anyhow::{Result, anyhow};
-use lib_identity::{SessionToken, IdentityId};
+use lib_identity::{SessionToken, RefreshToken, IdentityId};
 use tokio::sync::RwLock;
 use std::sync::Arc;
 
@@ -15,10 +15,20 @@ pub struct SessionManager {
     sessions: Arc<RwLock<HashMap<String, SessionToken>>>,
     /// Sessions by identity ID for cleanup
     sessions_by_identity: Arc<RwLock<HashMap<IdentityId, Vec<String>>>>,
+    /// Outstanding refresh tokens by token string
+    refresh_tokens: Arc<RwLock<HashMap<String, RefreshToken>>>,
+    /// Refresh token ids that have already been rotated away, kept for a
+    /// grace window so a replayed id can be recognized as reuse (a theft
+    /// signal) rather than silently treated as "just invalid"
+    rotated_refresh_tokens: Arc<RwLock<HashMap<String, (IdentityId, u64)>>>,
     /// Default session duration
     default_session_duration: u64,
     /// Maximum concurrent sessions per identity
     max_sessions_per_identity: usize,
+    /// Lifetime of the short-lived access token half of a token pair
+    access_token_duration: u64,
+    /// Lifetime of the longer-lived refresh token half of a token pair
+    refresh_token_duration: u64,
 }
 
 impl SessionManager {
@@ -27,8 +37,12 @@ impl SessionManager {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             sessions_by_identity: Arc::new(RwLock::new(HashMap::new())),
+            refresh_tokens: Arc::new(RwLock::new(HashMap::new())),
+            rotated_refresh_tokens: Arc::new(RwLock::new(HashMap::new())),
             default_session_duration: 24 * 60 * 60, // 24 hours
             max_sessions_per_identity: 5,
+            access_token_duration: 15 * 60,                // 15 minutes
+            refresh_token_duration: 30 * 24 * 60 * 60,      // 30 days
         }
     }
 
@@ -38,6 +52,18 @@ impl SessionManager {
         identity_id: IdentityId,
         client_ip: &str,
         user_agent: &str,
+    ) -> Result<String> {
+        self.create_session_with_duration(identity_id, client_ip, user_agent, self.default_session_duration).await
+    }
+
+    /// Create a new session with an explicit duration (used for the
+    /// short-lived access token half of [`SessionManager::create_token_pair`])
+    async fn create_session_with_duration(
+        &self,
+        identity_id: IdentityId,
+        client_ip: &str,
+        user_agent: &str,
+        duration_seconds: u64,
     ) -> Result<String> {
         // Clean up expired sessions first
         self.cleanup_expired_sessions().await;
@@ -59,7 +85,7 @@ impl SessionManager {
         // Create new session token with IP/UA binding (P0-6)
         let session_token = SessionToken::new(
             identity_id.clone(),
-            self.default_session_duration,
+            duration_seconds,
             Some(client_ip.to_string()),
             Some(user_agent.to_string()),
         )?;
@@ -88,6 +114,104 @@ impl SessionManager {
         Ok(token_string)
     }
 
+    /// Issue a short-lived access token plus a longer-lived refresh token
+    ///
+    /// Unlike [`SessionManager::create_session`] (a single long-lived
+    /// token), this lets a caller - e.g. a guardian approving a
+    /// long-running recovery - keep acting across hours without holding
+    /// one long-lived credential: the access token is validated exactly
+    /// like any other session via `validate_session`, while the refresh
+    /// token is presented only to `refresh_token_pair` to mint a new pair
+    /// before the access token expires.
+    pub async fn create_token_pair(
+        &self,
+        identity_id: IdentityId,
+        client_ip: &str,
+        user_agent: &str,
+    ) -> Result<(String, String)> {
+        let access_token = self
+            .create_session_with_duration(identity_id.clone(), client_ip, user_agent, self.access_token_duration)
+            .await?;
+
+        let refresh = RefreshToken::new(
+            identity_id.clone(),
+            self.refresh_token_duration,
+            Some(client_ip.to_string()),
+            Some(user_agent.to_string()),
+        )?;
+        let refresh_token = refresh.token.clone();
+
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+        refresh_tokens.insert(refresh_token.clone(), refresh);
+        drop(refresh_tokens);
+
+        tracing::info!(
+            "🎫 New refresh token issued for identity {} (IP: {})",
+            hex::encode(&identity_id.0[..8]),
+            client_ip
+        );
+
+        Ok((access_token, refresh_token))
+    }
+
+    /// Validate a refresh token, rotate it, and mint a fresh access/refresh pair
+    ///
+    /// The presented id is consumed (rotated away) whether or not
+    /// validation ultimately succeeds, so it can only ever be used once.
+    /// Presenting an id that was already rotated away is treated as a
+    /// theft signal: every session held by that identity is revoked.
+    pub async fn refresh_token_pair(
+        &self,
+        refresh_token: &str,
+        current_ip: &str,
+        current_ua: &str,
+    ) -> Result<(String, String)> {
+        let presented = {
+            let mut refresh_tokens = self.refresh_tokens.write().await;
+            refresh_tokens.remove(refresh_token)
+        };
+
+        let Some(token) = presented else {
+            let already_rotated = self
+                .rotated_refresh_tokens
+                .read()
+                .await
+                .get(refresh_token)
+                .map(|(identity_id, _)| identity_id.clone());
+
+            if let Some(identity_id) = already_rotated {
+                tracing::warn!(
+                    "🚨 Rotated refresh token reused for identity {} - revoking all sessions",
+                    hex::encode(&identity_id.0[..8])
+                );
+                self.remove_all_sessions(&identity_id).await?;
+            }
+
+            return Err(anyhow!("Invalid or already-rotated refresh token"));
+        };
+
+        // Security: mark this id as rotated immediately so it can never be
+        // redeemed a second time, regardless of what the checks below find
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.rotated_refresh_tokens
+            .write()
+            .await
+            .insert(refresh_token.to_string(), (token.identity_id.clone(), now));
+
+        if !token.is_valid() {
+            return Err(anyhow!("Refresh token expired"));
+        }
+
+        if !token.validate_binding(current_ip, current_ua) {
+            return Err(anyhow!("Refresh token binding validation failed"));
+        }
+
+        self.create_token_pair(token.identity_id, current_ip, current_ua).await
+    }
+
     /// Validate and get session token with IP/UA binding check (P0-6)
     pub async fn validate_session(
         &self,
@@ -248,6 +372,22 @@ impl SessionManager {
         if removed_count > 0 {
             tracing::info!(" Cleaned up {} expired sessions", removed_count);
         }
+
+        // Purge expired refresh tokens and age out old rotation records so
+        // both maps stay bounded instead of growing forever
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+        refresh_tokens.retain(|_, token| token.is_valid());
+        drop(refresh_tokens);
+
+        let mut rotated_refresh_tokens = self.rotated_refresh_tokens.write().await;
+        rotated_refresh_tokens.retain(|_, (_, rotated_at)| {
+            now.saturating_sub(*rotated_at) < self.refresh_token_duration
+        });
     }
 
     /// Get total active session count
@@ -291,8 +431,12 @@ impl SessionManager {
         let session_manager = SessionManager {
             sessions: Arc::clone(&self.sessions),
             sessions_by_identity: Arc::clone(&self.sessions_by_identity),
+            refresh_tokens: Arc::clone(&self.refresh_tokens),
+            rotated_refresh_tokens: Arc::clone(&self.rotated_refresh_tokens),
             default_session_duration: self.default_session_duration,
             max_sessions_per_identity: self.max_sessions_per_identity,
+            access_token_duration: self.access_token_duration,
+            refresh_token_duration: self.refresh_token_duration,
         };
         
         tokio::spawn(async move {
@@ -69,7 +69,16 @@ pub use crate::server::{
     AlertLevel,
     AlertThresholds,
     MetricsSnapshot,
+    GasFeeHistory,
     PeerPerformanceStats,
+    ViolationKind,
+    Punishment,
+    BanReason,
+    PeerCredits,
+    PeerFlowParams,
+    MonitoringEvent,
+    MonitoringTopic,
+    SubscriptionFilter,
     // Protocol routers
     WiFiRouter,
     BluetoothRouter,
@@ -490,6 +499,11 @@ impl ZhtpUnifiedServer {
         let recovery_manager = Arc::new(RwLock::new(
             lib_identity::SocialRecoveryManager::new()
         ));
+        // Start background sweep to mature elapsed emergency access windows
+        crate::api::handlers::guardian::spawn_emergency_access_sweep(
+            recovery_manager.clone(),
+            identity_manager.clone(),
+        );
 
         let guardian_handler: Arc<dyn ZhtpRequestHandler> = Arc::new(
             crate::api::handlers::guardian::GuardianHandler::new(
@@ -593,15 +607,29 @@ impl ZhtpUnifiedServer {
 
         // Create RuntimeOrchestrator for handlers that need runtime access
         let runtime_config = crate::config::NodeConfig::default();
+        let data_directory = runtime_config.data_directory.clone();
         let runtime = Arc::new(crate::runtime::RuntimeOrchestrator::new(runtime_config).await?);
 
+        // Load the persistent peer_id <-> address registry before any peer
+        // connect/disconnect can race ahead of it.
+        if let Err(e) = crate::runtime::peer_registry::initialize_global_peer_registry(&data_directory).await {
+            tracing::warn!("Failed to initialize peer registry: {}", e);
+        }
+
         // Network management (gas pricing, peers, sync metrics)
         let network_handler: Arc<dyn ZhtpRequestHandler> = Arc::new(
             crate::api::handlers::NetworkHandler::new(runtime.clone())
         );
         zhtp_router.register_handler("/api/v1/network".to_string(), network_handler.clone());
         zhtp_router.register_handler("/api/v1/blockchain/network".to_string(), network_handler.clone());
-        zhtp_router.register_handler("/api/v1/blockchain/sync".to_string(), network_handler);
+        zhtp_router.register_handler("/api/v1/blockchain/sync".to_string(), network_handler.clone());
+        zhtp_router.register_handler("/api/v1/blockchain/txpool".to_string(), network_handler);
+
+        // Light/SPV client (headers + account/storage proofs)
+        let light_handler: Arc<dyn ZhtpRequestHandler> = Arc::new(
+            crate::api::handlers::LightHandler::new(runtime.clone())
+        );
+        zhtp_router.register_handler("/api/v1/light".to_string(), light_handler);
 
         // Mesh blockchain operations
         let mesh_handler: Arc<dyn ZhtpRequestHandler> = Arc::new(